@@ -287,6 +287,9 @@ pub async fn run(
 
     let mut conn = crate::connect(connect_opts).await?;
 
+    // lock the database so that concurrent runs don't race; a dry run waits for them too
+    conn.lock().await?;
+
     conn.ensure_migrations_table().await?;
 
     let version = conn.dirty_version().await?;
@@ -354,6 +357,8 @@ pub async fn run(
         }
     }
 
+    conn.unlock().await?;
+
     // Close the connection before exiting:
     // * For MySQL and Postgres this should ensure timely cleanup on the server side,
     //   including decrementing the open connection count.
@@ -380,6 +385,9 @@ pub async fn revert(
 
     let mut conn = crate::connect(connect_opts).await?;
 
+    // lock the database so that concurrent runs don't race; a dry run waits for them too
+    conn.lock().await?;
+
     conn.ensure_migrations_table().await?;
 
     let version = conn.dirty_version().await?;
@@ -453,6 +461,8 @@ pub async fn revert(
         println!("No migrations available to revert");
     }
 
+    conn.unlock().await?;
+
     let _ = conn.close().await;
 
     Ok(())
@@ -47,6 +47,11 @@ impl<T> StatementCache<T> {
         self.inner.is_empty()
     }
 
+    /// Removes the statement for the given key from the cache, returning it if it was present.
+    pub fn remove(&mut self, k: &str) -> Option<T> {
+        self.inner.remove(k)
+    }
+
     /// Removes the least recently used item from the cache.
     pub fn remove_lru(&mut self) -> Option<T> {
         self.inner.remove_lru().map(|(_, v)| v)
@@ -62,11 +67,32 @@ impl<T> StatementCache<T> {
         self.inner.contains_key(k)
     }
 
+    /// Iterates over the SQL and the value of each cached statement, least recently used first.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> + '_ {
+        self.inner.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
     /// Returns the maximum number of statements the cache can hold.
     pub fn capacity(&self) -> usize {
         self.inner.capacity()
     }
 
+    /// Changes the maximum number of statements the cache can hold, returning the
+    /// least recently used statements that no longer fit.
+    pub fn set_capacity(&mut self, capacity: usize) -> Vec<T> {
+        let mut evicted = Vec::new();
+
+        while self.len() > capacity {
+            if let Some(v) = self.remove_lru() {
+                evicted.push(v);
+            }
+        }
+
+        self.inner.set_capacity(capacity);
+
+        evicted
+    }
+
     /// Returns true if the cache capacity is more than 0.
     #[allow(dead_code)] // Only used for some `cfg`s
     pub fn is_enabled(&self) -> bool {
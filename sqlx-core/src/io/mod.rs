@@ -24,3 +24,15 @@ pub use futures_util::io::AsyncReadExt;
 
 #[cfg(feature = "_rt-tokio")]
 pub use tokio::io::AsyncReadExt;
+
+#[cfg(not(feature = "_rt-tokio"))]
+pub use futures_io::AsyncWrite;
+
+#[cfg(feature = "_rt-tokio")]
+pub use tokio::io::AsyncWrite;
+
+#[cfg(not(feature = "_rt-tokio"))]
+pub use futures_util::io::AsyncWriteExt;
+
+#[cfg(feature = "_rt-tokio")]
+pub use tokio::io::AsyncWriteExt;
@@ -148,6 +148,78 @@ impl Migrator {
             conn.lock().await?;
         }
 
+        for migration in self.pending_migrations(conn).await? {
+            conn.apply(migration).await?;
+        }
+
+        // unlock the migrator to allow other migrators to run
+        // but do nothing as we already migrated
+        if self.locking {
+            conn.unlock().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Report the migrations that [`run()`][Self::run()] would apply, without applying them.
+    ///
+    /// The database is locked while the applied migrations are inspected (unless locking is
+    /// disabled with [`set_locking()`][Self::set_locking()]), so this waits for a migrator
+    /// that is already running to finish first.
+    ///
+    /// The migrations table is still created if it does not exist yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::migrate::MigrateError;
+    /// # fn main() -> Result<(), MigrateError> {
+    /// #     sqlx::__rt::test_block_on(async move {
+    /// use sqlx::migrate::Migrator;
+    /// use sqlx::sqlite::SqlitePoolOptions;
+    ///
+    /// let m = Migrator::new(std::path::Path::new("./migrations")).await?;
+    /// let pool = SqlitePoolOptions::new().connect("sqlite::memory:").await?;
+    ///
+    /// for migration in m.dry_run(&pool).await? {
+    ///     println!("can apply {}/{}", migration.version, migration.description);
+    /// }
+    /// #         Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn dry_run<'a, A>(&self, migrator: A) -> Result<Vec<&Migration>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+        self.dry_run_direct(&mut *conn).await
+    }
+
+    #[doc(hidden)]
+    pub async fn dry_run_direct<C>(&self, conn: &mut C) -> Result<Vec<&Migration>, MigrateError>
+    where
+        C: Migrate,
+    {
+        if self.locking {
+            conn.lock().await?;
+        }
+
+        let pending = self.pending_migrations(conn).await;
+
+        if self.locking {
+            conn.unlock().await?;
+        }
+
+        pending
+    }
+
+    // The up migrations that have not been applied yet, in order.
+    async fn pending_migrations<C>(&self, conn: &mut C) -> Result<Vec<&Migration>, MigrateError>
+    where
+        C: Migrate,
+    {
         // creates [_migrations] table only if needed
         // eventually this will likely migrate previous versions of the table
         conn.ensure_migrations_table().await?;
@@ -165,6 +237,8 @@ impl Migrator {
             .map(|m| (m.version, m))
             .collect();
 
+        let mut pending = Vec::new();
+
         for migration in self.iter() {
             if migration.migration_type.is_down_migration() {
                 continue;
@@ -176,19 +250,11 @@ impl Migrator {
                         return Err(MigrateError::VersionMismatch(migration.version));
                     }
                 }
-                None => {
-                    conn.apply(migration).await?;
-                }
+                None => pending.push(migration),
             }
         }
 
-        // unlock the migrator to allow other migrators to run
-        // but do nothing as we already migrated
-        if self.locking {
-            conn.unlock().await?;
-        }
-
-        Ok(())
+        Ok(pending)
     }
 
     /// Run down migrations against the database until a specific version.
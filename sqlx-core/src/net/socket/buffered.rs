@@ -279,7 +279,18 @@ impl ReadBuffer {
         // Because of how `BytesMut` works, we should only be shifting capacity back and forth
         // between `read` and `available` unless we have to read an oversize message.
         while self.read.len() < len {
-            self.reserve(len - self.read.len());
+            let needed = len - self.read.len();
+
+            // Only about as much as was asked for is read, so that the peer is only read from
+            // as fast as messages are consumed. Growing `available` with `BytesMut::reserve()`
+            // may double its capacity, and after an oversize message it may be left with far
+            // more capacity than is needed now. `BytesMut` cannot give up capacity, but since
+            // `available` is only ever spare capacity, it is replaced instead, as in `shrink()`.
+            let limit = cmp::max(needed, DEFAULT_BUF_SIZE);
+
+            if !(needed..=limit).contains(&self.available.capacity()) {
+                self.available = BytesMut::with_capacity(limit);
+            }
 
             let read = socket.read(&mut self.available).await?;
 
@@ -300,12 +311,6 @@ impl ReadBuffer {
         Ok(())
     }
 
-    fn reserve(&mut self, amt: usize) {
-        if let Some(additional) = amt.checked_sub(self.available.capacity()) {
-            self.available.reserve(additional);
-        }
-    }
-
     fn advance(&mut self, amt: usize) {
         self.read.unsplit(self.available.split_to(amt));
     }
@@ -327,3 +332,80 @@ impl ReadBuffer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BufferedSocket, DEFAULT_BUF_SIZE};
+    use crate::io::ReadBuf;
+    use crate::net::Socket;
+    use futures_util::FutureExt;
+    use std::io;
+    use std::task::{Context, Poll};
+
+    // A peer that has sent far more than is read, and never makes a read wait
+    struct Sender {
+        unread: usize,
+    }
+
+    impl Socket for Sender {
+        fn try_read(&mut self, buf: &mut dyn ReadBuf) -> io::Result<usize> {
+            let read = self.unread.min(buf.chunk_mut().len());
+            buf.put_bytes(0, read);
+            self.unread -= read;
+
+            Ok(read)
+        }
+
+        fn try_write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn poll_read_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_write_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_reads_are_bounded_after_an_oversize_message() {
+        const SENT: usize = 100 * 1024 * 1024;
+        const OVERSIZE: usize = 10 * 1024 * 1024;
+
+        let mut socket = BufferedSocket::new(Sender { unread: SENT });
+
+        socket
+            .read_buffered(10)
+            .now_or_never()
+            .expect("the read should not wait")
+            .unwrap();
+
+        let message = socket
+            .read_buffered(OVERSIZE)
+            .now_or_never()
+            .expect("the read should not wait")
+            .unwrap();
+        drop(message);
+
+        for _ in 0..10 {
+            socket
+                .read_buffered(10)
+                .now_or_never()
+                .expect("the read should not wait")
+                .unwrap();
+        }
+
+        // the capacity left over from the oversize message was not read into
+        let read = SENT - socket.socket.unread;
+        assert!(
+            read <= OVERSIZE + 2 * DEFAULT_BUF_SIZE,
+            "read {read} bytes for {OVERSIZE} + 110 bytes of messages"
+        );
+    }
+}
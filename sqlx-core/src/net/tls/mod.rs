@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use crate::error::Error;
 use crate::net::socket::WithSocket;
@@ -56,6 +57,41 @@ impl std::fmt::Display for CertificateInput {
     }
 }
 
+/// A version of the TLS protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    /// TLS 1.2
+    Tls12,
+    /// TLS 1.3
+    Tls13,
+}
+
+impl FromStr for TlsVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "tlsv1.2" => TlsVersion::Tls12,
+            "tlsv1.3" => TlsVersion::Tls13,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown or unsupported TLS version {s:?}").into(),
+                ));
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TlsVersion::Tls12 => "TLSv1.2",
+            TlsVersion::Tls13 => "TLSv1.3",
+        })
+    }
+}
+
 pub struct TlsConfig<'a> {
     pub accept_invalid_certs: bool,
     pub accept_invalid_hostnames: bool,
@@ -63,6 +99,8 @@ pub struct TlsConfig<'a> {
     pub root_cert_path: Option<&'a CertificateInput>,
     pub client_cert_path: Option<&'a CertificateInput>,
     pub client_key_path: Option<&'a CertificateInput>,
+    pub client_key_password: Option<&'a str>,
+    pub min_version: Option<TlsVersion>,
 }
 
 pub async fn handshake<S, Ws>(
@@ -2,7 +2,7 @@ use std::io::{self, Read, Write};
 
 use crate::io::ReadBuf;
 use crate::net::tls::util::StdSocket;
-use crate::net::tls::TlsConfig;
+use crate::net::tls::{TlsConfig, TlsVersion};
 use crate::net::Socket;
 use crate::Error;
 
@@ -48,6 +48,18 @@ pub async fn handshake<S: Socket>(
         .danger_accept_invalid_certs(config.accept_invalid_certs)
         .danger_accept_invalid_hostnames(config.accept_invalid_hostnames);
 
+    match config.min_version {
+        None => {}
+        Some(TlsVersion::Tls12) => {
+            builder.min_protocol_version(Some(native_tls::Protocol::Tlsv12));
+        }
+        Some(TlsVersion::Tls13) => {
+            return Err(Error::Configuration(
+                "native-tls cannot require TLS 1.3; use one of the rustls features instead".into(),
+            ));
+        }
+    }
+
     if let Some(root_cert_path) = config.root_cert_path {
         let data = root_cert_path.data().await?;
         builder.add_root_certificate(native_tls::Certificate::from_pem(&data).map_err(Error::tls)?);
@@ -56,7 +68,12 @@ pub async fn handshake<S: Socket>(
     // authentication using user's key-file and its associated certificate
     if let (Some(cert_path), Some(key_path)) = (config.client_cert_path, config.client_key_path) {
         let cert_path = cert_path.data().await?;
-        let key_path = key_path.data().await?;
+        let mut key_path = key_path.data().await?;
+
+        if let Some(password) = config.client_key_password {
+            key_path = decrypt_private_key(&key_path, password)?;
+        }
+
         let identity = Identity::from_pkcs8(&cert_path, &key_path).map_err(Error::tls)?;
         builder.identity(identity);
     }
@@ -81,3 +98,21 @@ pub async fn handshake<S: Socket>(
         }
     }
 }
+
+// `native-tls` only accepts unencrypted PKCS#8 keys, so decrypt the key into one first
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+fn decrypt_private_key(pem: &[u8], password: &str) -> Result<Vec<u8>, Error> {
+    let key = openssl::pkey::PKey::private_key_from_pem_passphrase(pem, password.as_bytes())
+        .map_err(|e| Error::Tls(format!("failed to decrypt the client key: {e}").into()))?;
+
+    key.private_key_to_pem_pkcs8().map_err(Error::tls)
+}
+
+#[cfg(any(target_os = "windows", target_vendor = "apple"))]
+fn decrypt_private_key(_pem: &[u8], _password: &str) -> Result<Vec<u8>, Error> {
+    Err(Error::Configuration(
+        "native-tls cannot decrypt client keys on this platform; \
+         use one of the rustls features or an unencrypted key instead"
+            .into(),
+    ))
+}
@@ -27,6 +27,8 @@ pub struct PoolConnection<DB: Database> {
 pub(super) struct Live<DB: Database> {
     pub(super) raw: DB::Connection,
     pub(super) created_at: Instant,
+    // the number of times this connection was handed out by the pool
+    pub(super) times_acquired: u64,
 }
 
 pub(super) struct Idle<DB: Database> {
@@ -40,6 +42,24 @@ pub(super) struct Floating<DB: Database, C> {
     pub(super) guard: DecrementSizeGuard<DB>,
 }
 
+/// The age and usage of an idle connection, as returned by
+/// [`Pool::connection_metrics()`][crate::pool::Pool::connection_metrics].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PoolConnectionMetrics {
+    /// The duration since the connection was first opened.
+    pub age: Duration,
+
+    /// The duration that the connection has spent in the idle queue since it was last released.
+    pub idle_for: Duration,
+
+    /// The number of times the connection was acquired from the pool.
+    ///
+    /// Queries executed on the pool itself acquire a connection for each query, so for those
+    /// this is the number of queries the connection served.
+    pub times_acquired: u64,
+}
+
 const EXPECT_MSG: &str = "BUG: inner connection already taken!";
 
 impl<DB: Database> Debug for PoolConnection<DB> {
@@ -247,13 +267,16 @@ impl<DB: Database> Floating<DB, Live<DB>> {
             inner: Live {
                 raw: conn,
                 created_at: Instant::now(),
+                times_acquired: 0,
             },
             guard,
         }
     }
 
     pub fn reattach(self) -> PoolConnection<DB> {
-        let Floating { inner, guard } = self;
+        let Floating { mut inner, guard } = self;
+
+        inner.times_acquired += 1;
 
         let pool = Arc::clone(&guard.pool);
 
@@ -404,6 +427,16 @@ impl<DB: Database> Floating<DB, Idle<DB>> {
             idle_for: now.saturating_duration_since(self.idle_since),
         }
     }
+
+    pub fn metrics(&self) -> PoolConnectionMetrics {
+        let metadata = self.metadata();
+
+        PoolConnectionMetrics {
+            age: metadata.age,
+            idle_for: metadata.idle_for,
+            times_acquired: self.live.times_acquired,
+        }
+    }
 }
 
 impl<DB: Database, C> Deref for Floating<DB, C> {
@@ -1,4 +1,4 @@
-use super::connection::{Floating, Idle, Live};
+use super::connection::{Floating, Idle, Live, PoolConnectionMetrics};
 use crate::connection::ConnectOptions;
 use crate::connection::Connection;
 use crate::database::Database;
@@ -200,7 +200,12 @@ impl<DB: Database> PoolInner<DB> {
     pub(super) fn release(&self, floating: Floating<DB, Live<DB>>) {
         // `options.after_release` and other checks are in `PoolConnection::return_to_pool()`.
 
-        let Floating { inner: idle, guard } = floating.into_idle();
+        self.release_idle(floating.into_idle());
+    }
+
+    // Return a connection to the idle queue without resetting how long it has been idle.
+    fn release_idle(&self, floating: Floating<DB, Idle<DB>>) {
+        let Floating { inner: idle, guard } = floating;
 
         if self.idle_conns.push(idle).is_err() {
             panic!("BUG: connection queue overflow in release()");
@@ -213,6 +218,22 @@ impl<DB: Database> PoolInner<DB> {
         self.num_idle.fetch_add(1, Ordering::AcqRel);
     }
 
+    pub(super) fn connection_metrics(self: &Arc<Self>) -> Vec<PoolConnectionMetrics> {
+        let mut metrics = Vec::with_capacity(self.num_idle());
+
+        // Take each idle connection in turn and put it back at the end of the queue, like the
+        // reaper does. Others may be acquired or released in the meantime, in which case a
+        // connection may be missed or seen twice, which is fine for a snapshot.
+        for _ in 0..self.num_idle() {
+            if let Some(conn) = self.try_acquire() {
+                metrics.push(conn.metrics());
+                self.release_idle(conn);
+            }
+        }
+
+        metrics
+    }
+
     /// Try to atomically increment the pool size for a new connection.
     ///
     /// Returns `Err` if the pool is at max capacity already or is closed.
@@ -294,6 +315,12 @@ impl<DB: Database> PoolInner<DB> {
 
         let acquired_after = acquire_started_at.elapsed();
 
+        if acquired_after > self.options.acquire_slow_threshold {
+            if let Some(callback) = &self.options.on_acquire_slow {
+                callback(acquired_after);
+            }
+        }
+
         let acquire_slow_level = self
             .acquire_slow_level
             .filter(|_| acquired_after > self.options.acquire_slow_threshold);
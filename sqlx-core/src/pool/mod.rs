@@ -70,7 +70,7 @@ use crate::database::Database;
 use crate::error::Error;
 use crate::transaction::Transaction;
 
-pub use self::connection::PoolConnection;
+pub use self::connection::{PoolConnection, PoolConnectionMetrics};
 use self::inner::PoolInner;
 #[doc(hidden)]
 pub use self::maybe::MaybePoolConnection;
@@ -505,6 +505,15 @@ impl<DB: Database> Pool<DB> {
         self.0.num_idle()
     }
 
+    /// Returns the age and usage of each idle connection.
+    ///
+    /// Connections that are checked out are not included. Each idle connection is briefly
+    /// taken from the idle queue to be inspected, so a concurrent acquire may open a new
+    /// connection or wait for another one instead.
+    pub fn connection_metrics(&self) -> Vec<PoolConnectionMetrics> {
+        self.0.connection_metrics()
+    }
+
     /// Gets a clone of the connection options for this pool
     pub fn connect_options(&self) -> Arc<<DB::Connection as Connection>::Options> {
         self.0
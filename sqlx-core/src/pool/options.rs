@@ -78,6 +78,7 @@ pub struct PoolOptions<DB: Database> {
     pub(crate) acquire_time_level: LevelFilter,
     pub(crate) acquire_slow_level: LevelFilter,
     pub(crate) acquire_slow_threshold: Duration,
+    pub(crate) on_acquire_slow: Option<Arc<dyn Fn(Duration) + 'static + Send + Sync>>,
     pub(crate) acquire_timeout: Duration,
     pub(crate) min_connections: u32,
     pub(crate) max_lifetime: Option<Duration>,
@@ -101,6 +102,7 @@ impl<DB: Database> Clone for PoolOptions<DB> {
             acquire_time_level: self.acquire_time_level,
             acquire_slow_threshold: self.acquire_slow_threshold,
             acquire_slow_level: self.acquire_slow_level,
+            on_acquire_slow: self.on_acquire_slow.clone(),
             acquire_timeout: self.acquire_timeout,
             min_connections: self.min_connections,
             max_lifetime: self.max_lifetime,
@@ -157,6 +159,7 @@ impl<DB: Database> PoolOptions<DB> {
             // Fast enough to catch problems (e.g. a full pool); slow enough
             // to not flag typical time to add a new connection to a pool.
             acquire_slow_threshold: Duration::from_secs(2),
+            on_acquire_slow: None,
             acquire_timeout: Duration::from_secs(30),
             idle_timeout: Some(Duration::from_secs(10 * 60)),
             max_lifetime: Some(Duration::from_secs(30 * 60)),
@@ -245,6 +248,44 @@ impl<DB: Database> PoolOptions<DB> {
         self.acquire_slow_threshold
     }
 
+    /// Call `callback` whenever acquiring a connection via [`Pool::acquire()`] takes longer than
+    /// [`acquire_slow_threshold`][Self::acquire_slow_threshold].
+    ///
+    /// The callback receives the time taken to acquire the connection. It is called on the task
+    /// that acquired the connection, before the connection is returned, so it should not block.
+    ///
+    /// Unlike the log event, this is called regardless of
+    /// [`acquire_slow_level`][Self::acquire_slow_level].
+    ///
+    /// # Example: Count Slow Acquires
+    /// A high rate of slow acquires usually means the pool is saturated.
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use std::time::Duration;
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// static SLOW_ACQUIRES: AtomicU64 = AtomicU64::new(0);
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .acquire_slow_threshold(Duration::from_millis(100))
+    ///     .on_acquire_slow(|waited| {
+    ///         SLOW_ACQUIRES.fetch_add(1, Ordering::Relaxed);
+    ///         eprintln!("waited {waited:?} for a connection");
+    ///     })
+    ///     .connect("postgres:// …").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_acquire_slow<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Duration) + 'static + Send + Sync,
+    {
+        self.on_acquire_slow = Some(Arc::new(callback));
+        self
+    }
+
     /// Set the maximum amount of time to spend waiting for a connection in [`Pool::acquire()`].
     ///
     /// Caps the total amount of time `Pool::acquire()` can spend waiting across multiple phases:
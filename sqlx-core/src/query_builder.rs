@@ -227,6 +227,10 @@ where
     /// [`.persistent(false)`][Query::persistent] after [`.build()`][Self::build] to avoid
     /// filling up the connection's prepared statement cache.
     ///
+    /// For a bulk upsert, push the conflict handling and any `RETURNING` clause after this call,
+    /// e.g. `" ON CONFLICT (id) DO UPDATE SET ... RETURNING id, updated_at"`, and build with
+    /// [`.build_query_as()`][Self::build_query_as] to decode the returned rows into your own type.
+    ///
     /// Because the `Arguments` API has a lifetime that must live longer than `Self`, you cannot
     /// bind by-reference from an iterator unless that iterator yields references that live
     /// longer than `Self`, even if the specific `Arguments` implementation doesn't actually
@@ -522,6 +526,15 @@ where
         &self.query
     }
 
+    /// The number of bind arguments pushed so far, including any the builder was
+    /// [constructed with][Self::with_arguments].
+    ///
+    /// Useful for staying under the bind parameter limit of the database
+    /// (see [`.push_bind()`][Self::push_bind]).
+    pub fn arguments_len(&self) -> usize {
+        self.arguments.as_ref().map_or(0, Arguments::len)
+    }
+
     /// Deconstruct this `QueryBuilder`, returning the built SQL. May not be syntactically correct.
     pub fn into_sql(self) -> String {
         self.query
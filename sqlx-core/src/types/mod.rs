@@ -44,6 +44,20 @@ pub mod chrono {
     pub use chrono::{
         DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
     };
+
+    #[cfg(feature = "chrono-tz")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+    #[doc(no_inline)]
+    pub use chrono_tz::Tz;
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+pub mod jiff {
+    #[doc(no_inline)]
+    pub use jiff::civil::{Date, DateTime, Time};
+    #[doc(no_inline)]
+    pub use jiff::Timestamp;
 }
 
 #[cfg(feature = "bit-vec")]
@@ -81,6 +95,65 @@ pub mod mac_address {
     pub use mac_address::MacAddress;
 }
 
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+#[doc(no_inline)]
+pub use ordered_float::OrderedFloat;
+
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+#[doc(no_inline)]
+pub use arrayvec::ArrayVec;
+
+#[cfg(feature = "iso8601")]
+#[cfg_attr(docsrs, doc(cfg(feature = "iso8601")))]
+pub mod iso8601 {
+    #[doc(no_inline)]
+    pub use iso8601::Duration;
+}
+
+#[cfg(feature = "camino")]
+#[cfg_attr(docsrs, doc(cfg(feature = "camino")))]
+pub mod camino {
+    #[doc(no_inline)]
+    pub use camino::{Utf8Path, Utf8PathBuf};
+}
+
+#[cfg(feature = "compact_str")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compact_str")))]
+pub mod compact_str {
+    #[doc(no_inline)]
+    pub use compact_str::CompactString;
+}
+
+#[cfg(feature = "nalgebra")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+pub mod nalgebra {
+    #[doc(no_inline)]
+    pub use nalgebra::Point2;
+}
+
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+pub mod num_bigint {
+    #[doc(no_inline)]
+    pub use num_bigint::{BigInt, BigUint};
+}
+
+#[cfg(feature = "secrecy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "secrecy")))]
+pub mod secrecy {
+    #[doc(no_inline)]
+    pub use secrecy::{ExposeSecret, SecretString};
+}
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub mod url {
+    #[doc(no_inline)]
+    pub use url::Url;
+}
+
 #[cfg(feature = "json")]
 pub use json::{Json, JsonRawValue, JsonValue};
 pub use text::Text;
@@ -5,13 +5,20 @@ use std::sync::Arc;
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
+use crate::type_info::PgTypeKind;
 use crate::types::Type;
-use crate::{PgConnection, PgTypeInfo, Postgres};
+use crate::{PgConnection, PgTypeInfo, PgValueFormat, Postgres};
 
 use crate::type_info::PgArrayOf;
 pub(crate) use sqlx_core::arguments::Arguments;
+use sqlx_core::arguments::IntoArguments;
+use sqlx_core::bytes::Bytes;
 use sqlx_core::error::BoxDynError;
 
+// Postgres rejects any `Bind` message larger than `PQ_LARGE_MESSAGE_LIMIT` (1 GiB - 1 byte),
+// which is about as far as the parameter data can grow without the server closing the connection.
+pub(crate) const DEFAULT_MAX_BIND_SIZE: usize = 0x3fff_ffff;
+
 // TODO: buf.patch(|| ...) is a poor name, can we think of a better name? Maybe `buf.lazy(||)` ?
 // TODO: Extend the patch system to support dynamic lengths
 //       Considerations:
@@ -22,7 +29,7 @@ use sqlx_core::error::BoxDynError;
 //            that has a patch, we then apply the patch which should write to &mut Vec<u8>,
 //            backtrack and update the prefixed-len, then write until the next patch offset
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Clone)]
 pub struct PgArgumentBuffer {
     buffer: Vec<u8>,
 
@@ -44,6 +51,14 @@ pub struct PgArgumentBuffer {
     // function and can just ask postgres.
     //
     type_holes: Vec<(usize, HoleKind)>, // Vec<{ offset, type_name }>
+
+    // Values that are sent straight from their own buffer instead of being copied into this one,
+    // with the offset they belong at in `buffer`, right after their length prefix.
+    external: Vec<(usize, Bytes)>,
+
+    // Indices of the arguments that contain a secret, which are redacted wherever
+    // the arguments are logged or printed.
+    secrets: Vec<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,7 +72,38 @@ struct Patch {
     buf_offset: usize,
     arg_index: usize,
     #[allow(clippy::type_complexity)]
-    callback: Arc<dyn Fn(&mut [u8], &PgTypeInfo) + 'static + Send + Sync>,
+    callback:
+        Arc<dyn Fn(&mut [u8], &PgTypeInfo) -> Result<(), BoxDynError> + 'static + Send + Sync>,
+}
+
+impl fmt::Debug for PgArgumentBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the encoded values are listed instead of the raw buffer, so secrets can be left out
+        struct Values<'a>(&'a PgArgumentBuffer);
+
+        impl fmt::Debug for Values<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut list = f.debug_list();
+
+                for (index, value) in self.0.values().enumerate() {
+                    if self.0.is_secret(index) {
+                        list.entry(&format_args!("<redacted>"));
+                    } else {
+                        list.entry(&value);
+                    }
+                }
+
+                list.finish()
+            }
+        }
+
+        f.debug_struct("PgArgumentBuffer")
+            .field("count", &self.count)
+            .field("values", &Values(self))
+            .field("patches", &self.patches)
+            .field("type_holes", &self.type_holes)
+            .finish()
+    }
 }
 
 impl fmt::Debug for Patch {
@@ -71,6 +117,9 @@ impl fmt::Debug for Patch {
 }
 
 /// Implementation of [`Arguments`] for PostgreSQL.
+///
+/// Arguments can also be bound by reference, with `sqlx::query_with(sql, &arguments)`,
+/// to execute several queries with the same values; each query gets its own copy.
 #[derive(Default, Debug, Clone)]
 pub struct PgArguments {
     // Types of each bind parameter
@@ -78,6 +127,9 @@ pub struct PgArguments {
 
     // Buffer of encoded bind parameters
     pub(crate) buffer: PgArgumentBuffer,
+
+    // Format of each bind parameter, left empty while they are all binary
+    pub(crate) formats: Vec<PgValueFormat>,
 }
 
 impl PgArguments {
@@ -102,6 +154,136 @@ impl PgArguments {
         // increment the number of arguments we are tracking
         self.buffer.count += 1;
 
+        if !self.formats.is_empty() {
+            self.formats.push(PgValueFormat::Binary);
+        }
+
+        Ok(())
+    }
+
+    /// Add a value as a parameter of type `type_info` instead of the type inferred from it,
+    /// like an explicit cast in the query but without changing its text.
+    ///
+    /// A textual value, such as a `String`, is sent in text format, so Postgres parses it as
+    /// `type_info` like it would a string literal; this binds a `String` to a `jsonb` parameter.
+    /// Any other value is sent in binary format as usual, so its encoding has to be that of
+    /// `type_info`, e.g. an `i32` bound as a domain over `INT4`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::Connection;
+    /// # use sqlx::postgres::{PgArguments, PgConnection, PgTypeInfo};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut arguments = PgArguments::default();
+    /// arguments
+    ///     .add_with_type(r#"{"theme": "dark"}"#.to_string(), PgTypeInfo::with_name("jsonb"))
+    ///     .map_err(sqlx::Error::Encode)?;
+    ///
+    /// sqlx::query_with("UPDATE users SET settings = $1", arguments)
+    ///     .execute(&mut conn)
+    ///     .await?;
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub fn add_with_type<'q, T>(
+        &mut self,
+        value: T,
+        type_info: PgTypeInfo,
+    ) -> Result<(), BoxDynError>
+    where
+        T: Encode<'q, Postgres> + Type<Postgres>,
+    {
+        let textual =
+            <str as Type<Postgres>>::compatible(&value.produces().unwrap_or_else(T::type_info));
+
+        self.add(value)?;
+
+        let index = self.types.len() - 1;
+        self.types[index] = type_info;
+
+        // the binary format of a string is its text, which the server then parses as `type_info`
+        if textual {
+            self.formats.resize(self.types.len(), PgValueFormat::Binary);
+            self.formats[index] = PgValueFormat::Text;
+        }
+
+        Ok(())
+    }
+
+    // The parameter format codes to send in `Bind`
+    pub(crate) fn formats(&self) -> &[PgValueFormat] {
+        if self.formats.is_empty() {
+            &[PgValueFormat::Binary]
+        } else {
+            &self.formats
+        }
+    }
+
+    pub(crate) fn format(&self, index: usize) -> PgValueFormat {
+        self.formats
+            .get(index)
+            .copied()
+            .unwrap_or(PgValueFormat::Binary)
+    }
+
+    // Moves the arguments from `index` on into a new `PgArguments`, where they are numbered
+    // from the first
+    pub(crate) fn split_off(&mut self, index: usize) -> PgArguments {
+        let formats = if self.formats.is_empty() {
+            Vec::new()
+        } else {
+            self.formats.split_off(index)
+        };
+
+        PgArguments {
+            types: self.types.split_off(index),
+            buffer: self.buffer.split_off(index),
+            formats,
+        }
+    }
+
+    /// Returns the total size in bytes of the encoded bind parameters.
+    ///
+    /// This includes the 4-byte length prefix written for every value and is the amount of
+    /// parameter data that will be sent in the `Bind` message.
+    pub fn encoded_size(&self) -> usize {
+        self.buffer.len() + self.buffer.external_len()
+    }
+
+    // Ensure that neither a single parameter nor all of them together
+    // are larger than `max_bind_size` bytes
+    pub(crate) fn check_bind_size(&self, max_bind_size: usize) -> Result<(), Error> {
+        let sizes = self
+            .buffer
+            .values()
+            .map(|value| value.map_or(0, <[u8]>::len));
+
+        for (index, size) in sizes.enumerate() {
+            if size > max_bind_size {
+                return Err(Error::Encode(
+                    format!(
+                        "bind parameter ${} is too large: {size} bytes exceeds the limit of {max_bind_size} bytes",
+                        index + 1
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        let total = self.encoded_size();
+
+        if total > max_bind_size {
+            return Err(Error::Encode(
+                format!(
+                    "bind parameters are too large: {total} bytes in total exceeds the limit of {max_bind_size} bytes"
+                )
+                .into(),
+            ));
+        }
+
         Ok(())
     }
 
@@ -123,7 +305,7 @@ impl PgArguments {
             let buf = &mut buffer[patch.buf_offset..];
             let ty = &parameters[patch.arg_index];
 
-            (patch.callback)(buf, ty);
+            (patch.callback)(buf, ty).map_err(Error::Encode)?;
         }
 
         for (offset, kind) in type_holes {
@@ -134,8 +316,90 @@ impl PgArguments {
             buffer[*offset..(*offset + 4)].copy_from_slice(&oid.0.to_be_bytes());
         }
 
+        self.check_enum_labels(parameters)
+    }
+
+    // A string bound to an enum parameter must be one of its labels;
+    // catch a typo here instead of waiting for the server to reject it.
+    //
+    // Strings nested in arrays or records are not checked, as they are sent as `TEXT`
+    // elements, which the server rejects for an enum array or field no matter their value.
+    fn check_enum_labels(&self, parameters: &[PgTypeInfo]) -> Result<(), Error> {
+        let arguments = self.buffer.values().zip(&self.types).zip(parameters);
+
+        for (index, ((value, bound), ty)) in arguments.enumerate() {
+            let PgTypeKind::Enum(labels) = ty.kind() else {
+                continue;
+            };
+
+            // a value bound with `add_with_type()` is parsed by the server instead
+            if !<str as Type<Postgres>>::compatible(bound)
+                || self.format(index) != PgValueFormat::Binary
+            {
+                continue;
+            }
+
+            let Some(value) = value else {
+                continue;
+            };
+
+            let label = std::str::from_utf8(value).map_err(|error| Error::Encode(error.into()))?;
+
+            if !labels.iter().any(|l| l == label) {
+                return Err(Error::Encode(
+                    format!("invalid input value for enum {}: {label:?}", ty.name()).into(),
+                ));
+            }
+        }
+
         Ok(())
     }
+
+    // Apply patches using only the type OIDs already cached on the connection,
+    // for when it cannot be queried, such as in the middle of a `COPY`
+    pub(crate) fn apply_patches_cached(&mut self, conn: &PgConnection) -> Result<(), Error> {
+        let PgArgumentBuffer {
+            ref patches,
+            ref type_holes,
+            ref mut buffer,
+            ..
+        } = self.buffer;
+
+        for patch in patches {
+            let buf = &mut buffer[patch.buf_offset..];
+            let ty = &self.types[patch.arg_index];
+
+            (patch.callback)(buf, ty).map_err(Error::Encode)?;
+        }
+
+        for (offset, kind) in type_holes {
+            let oid = match kind {
+                HoleKind::Type { name } => {
+                    conn.cached_type_id_by_name(name)
+                        .ok_or_else(|| Error::TypeNotFound {
+                            type_name: name.to_string(),
+                        })?
+                }
+                HoleKind::Array(array) => {
+                    conn.cached_array_type_id(array)
+                        .ok_or_else(|| Error::TypeNotFound {
+                            type_name: array.name.to_string(),
+                        })?
+                }
+            };
+
+            buffer[*offset..(*offset + 4)].copy_from_slice(&oid.0.to_be_bytes());
+        }
+
+        Ok(())
+    }
+}
+
+// executing a query applies its patches to the buffer, so each query needs its own copy
+impl<'q> IntoArguments<'q, Postgres> for &PgArguments {
+    fn into_arguments(self) -> PgArguments {
+        self.clone()
+    }
 }
 
 impl<'q> Arguments<'q> for PgArguments {
@@ -176,10 +440,24 @@ impl PgArgumentBuffer {
 
         self.extend(&[0; 4]);
 
+        let external_start = self.external.len();
+
         // encode the value into our buffer
         let len = if let IsNull::No = value.encode(self)? {
+            let size = match &self.external[external_start..] {
+                [] => self.len() - offset - 4,
+
+                // the value is external by itself
+                [(at, bytes)] if *at == offset + 4 && self.len() == offset + 4 => bytes.len(),
+
+                _ => return Err(
+                    "`PgLargeBytes` can only be bound by itself, not in an array, range or record"
+                        .into(),
+                ),
+            };
+
             // Ensure that the value size does not overflow i32
-            value_size_int4_checked(self.len() - offset - 4)?
+            value_size_int4_checked(size)?
         } else {
             // Write a -1 to indicate NULL
             // NOTE: It is illegal for [encode] to write any data
@@ -194,11 +472,74 @@ impl PgArgumentBuffer {
         Ok(())
     }
 
+    // The encoded values in the order they were added, `None` for NULL
+    pub(crate) fn values(&self) -> impl Iterator<Item = Option<&[u8]>> + '_ {
+        let mut offset = 0;
+        let mut external = self.external.iter().peekable();
+
+        std::iter::from_fn(move || {
+            let (len, rest) = self.buffer.get(offset..)?.split_first_chunk::<4>()?;
+            offset += 4;
+
+            let Ok(len) = usize::try_from(i32::from_be_bytes(*len)) else {
+                return Some(None);
+            };
+
+            if let Some((_, bytes)) = external.next_if(|(at, _)| *at == offset) {
+                return Some(Some(&bytes[..]));
+            }
+
+            let value = rest.get(..len).unwrap_or(rest);
+            offset += value.len();
+
+            Some(Some(value))
+        })
+    }
+
+    // Adds a value that is sent from `bytes` when the query is executed, instead of being copied
+    pub(crate) fn push_external(&mut self, bytes: Bytes) {
+        self.external.push((self.len(), bytes));
+    }
+
+    pub(crate) fn external(&self) -> &[(usize, Bytes)] {
+        &self.external
+    }
+
+    // The total size of the external values
+    pub(crate) fn external_len(&self) -> usize {
+        self.external.iter().map(|(_, bytes)| bytes.len()).sum()
+    }
+
+    // Fails if there are external values, for the ways of sending arguments that cannot send them
+    pub(crate) fn check_no_external(&self, context: &str) -> Result<(), Error> {
+        if self.external.is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::Encode(
+            format!("`PgLargeBytes` cannot be bound {context}").into(),
+        ))
+    }
+
+    // Marks the argument being encoded as a secret, so that its value is never logged or printed
+    #[cfg(feature = "secrecy")]
+    pub(crate) fn mark_secret(&mut self) {
+        if self.secrets.last() != Some(&self.count) {
+            self.secrets.push(self.count);
+        }
+    }
+
+    pub(crate) fn is_secret(&self, index: usize) -> bool {
+        self.secrets.contains(&index)
+    }
+
     // Adds a callback to be invoked later when we know the parameter type
-    #[allow(dead_code)]
+    //
+    // The callback may reject the value once the type is known, which fails the query
+    // before it is sent.
     pub(crate) fn patch<F>(&mut self, callback: F)
     where
-        F: Fn(&mut [u8], &PgTypeInfo) + 'static + Send + Sync,
+        F: Fn(&mut [u8], &PgTypeInfo) -> Result<(), BoxDynError> + 'static + Send + Sync,
     {
         let offset = self.len();
         let arg_index = self.count;
@@ -237,6 +578,8 @@ impl PgArgumentBuffer {
             count,
             patches,
             type_holes,
+            external,
+            secrets,
         } = self;
 
         PgArgumentBufferSnapshot {
@@ -244,6 +587,8 @@ impl PgArgumentBuffer {
             count: *count,
             patches_length: patches.len(),
             type_holes_length: type_holes.len(),
+            external_length: external.len(),
+            secrets_length: secrets.len(),
         }
     }
 
@@ -254,12 +599,79 @@ impl PgArgumentBuffer {
             count,
             patches_length,
             type_holes_length,
+            external_length,
+            secrets_length,
         }: PgArgumentBufferSnapshot,
     ) {
         self.buffer.truncate(buffer_length);
         self.count = count;
         self.patches.truncate(patches_length);
         self.type_holes.truncate(type_holes_length);
+        self.external.truncate(external_length);
+        self.secrets.truncate(secrets_length);
+    }
+
+    fn split_off(&mut self, index: usize) -> PgArgumentBuffer {
+        // the offset of the length prefix of the argument at `index`
+        let mut offset = 0;
+
+        for _ in 0..index {
+            let len = i32::from_be_bytes(self.buffer[offset..offset + 4].try_into().unwrap());
+            offset += 4;
+
+            // NULL has no value, and an external value is not in `buffer`
+            if let Ok(len) = usize::try_from(len) {
+                if !self.external.iter().any(|(at, _)| *at == offset) {
+                    offset += len;
+                }
+            }
+        }
+
+        let mut patches = self.patches.split_off(
+            self.patches
+                .partition_point(|patch| patch.arg_index < index),
+        );
+
+        for patch in &mut patches {
+            patch.buf_offset -= offset;
+            patch.arg_index -= index;
+        }
+
+        let mut type_holes = self
+            .type_holes
+            .split_off(self.type_holes.partition_point(|(at, _)| *at < offset));
+
+        for (at, _) in &mut type_holes {
+            *at -= offset;
+        }
+
+        let mut external = self
+            .external
+            .split_off(self.external.partition_point(|(at, _)| *at < offset));
+
+        for (at, _) in &mut external {
+            *at -= offset;
+        }
+
+        let mut secrets = self
+            .secrets
+            .split_off(self.secrets.partition_point(|secret| *secret < index));
+
+        for secret in &mut secrets {
+            *secret -= index;
+        }
+
+        let count = self.count - index;
+        self.count = index;
+
+        PgArgumentBuffer {
+            buffer: self.buffer.split_off(offset),
+            count,
+            patches,
+            type_holes,
+            external,
+            secrets,
+        }
     }
 }
 
@@ -268,6 +680,8 @@ struct PgArgumentBufferSnapshot {
     count: usize,
     patches_length: usize,
     type_holes_length: usize,
+    external_length: usize,
+    secrets_length: usize,
 }
 
 impl Deref for PgArgumentBuffer {
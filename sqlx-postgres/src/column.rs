@@ -1,5 +1,8 @@
 use crate::ext::ustr::UStr;
+use crate::type_info::PgType;
+use crate::types::Oid;
 use crate::{PgTypeInfo, Postgres};
+use sqlx_core::type_info::TypeInfo;
 
 pub(crate) use sqlx_core::column::{Column, ColumnIndex};
 
@@ -35,6 +38,28 @@ impl PgColumn {
     pub fn relation_attribute_no(&self) -> Option<i16> {
         self.relation_attribute_no
     }
+
+    /// Returns the name of the type of this column, as in [`TypeInfo::name()`].
+    pub fn type_name(&self) -> &str {
+        self.type_info.name()
+    }
+
+    /// Returns the OID of the type of this column, as in [`PgTypeInfo::oid()`].
+    pub fn type_oid(&self) -> Option<Oid> {
+        self.type_info.oid()
+    }
+
+    /// Returns the name of the type of this column qualified with its schema,
+    /// such as `"my schema".mood`, if it is a custom type.
+    ///
+    /// This is looked up along with the rest of the type when the query is described,
+    /// so it is `None` for the built-in types SQLx knows without asking the database.
+    pub fn qualified_type_name(&self) -> Option<&str> {
+        match &self.type_info.0 {
+            PgType::Custom(custom) => custom.qualified_name.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 impl Column for PgColumn {
@@ -0,0 +1,355 @@
+use std::fmt::Write;
+
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+use sqlx_core::arguments::Arguments;
+use sqlx_core::executor::Execute;
+use sqlx_core::query_builder::{QueryBuilder, Separated};
+
+use crate::connection::fetch::SyncOnDrop;
+use crate::connection::Connection;
+use crate::error::Error;
+use crate::io::PortalId;
+use crate::message::{self, BackendMessageFormat, Bind, CommandComplete};
+use crate::{PgArguments, PgConnection, PgQueryResult, PgValueFormat, Postgres};
+
+// Executions written before reading their responses back; bounds how much either side has
+// to buffer, as neither can make progress while both are blocked writing to the other
+const PIPELINE_DEPTH: usize = 256;
+
+// Postgres reads the number of parameters of a statement as an unsigned 16-bit integer
+const MAX_BIND_PARAMS: usize = u16::MAX as usize;
+
+impl PgConnection {
+    /// Execute `sql` once for each set of `arguments`, pipelining every execution into a
+    /// single round-trip per batch of executions instead of one per execution.
+    ///
+    /// The statement is prepared (and cached) once, using the parameter types of the first
+    /// set of arguments, which every other set must match. Each set is then sent as its own
+    /// `Bind`/`Execute` pair, followed by a single `Sync` after the last.
+    ///
+    /// Returns the result of each execution, in the same order as `arguments`. Any rows
+    /// returned by the statement are discarded.
+    ///
+    /// Because only one `Sync` is sent, every execution runs in the same implicit transaction
+    /// (or in the current transaction, if one is open): if any of them fails, the server skips
+    /// the rest and none of their changes are kept.
+    ///
+    /// This is not the same as binding arrays with `UNNEST()`, which sends a single execution.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::{Arguments, Connection};
+    /// # use sqlx::postgres::{PgArguments, PgConnection};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut batch = Vec::new();
+    ///
+    /// for (id, name) in [(1, "foo"), (2, "bar")] {
+    ///     let mut arguments = PgArguments::default();
+    ///     arguments.add(id).map_err(sqlx::Error::Encode)?;
+    ///     arguments.add(name).map_err(sqlx::Error::Encode)?;
+    ///     batch.push(arguments);
+    /// }
+    ///
+    /// let results = conn
+    ///     .batch_bind_execute("INSERT INTO users (id, name) VALUES ($1, $2)", batch)
+    ///     .await?;
+    ///
+    /// assert_eq!(results.len(), 2);
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn batch_bind_execute(
+        &mut self,
+        sql: &str,
+        arguments: impl IntoIterator<Item = PgArguments>,
+    ) -> Result<Vec<PgQueryResult>, Error> {
+        self.batch_bind_execute_stream(sql, arguments)
+            .try_collect()
+            .await
+    }
+
+    /// Like [`batch_bind_execute()`][Self::batch_bind_execute], but streams the result of
+    /// each execution as soon as the server has sent it, instead of once the whole batch
+    /// has completed.
+    ///
+    /// Every `Execute` is followed by a `Flush`, so the server sends each result when that
+    /// execution finishes rather than holding it back until the final `Sync`. This lets the
+    /// results of earlier executions be processed while later ones are still running.
+    ///
+    /// If the stream is dropped before it is exhausted, the batch is ended with a `Sync` the
+    /// next time the connection is used. The executions already sent to the server still run
+    /// and, outside of a transaction, their changes are kept; the rest are never sent. Open a
+    /// transaction first to be able to roll back a batch that was cut short.
+    pub fn batch_bind_execute_stream<'e>(
+        &'e mut self,
+        sql: &'e str,
+        arguments: impl IntoIterator<Item = PgArguments>,
+    ) -> BoxStream<'e, Result<PgQueryResult, Error>> {
+        let mut batch: Vec<PgArguments> = arguments.into_iter().collect();
+
+        Box::pin(try_stream! {
+            let Some(first) = batch.first() else {
+                return Ok(());
+            };
+
+            let types = first.types.clone();
+
+            let num_params = u16::try_from(first.len()).map_err(|_| {
+                err_protocol!(
+                    "PgConnection::batch_bind_execute(): too many arguments for query: {}",
+                    first.len()
+                )
+            })?;
+
+            for (i, arguments) in batch.iter().enumerate() {
+                if arguments.types != types {
+                    Err(Error::Encode(
+                        format!(
+                            "PgConnection::batch_bind_execute(): argument set {i} does not have \
+                             the same types as the first"
+                        )
+                        .into(),
+                    ))?;
+                }
+
+                arguments.check_bind_size(self.inner.max_bind_size)?;
+                arguments.buffer.check_no_external("in a batch")?;
+            }
+
+            self.wait_until_ready().await?;
+
+            let (statement, metadata) = self.get_or_prepare(sql, &types, true, None).await?;
+
+            for arguments in &mut batch {
+                arguments.apply_patches(self, &metadata.parameters).await?;
+            }
+
+            self.wait_until_ready().await?;
+
+            // sends the `Sync` if the stream is dropped part of the way through
+            let mut conn = SyncOnDrop(Some(self));
+
+            for chunk in batch.chunks(PIPELINE_DEPTH) {
+                let stream = &mut conn.conn().inner.stream;
+
+                for arguments in chunk {
+                    stream.write_msg(Bind {
+                        portal: PortalId::UNNAMED,
+                        statement,
+                        formats: arguments.formats(),
+                        num_params,
+                        params: &arguments.buffer,
+                        result_formats: &[PgValueFormat::Binary],
+                    })?;
+
+                    stream.write_msg(message::Execute {
+                        portal: PortalId::UNNAMED,
+                        limit: 0,
+                    })?;
+
+                    // `Flush` rather than `Sync`, which would end the implicit transaction
+                    stream.write_msg(message::Flush)?;
+                }
+
+                stream.flush().await?;
+
+                for _ in 0..chunk.len() {
+                    match conn.conn().recv_batch_result().await {
+                        Ok(result) => r#yield!(result),
+
+                        Err(error) => {
+                            // the server skips everything up to the `Sync`
+                            conn.finish()?.wait_until_ready().await?;
+
+                            return Err(error);
+                        }
+                    }
+                }
+            }
+
+            conn.finish()?.wait_until_ready().await?;
+
+            Ok(())
+        })
+    }
+
+    /// Execute `insert` followed by a `VALUES` list of `tuples`, split over as many statements
+    /// as it takes to stay under the limit of 65535 bind parameters per statement.
+    ///
+    /// This works like [`QueryBuilder::push_values()`], which leaves staying under the limit to
+    /// the caller: `push_tuple` is called with each tuple and a [`Separated`] to push its
+    /// values to, and a new statement is started whenever the next tuple would not fit in the
+    /// current one. `insert` should end with the column list, e.g. `INSERT INTO users (id, name) `.
+    ///
+    /// The statements are executed in a single transaction (or a savepoint, if a transaction
+    /// is already open), so either every tuple is inserted or none of them are.
+    /// Returns the combined result of the statements.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::Connection;
+    /// # use sqlx::postgres::PgConnection;
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let users = (0..100_000).map(|id| (id, format!("user {id}")));
+    ///
+    /// let result = conn
+    ///     .insert_values("INSERT INTO users (id, name) ", users, |mut b, (id, name)| {
+    ///         b.push_bind(id).push_bind(name);
+    ///     })
+    ///     .await?;
+    ///
+    /// assert_eq!(result.rows_affected(), 100_000);
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn insert_values<'args, I, F>(
+        &mut self,
+        insert: &str,
+        tuples: I,
+        mut push_tuple: F,
+    ) -> Result<PgQueryResult, Error>
+    where
+        I: IntoIterator,
+        F: FnMut(Separated<'_, 'args, Postgres, &'static str>, I::Item),
+    {
+        let mut tuples = tuples.into_iter().peekable();
+
+        if tuples.peek().is_none() {
+            return Ok(PgQueryResult::default());
+        }
+
+        let mut tx = self.begin().await?;
+        let mut result = PgQueryResult::default();
+
+        let mut query = QueryBuilder::<Postgres>::new(format!("{insert}VALUES "));
+        let mut statement_tuples = 0;
+
+        for tuple in tuples {
+            let statement_len = query.sql().len();
+            let statement_arguments = query.arguments_len();
+
+            if statement_tuples > 0 {
+                query.push(", ");
+            }
+
+            let tuple_start = query.sql().len();
+
+            query.push("(");
+            push_tuple(query.separated(", "), tuple);
+            query.push(")");
+
+            // the tuple does not fit, so the statement is executed without it
+            // and the next one starts with it
+            if statement_tuples > 0 && query.arguments_len() > MAX_BIND_PARAMS {
+                let mut statement = query.build();
+
+                let sql = statement.sql();
+                let tuple_sql = renumber_placeholders(&sql[tuple_start..], statement_arguments);
+                let sql = sql[..statement_len].to_owned();
+
+                let mut arguments = statement
+                    .take_arguments()
+                    .map_err(Error::Encode)?
+                    .unwrap_or_default();
+                let tuple_arguments = arguments.split_off(statement_arguments);
+
+                result.extend(Some(
+                    sqlx_core::query::query_with(&sql, arguments)
+                        .execute(&mut *tx)
+                        .await?,
+                ));
+
+                query = QueryBuilder::with_arguments(
+                    format!("{insert}VALUES {tuple_sql}"),
+                    tuple_arguments,
+                );
+                statement_tuples = 0;
+            }
+
+            statement_tuples += 1;
+        }
+
+        result.extend(Some(query.build().execute(&mut *tx).await?));
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    // The result of the next execution of a batch
+    async fn recv_batch_result(&mut self) -> Result<PgQueryResult, Error> {
+        loop {
+            let message = self.inner.stream.recv().await?;
+
+            match message.format {
+                // rows returned by the statement are not collected
+                BackendMessageFormat::BindComplete | BackendMessageFormat::DataRow => {}
+
+                BackendMessageFormat::CommandComplete => {
+                    let cc: CommandComplete = message.decode()?;
+
+                    return Ok(PgQueryResult {
+                        rows_affected: cc.rows_affected(),
+                    });
+                }
+
+                BackendMessageFormat::EmptyQueryResponse => {
+                    return Ok(PgQueryResult::default());
+                }
+
+                _ => {
+                    return Err(err_protocol!(
+                        "batch_bind_execute: unexpected message: {:?}",
+                        message.format
+                    ));
+                }
+            }
+        }
+    }
+}
+
+// Shifts the numbers of the placeholders in `sql` down by `offset`, for a tuple moved from the end
+// of one statement to the start of the next; quoted strings and identifiers are left alone
+fn renumber_placeholders(sql: &str, offset: usize) -> String {
+    let mut renumbered = String::with_capacity(sql.len());
+    let mut quote = None;
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        renumbered.push(c);
+
+        match (quote, c) {
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+
+            (None, '$') => {
+                let mut end = i + 1;
+
+                while let Some((j, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = j + 1;
+                }
+
+                let digits = &sql[i + 1..end];
+
+                match digits.parse::<usize>() {
+                    Ok(number) if number > offset => {
+                        write!(renumbered, "{}", number - offset).unwrap();
+                    }
+
+                    _ => renumbered.push_str(digits),
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    renumbered
+}
@@ -0,0 +1,148 @@
+use std::cmp;
+use std::io;
+use std::time::Instant;
+
+use futures_core::future::BoxFuture;
+use futures_util::future::{self, Either};
+use futures_util::pin_mut;
+
+use crate::connection::tls::MaybeUpgradeTls;
+use crate::error::Error;
+use crate::io::ProtocolEncode;
+use crate::message::CancelRequest;
+use crate::net::{self, Socket};
+use crate::{PgConnectOptions, PgConnection};
+
+impl PgConnection {
+    /// Run `callback` with every query it makes on this connection bounded by `deadline`.
+    ///
+    /// If a query is still running when the deadline passes, the server is asked to cancel it,
+    /// and it fails with a database error with code `57014` (`query_canceled`). Queries started
+    /// after the deadline fail straight away, without reaching the server.
+    ///
+    /// Cancellation is sent on a separate connection, the same way `pg_cancel_backend()` or
+    /// pressing Ctrl-C in `psql` does, so it may arrive just after the query has finished.
+    /// In that case the server ignores it.
+    ///
+    /// When nested, the earlier of the two deadlines applies.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::time::{Duration, Instant};
+    /// # use sqlx::Connection;
+    /// # use sqlx::postgres::PgConnection;
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let deadline = Instant::now() + Duration::from_secs(5);
+    ///
+    /// let (orders, users): (i64, i64) = conn
+    ///     .with_deadline(deadline, |conn| {
+    ///         Box::pin(async move {
+    ///             let orders = sqlx::query_scalar("SELECT count(*) FROM orders")
+    ///                 .fetch_one(&mut *conn)
+    ///                 .await?;
+    ///
+    ///             let users = sqlx::query_scalar("SELECT count(*) FROM users")
+    ///                 .fetch_one(&mut *conn)
+    ///                 .await?;
+    ///
+    ///             Ok::<_, sqlx::Error>((orders, users))
+    ///         })
+    ///     })
+    ///     .await?;
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn with_deadline<F, R, E>(&mut self, deadline: Instant, callback: F) -> Result<R, E>
+    where
+        for<'c> F: FnOnce(&'c mut PgConnection) -> BoxFuture<'c, Result<R, E>>,
+        E: From<Error>,
+    {
+        let previous = self.inner.deadline;
+        let deadline = previous.map_or(deadline, |previous| cmp::min(previous, deadline));
+
+        let options = self.inner.options.clone();
+        let request = CancelRequest {
+            process_id: self.inner.process_id,
+            secret_key: self.inner.secret_key,
+        };
+
+        self.inner.deadline = Some(deadline);
+
+        let guard = DeadlineGuard {
+            conn: self,
+            previous,
+        };
+
+        let result = {
+            let callback = callback(&mut *guard.conn);
+            let expired = sqlx_core::rt::sleep(deadline.saturating_duration_since(Instant::now()));
+
+            pin_mut!(expired);
+
+            match future::select(callback, expired).await {
+                Either::Left((result, _)) => result,
+
+                // the query in progress (if any) is still running; once the server cancels it,
+                // the callback sees the error and returns
+                Either::Right(((), callback)) => match send_cancel_request(&options, request).await
+                {
+                    Ok(()) => callback.await,
+                    Err(error) => Err(error.into()),
+                },
+            }
+        };
+
+        result
+    }
+
+    pub(crate) fn check_deadline(&self) -> Result<(), Error> {
+        match self.inner.deadline {
+            Some(deadline) if deadline <= Instant::now() => Err(Error::Io(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "the deadline set by `PgConnection::with_deadline()` has passed",
+            ))),
+
+            _ => Ok(()),
+        }
+    }
+}
+
+// Restores the deadline that applied before `with_deadline()`, even if its future is dropped
+struct DeadlineGuard<'a> {
+    conn: &'a mut PgConnection,
+    previous: Option<Instant>,
+}
+
+impl Drop for DeadlineGuard<'_> {
+    fn drop(&mut self) {
+        self.conn.inner.deadline = self.previous;
+    }
+}
+
+// https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-FLOW-CANCELING-REQUESTS
+async fn send_cancel_request(
+    options: &PgConnectOptions,
+    request: CancelRequest,
+) -> Result<(), Error> {
+    let socket_result = match options.fetch_socket() {
+        Some(ref path) => net::connect_uds(path, MaybeUpgradeTls(options)).await?,
+        None => net::connect_tcp(&options.host, options.port, MaybeUpgradeTls(options)).await?,
+    };
+
+    let mut socket = socket_result?;
+
+    let mut buf = Vec::new();
+    request.encode(&mut buf)?;
+
+    socket.write(&buf).await?;
+    socket.flush().await?;
+
+    // the server closes the connection without replying once it has processed the request
+    let mut response = [0u8];
+    let _ = socket.read(&mut &mut response[..]).await;
+
+    Ok(())
+}
@@ -191,7 +191,8 @@ impl PgConnection {
     }
 
     async fn fetch_type_by_oid(&mut self, oid: Oid) -> Result<PgTypeInfo, Error> {
-        let (name, typ_type, category, relation_id, element, base_type): (
+        let (name, qualified_name, typ_type, category, relation_id, element, base_type): (
+            String,
             String,
             i8,
             i8,
@@ -201,14 +202,16 @@ impl PgConnection {
         ) = query_as(
             // Converting the OID to `regtype` and then `text` will give us the name that
             // the type will need to be found at by search_path.
-            "SELECT oid::regtype::text, \
-                     typtype, \
-                     typcategory, \
-                     typrelid, \
-                     typelem, \
-                     typbasetype \
-                     FROM pg_catalog.pg_type \
-                     WHERE oid = $1",
+            "SELECT t.oid::regtype::text, \
+                     pg_catalog.quote_ident(n.nspname) || '.' || pg_catalog.quote_ident(t.typname), \
+                     t.typtype, \
+                     t.typcategory, \
+                     t.typrelid, \
+                     t.typelem, \
+                     t.typbasetype \
+                     FROM pg_catalog.pg_type t \
+                     JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace \
+                     WHERE t.oid = $1",
         )
         .bind(oid)
         .fetch_one(&mut *self)
@@ -217,46 +220,35 @@ impl PgConnection {
         let typ_type = TypType::try_from(typ_type);
         let category = TypCategory::try_from(category);
 
-        match (typ_type, category) {
-            (Ok(TypType::Domain), _) => self.fetch_domain_by_oid(oid, base_type, name).await,
+        let kind = match (typ_type, category) {
+            (Ok(TypType::Domain), _) => self.fetch_domain_by_oid(base_type).await?,
 
             (Ok(TypType::Base), Ok(TypCategory::Array)) => {
-                Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-                    kind: PgTypeKind::Array(
-                        self.maybe_fetch_type_info_by_oid(element, true).await?,
-                    ),
-                    name: name.into(),
-                    oid,
-                }))))
+                PgTypeKind::Array(self.maybe_fetch_type_info_by_oid(element, true).await?)
             }
 
-            (Ok(TypType::Pseudo), Ok(TypCategory::Pseudo)) => {
-                Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-                    kind: PgTypeKind::Pseudo,
-                    name: name.into(),
-                    oid,
-                }))))
-            }
+            (Ok(TypType::Pseudo), Ok(TypCategory::Pseudo)) => PgTypeKind::Pseudo,
 
-            (Ok(TypType::Range), Ok(TypCategory::Range)) => {
-                self.fetch_range_by_oid(oid, name).await
-            }
+            (Ok(TypType::Range), Ok(TypCategory::Range)) => self.fetch_range_by_oid(oid).await?,
 
-            (Ok(TypType::Enum), Ok(TypCategory::Enum)) => self.fetch_enum_by_oid(oid, name).await,
+            (Ok(TypType::Enum), Ok(TypCategory::Enum)) => self.fetch_enum_by_oid(oid).await?,
 
             (Ok(TypType::Composite), Ok(TypCategory::Composite)) => {
-                self.fetch_composite_by_oid(oid, relation_id, name).await
+                self.fetch_composite_by_oid(relation_id).await?
             }
 
-            _ => Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-                kind: PgTypeKind::Simple,
-                name: name.into(),
-                oid,
-            })))),
-        }
+            _ => PgTypeKind::Simple,
+        };
+
+        Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+            oid,
+            name: name.into(),
+            qualified_name: Some(qualified_name.into()),
+            kind,
+        }))))
     }
 
-    async fn fetch_enum_by_oid(&mut self, oid: Oid, name: String) -> Result<PgTypeInfo, Error> {
+    async fn fetch_enum_by_oid(&mut self, oid: Oid) -> Result<PgTypeKind, Error> {
         let variants: Vec<String> = query_scalar(
             r#"
 SELECT enumlabel
@@ -269,19 +261,10 @@ ORDER BY enumsortorder
         .fetch_all(self)
         .await?;
 
-        Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-            oid,
-            name: name.into(),
-            kind: PgTypeKind::Enum(Arc::from(variants)),
-        }))))
+        Ok(PgTypeKind::Enum(Arc::from(variants)))
     }
 
-    async fn fetch_composite_by_oid(
-        &mut self,
-        oid: Oid,
-        relation_id: Oid,
-        name: String,
-    ) -> Result<PgTypeInfo, Error> {
+    async fn fetch_composite_by_oid(&mut self, relation_id: Oid) -> Result<PgTypeKind, Error> {
         let raw_fields: Vec<(String, Oid)> = query_as(
             r#"
 SELECT attname, atttypid
@@ -304,29 +287,16 @@ ORDER BY attnum
             fields.push((field_name, field_type));
         }
 
-        Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-            oid,
-            name: name.into(),
-            kind: PgTypeKind::Composite(Arc::from(fields)),
-        }))))
+        Ok(PgTypeKind::Composite(Arc::from(fields)))
     }
 
-    async fn fetch_domain_by_oid(
-        &mut self,
-        oid: Oid,
-        base_type: Oid,
-        name: String,
-    ) -> Result<PgTypeInfo, Error> {
+    async fn fetch_domain_by_oid(&mut self, base_type: Oid) -> Result<PgTypeKind, Error> {
         let base_type = self.maybe_fetch_type_info_by_oid(base_type, true).await?;
 
-        Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-            oid,
-            name: name.into(),
-            kind: PgTypeKind::Domain(base_type),
-        }))))
+        Ok(PgTypeKind::Domain(base_type))
     }
 
-    async fn fetch_range_by_oid(&mut self, oid: Oid, name: String) -> Result<PgTypeInfo, Error> {
+    async fn fetch_range_by_oid(&mut self, oid: Oid) -> Result<PgTypeKind, Error> {
         let element_oid: Oid = query_scalar(
             r#"
 SELECT rngsubtype
@@ -340,11 +310,7 @@ WHERE rngtypid = $1
 
         let element = self.maybe_fetch_type_info_by_oid(element_oid, true).await?;
 
-        Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
-            kind: PgTypeKind::Range(element),
-            name: name.into(),
-            oid,
-        }))))
+        Ok(PgTypeKind::Range(element))
     }
 
     pub(crate) async fn resolve_type_id(&mut self, ty: &PgType) -> Result<Oid, Error> {
@@ -360,9 +326,21 @@ WHERE rngtypid = $1
         }
     }
 
+    pub(crate) fn cached_type_id_by_name(&self, name: &str) -> Option<Oid> {
+        self.inner.cache_type_oid.get(name).copied()
+    }
+
+    pub(crate) fn cached_array_type_id(&self, array: &PgArrayOf) -> Option<Oid> {
+        self.inner
+            .cache_type_oid
+            .get(&array.elem_name)
+            .and_then(|elem_oid| self.inner.cache_elem_type_to_array.get(elem_oid))
+            .copied()
+    }
+
     pub(crate) async fn fetch_type_id_by_name(&mut self, name: &str) -> Result<Oid, Error> {
-        if let Some(oid) = self.inner.cache_type_oid.get(name) {
-            return Ok(*oid);
+        if let Some(oid) = self.cached_type_id_by_name(name) {
+            return Ok(oid);
         }
 
         // language=SQL
@@ -380,14 +358,62 @@ WHERE rngtypid = $1
         Ok(oid)
     }
 
+    /// Look up the OIDs of the types named `names` (and of their array types) in a single query,
+    /// caching them on this connection.
+    ///
+    /// Types referred to by name, such as with [`PgTypeInfo::with_name()`], are otherwise looked
+    /// up one at a time, the first time each is bound. Calling this once after connecting,
+    /// such as in [`PgPoolOptions::after_connect()`][crate::PgPoolOptions::after_connect],
+    /// saves a round-trip per type.
+    ///
+    /// Names are resolved like any other type name in SQL, including the current `search_path`.
+    /// Returns [`Error::TypeNotFound`] for the first name that does not refer to a type,
+    /// in which case none of the types are cached.
+    pub async fn cache_type_oids(&mut self, names: &[&str]) -> Result<(), Error> {
+        let names: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|name| !self.inner.cache_type_oid.contains_key(*name))
+            .collect();
+
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        // language=SQL
+        let types: Vec<(String, Option<Oid>, Option<Oid>)> = query_as(
+            "SELECT n.name, t.oid, t.typarray \
+             FROM UNNEST($1::text[]) WITH ORDINALITY AS n(name, ord) \
+             LEFT JOIN pg_catalog.pg_type t ON t.oid = pg_catalog.to_regtype(n.name) \
+             ORDER BY n.ord",
+        )
+        .bind(&names)
+        .fetch_all(&mut *self)
+        .await?;
+
+        if let Some((name, ..)) = types.iter().find(|(_, oid, _)| oid.is_none()) {
+            return Err(Error::TypeNotFound {
+                type_name: name.clone(),
+            });
+        }
+
+        for (name, oid, array_oid) in types {
+            let Some(oid) = oid else { continue };
+
+            self.inner.cache_type_oid.insert(name.into(), oid);
+
+            // `0` if the type has no array type
+            if let Some(array_oid) = array_oid.filter(|array_oid| array_oid.0 != 0) {
+                self.inner.cache_elem_type_to_array.insert(oid, array_oid);
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) async fn fetch_array_type_id(&mut self, array: &PgArrayOf) -> Result<Oid, Error> {
-        if let Some(oid) = self
-            .inner
-            .cache_type_oid
-            .get(&array.elem_name)
-            .and_then(|elem_oid| self.inner.cache_elem_type_to_array.get(elem_oid))
-        {
-            return Ok(*oid);
+        if let Some(oid) = self.cached_array_type_id(array) {
+            return Ok(oid);
         }
 
         // language=SQL
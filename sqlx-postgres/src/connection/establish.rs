@@ -1,13 +1,18 @@
+use std::collections::BTreeMap;
+use std::io;
+
 use crate::HashMap;
 
 use crate::common::StatementCache;
-use crate::connection::{sasl, stream::PgStream};
+use crate::connection::{sasl, stream::PgStream, Connection};
 use crate::error::Error;
 use crate::io::StatementId;
 use crate::message::{
     Authentication, BackendKeyData, BackendMessageFormat, Password, ReadyForQuery, Startup,
 };
-use crate::{PgConnectOptions, PgConnection};
+use crate::options::{binary_application_name, suffix_application_name};
+use crate::query_scalar::query_scalar;
+use crate::{PgChannelBinding, PgConnectOptions, PgConnection, PgTargetSessionAttrs};
 
 use super::PgConnectionInner;
 
@@ -16,6 +21,32 @@ use super::PgConnectionInner;
 
 impl PgConnection {
     pub(crate) async fn establish(options: &PgConnectOptions) -> Result<Self, Error> {
+        check_ssl_min_protocol_version(options)?;
+
+        // a Unix domain socket takes precedence over any host
+        if options.hosts.is_empty() || options.fetch_socket().is_some() {
+            return Self::establish_session(options).await;
+        }
+
+        let mut last_error = None;
+
+        for (host, port) in &options.hosts {
+            let options = options.clone().host(host).port(*port);
+
+            match Self::establish_session(&options).await {
+                Ok(conn) => return Ok(conn),
+
+                Err(error) => {
+                    tracing::debug!(%host, port, %error, "skipping host");
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.expect("BUG: tried no hosts"))
+    }
+
+    async fn establish_session(options: &PgConnectOptions) -> Result<Self, Error> {
         // Upgrade to TLS if we were asked to and the server supports it
         let mut stream = PgStream::connect(options).await?;
 
@@ -26,6 +57,8 @@ impl PgConnection {
             // Sets the display format for date and time values,
             // as well as the rules for interpreting ambiguous date input values.
             ("DateStyle", "ISO, MDY"),
+            // Sets the display format for interval values, which text-mode decoding relies on.
+            ("IntervalStyle", "postgres"),
             // Sets the client-side encoding (character set).
             // <https://www.postgresql.org/docs/devel/multibyte.html#MULTIBYTE-CHARSET-SUPPORTED>
             ("client_encoding", "UTF8"),
@@ -37,7 +70,21 @@ impl PgConnection {
             params.push(("extra_float_digits", extra_float_digits));
         }
 
-        if let Some(ref application_name) = options.application_name {
+        let derived_application_name = (options.application_name.is_none()
+            && options.application_name_from_binary)
+            .then(binary_application_name)
+            .flatten();
+
+        let application_name = options
+            .application_name
+            .as_deref()
+            .or(derived_application_name.as_deref());
+
+        let suffixed_application_name = options
+            .application_name_suffix
+            .map(|suffix| suffix_application_name(application_name, &suffix.generate()));
+
+        if let Some(application_name) = suffixed_application_name.as_deref().or(application_name) {
             params.push(("application_name", application_name));
         }
 
@@ -45,6 +92,20 @@ impl PgConnection {
             params.push(("options", options));
         }
 
+        if options.logical_replication {
+            params.push(("replication", "database"));
+        }
+
+        for (key, value) in &options.extra_startup_parameters {
+            check_startup_parameter(key, value)?;
+
+            // setting names are case-insensitive
+            match params.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case(key)) {
+                Some(param) => param.1 = value,
+                None => params.push((key, value)),
+            }
+        }
+
         stream.write(Startup {
             username: Some(&options.username),
             database: options.database.as_deref(),
@@ -66,6 +127,18 @@ impl PgConnection {
             let message = stream.recv().await?;
             match message.format {
                 BackendMessageFormat::Authentication => match message.decode()? {
+                    // channel binding is only done with SCRAM, which refuses it if required
+                    Authentication::Ok
+                    | Authentication::CleartextPassword
+                    | Authentication::Md5Password(_)
+                        if options.channel_binding == PgChannelBinding::Require =>
+                    {
+                        return Err(err_protocol!(
+                            "channel binding is required, but the server does not authenticate \
+                             with SCRAM"
+                        ));
+                    }
+
                     Authentication::Ok => {
                         // the authentication exchange is successfully completed
                         // do nothing; no more information is required to continue
@@ -135,21 +208,161 @@ impl PgConnection {
             }
         }
 
-        Ok(PgConnection {
+        check_integer_datetimes(&stream.parameter_statuses)?;
+
+        let mut conn = PgConnection {
             inner: Box::new(PgConnectionInner {
                 stream,
                 process_id,
                 secret_key,
+                options: options.clone(),
+                deadline: None,
                 transaction_status,
                 transaction_depth: 0,
                 pending_ready_for_query_count: 0,
+                copy_in_aborted: false,
+                bind_interrupted: false,
                 next_statement_id: StatementId::NAMED_START,
                 cache_statement: StatementCache::new(options.statement_cache_capacity),
                 cache_type_oid: HashMap::new(),
                 cache_type_info: HashMap::new(),
                 cache_elem_type_to_array: HashMap::new(),
                 log_settings: options.log_settings.clone(),
+                log_parameters: options.log_parameters,
+                parameter_mask: options.parameter_mask.clone(),
+                max_bind_size: options.max_bind_size,
+                prefer_simple_protocol: options.prefer_simple_protocol,
             }),
-        })
+        };
+
+        if options.target_session_attrs == PgTargetSessionAttrs::ReadWrite {
+            if let Err(error) = conn.check_read_write(options).await {
+                // the session itself is fine, so say goodbye instead of just hanging up
+                let _ = conn.close().await;
+                return Err(error);
+            }
+        }
+
+        Ok(conn)
+    }
+
+    // Rejects a session that is read-only by default, as on a hot standby, the same way
+    // `libpq` does for `target_session_attrs=read-write`.
+    async fn check_read_write(&mut self, options: &PgConnectOptions) -> Result<(), Error> {
+        let read_only: String = query_scalar("SHOW transaction_read_only")
+            .fetch_one(&mut *self)
+            .await?;
+
+        if read_only != "on" {
+            return Ok(());
+        }
+
+        // refused like a primary that is down, which a pool retries during a failover
+        Err(Error::Io(io::Error::new(
+            io::ErrorKind::ConnectionRefused,
+            format!(
+                "server at {}:{} only accepts read-only sessions",
+                options.host, options.port
+            ),
+        )))
+    }
+}
+
+// Parameters the startup packet reserves for itself, or that the driver relies on
+const RESERVED_STARTUP_PARAMETERS: &[&str] = &[
+    "user",
+    "database",
+    "options",
+    "replication",
+    "client_encoding",
+    "DateStyle",
+    "IntervalStyle",
+];
+
+fn check_startup_parameter(key: &str, value: &str) -> Result<(), Error> {
+    let reason = if key.is_empty() {
+        "the name is empty"
+    } else if key.contains('\0') || value.contains('\0') {
+        "it contains a NUL byte"
+    } else if key.starts_with("_pq_.") {
+        "names starting with `_pq_.` are reserved for protocol extensions"
+    } else if RESERVED_STARTUP_PARAMETERS
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(key))
+    {
+        "it is set by SQLx itself"
+    } else {
+        return Ok(());
+    };
+
+    Err(Error::Configuration(
+        format!("invalid startup parameter {key:?}: {reason}").into(),
+    ))
+}
+
+// An unknown `PGSSLMINPROTOCOLVERSION` must not quietly allow any TLS version.
+fn check_ssl_min_protocol_version(options: &PgConnectOptions) -> Result<(), Error> {
+    match &options.invalid_ssl_min_protocol_version {
+        Some(version) => Err(Error::Configuration(
+            format!("PGSSLMINPROTOCOLVERSION: unknown or unsupported TLS version {version:?}")
+                .into(),
+        )),
+
+        None => Ok(()),
+    }
+}
+
+// The binary formats of `TIMESTAMP`, `TIMESTAMPTZ`, `TIME` and `INTERVAL` are decoded as integer
+// microseconds, which servers built with `--disable-integer-datetimes` (before Postgres 10) do
+// not send. Rejecting those up front beats decoding every date and time into garbage.
+fn check_integer_datetimes(parameter_statuses: &BTreeMap<String, String>) -> Result<(), Error> {
+    match parameter_statuses
+        .get("integer_datetimes")
+        .map(String::as_str)
+    {
+        Some("off") => Err(err_protocol!(
+            "the server stores dates and times as floating-point numbers \
+             (integer_datetimes = off), which SQLx does not support"
+        )),
+
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::error::Error;
+
+    use super::{check_integer_datetimes, check_ssl_min_protocol_version};
+    use crate::{PgConnectOptions, TlsVersion};
+
+    #[test]
+    fn it_rejects_floating_point_datetimes() {
+        let mut parameter_statuses = BTreeMap::new();
+
+        // not reported at all, e.g. by some databases speaking the protocol
+        assert!(check_integer_datetimes(&parameter_statuses).is_ok());
+
+        parameter_statuses.insert("integer_datetimes".to_owned(), "on".to_owned());
+        assert!(check_integer_datetimes(&parameter_statuses).is_ok());
+
+        parameter_statuses.insert("integer_datetimes".to_owned(), "off".to_owned());
+        let err = check_integer_datetimes(&parameter_statuses).unwrap_err();
+        assert!(err.to_string().contains("integer_datetimes = off"), "{err}");
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_ssl_min_protocol_version() {
+        let mut options = PgConnectOptions::new_without_pgpass();
+        options.invalid_ssl_min_protocol_version = Some("TLSv1.1".to_owned());
+
+        let err = check_ssl_min_protocol_version(&options).unwrap_err();
+        assert!(matches!(err, Error::Configuration(_)), "{err:?}");
+
+        // an explicit version replaces the one from the environment
+        let options = options.min_tls_version(TlsVersion::Tls13);
+        assert!(check_ssl_min_protocol_version(&options).is_ok());
     }
 }
@@ -1,16 +1,17 @@
 use crate::describe::Describe;
-use crate::error::Error;
+use crate::error::{DatabaseError, Error};
 use crate::executor::{Execute, Executor};
 use crate::io::{PortalId, StatementId};
 use crate::logger::QueryLogger;
 use crate::message::{
     self, BackendMessageFormat, Bind, Close, CommandComplete, DataRow, ParameterDescription, Parse,
-    ParseComplete, Query, RowDescription,
+    ParseComplete, Query, RowDescription, TransactionStatus,
 };
+use crate::sqlstate;
 use crate::statement::PgStatementMetadata;
 use crate::{
-    statement::PgStatement, PgArguments, PgConnection, PgQueryResult, PgRow, PgTypeInfo,
-    PgValueFormat, Postgres,
+    statement::PgStatement, PgArguments, PgConnection, PgDatabaseError, PgQueryResult, PgRow,
+    PgTypeInfo, PgValueFormat, Postgres,
 };
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -20,6 +21,20 @@ use sqlx_core::arguments::Arguments;
 use sqlx_core::Either;
 use std::{borrow::Cow, pin::pin, sync::Arc};
 
+// A cached statement whose result columns changed since it was prepared, e.g. because a
+// column it returns had its type altered, can no longer be executed and must be prepared again.
+//
+// The message is translated according to `lc_messages`, so the error is recognized by the
+// server routine that raises it instead.
+fn is_stale_cached_plan(error: &dyn DatabaseError) -> bool {
+    error
+        .try_downcast_ref::<PgDatabaseError>()
+        .is_some_and(|e| {
+            e.code() == sqlstate::FEATURE_NOT_SUPPORTED
+                && e.routine() == Some("RevalidateCachedQuery")
+        })
+}
+
 async fn prepare(
     conn: &mut PgConnection,
     sql: &str,
@@ -159,7 +174,7 @@ impl PgConnection {
         self.inner.pending_ready_for_query_count += 1;
     }
 
-    async fn get_or_prepare<'a>(
+    pub(super) async fn get_or_prepare<'a>(
         &mut self,
         sql: &str,
         parameters: &[PgTypeInfo],
@@ -190,6 +205,85 @@ impl PgConnection {
         Ok(statement)
     }
 
+    // Writes the messages that execute `statement` with `arguments`, followed by a `Sync`.
+    async fn write_bind_execute(
+        &mut self,
+        statement: StatementId,
+        num_params: u16,
+        arguments: &PgArguments,
+        limit: u8,
+    ) -> Result<(), Error> {
+        // bind to attach the arguments to the statement and create a portal
+        let bind = Bind {
+            portal: PortalId::UNNAMED,
+            statement,
+            formats: arguments.formats(),
+            num_params,
+            params: &arguments.buffer,
+            result_formats: &[PgValueFormat::Binary],
+        };
+
+        match arguments.buffer.external() {
+            [] => self.inner.stream.write_msg(bind)?,
+
+            // values bound with `PgLargeBytes` are sent from where they are
+            external => {
+                // if this is cancelled, only part of the `Bind` has been sent
+                self.inner.bind_interrupted = true;
+
+                self.inner
+                    .stream
+                    .write_bind_external(bind, external)
+                    .await?;
+
+                self.inner.bind_interrupted = false;
+            }
+        }
+
+        // executes the portal up to the passed limit
+        // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+        self.inner.stream.write_msg(message::Execute {
+            portal: PortalId::UNNAMED,
+            limit: limit.into(),
+        })?;
+        // From https://www.postgresql.org/docs/current/protocol-flow.html:
+        //
+        // "An unnamed portal is destroyed at the end of the transaction, or as
+        // soon as the next Bind statement specifying the unnamed portal as
+        // destination is issued. (Note that a simple Query message also
+        // destroys the unnamed portal."
+
+        // we ask the database server to close the unnamed portal and free the associated resources
+        // earlier - after the execution of the current query.
+        self.inner
+            .stream
+            .write_msg(Close::Portal(PortalId::UNNAMED))?;
+
+        // finally, [Sync] asks postgres to process the messages that we sent and respond with
+        // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
+        // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
+        // is still serial but it would reduce round-trips. Some kind of builder pattern that is
+        // termed batching might suit this.
+        self.write_sync();
+
+        Ok(())
+    }
+
+    // Removes the statement prepared for `sql` from the cache and closes it on the server.
+    async fn deallocate_cached_statement(&mut self, sql: &str) -> Result<(), Error> {
+        if let Some((id, _)) = self.inner.cache_statement.remove(sql) {
+            self.inner.stream.write_msg(Close::Statement(id))?;
+            self.write_sync();
+
+            self.inner.stream.flush().await?;
+
+            self.wait_for_close_complete(1).await?;
+            self.recv_ready_for_query().await?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
         query: &'q str,
@@ -200,11 +294,31 @@ impl PgConnection {
     ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
         let mut logger = QueryLogger::new(query, self.inner.log_settings.clone());
 
+        self.check_deadline()?;
+
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
 
         let mut metadata: Arc<PgStatementMetadata>;
 
+        // the statement and arguments to execute again, if it has to be re-prepared
+        let mut reprepare = None;
+        let sql = query;
+
+        if let Some(arguments) = &arguments {
+            self.log_parameters(sql, arguments);
+        }
+
+        // without prepared statements, the arguments are inlined and the query sent as text
+        let inlined_query;
+        let (query, arguments) = match arguments {
+            Some(arguments) if self.inner.prefer_simple_protocol => {
+                inlined_query = self.inline_arguments(query, arguments).await?;
+                (&*inlined_query, None)
+            }
+            arguments => (query, arguments),
+        };
+
         let format = if let Some(mut arguments) = arguments {
             // Check this before we write anything to the stream.
             //
@@ -219,6 +333,8 @@ impl PgConnection {
                 )
             })?;
 
+            arguments.check_bind_size(self.inner.max_bind_size)?;
+
             // prepare the statement if this our first time executing it
             // always return the statement ID here
             let (statement, metadata_) = self
@@ -233,41 +349,13 @@ impl PgConnection {
             // consume messages till `ReadyForQuery` before bind and execute
             self.wait_until_ready().await?;
 
-            // bind to attach the arguments to the statement and create a portal
-            self.inner.stream.write_msg(Bind {
-                portal: PortalId::UNNAMED,
-                statement,
-                formats: &[PgValueFormat::Binary],
-                num_params,
-                params: &arguments.buffer,
-                result_formats: &[PgValueFormat::Binary],
-            })?;
+            self.write_bind_execute(statement, num_params, &arguments, limit)
+                .await?;
 
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.inner.stream.write_msg(message::Execute {
-                portal: PortalId::UNNAMED,
-                limit: limit.into(),
-            })?;
-            // From https://www.postgresql.org/docs/current/protocol-flow.html:
-            //
-            // "An unnamed portal is destroyed at the end of the transaction, or as
-            // soon as the next Bind statement specifying the unnamed portal as
-            // destination is issued. (Note that a simple Query message also
-            // destroys the unnamed portal."
-
-            // we ask the database server to close the unnamed portal and free the associated resources
-            // earlier - after the execution of the current query.
-            self.inner
-                .stream
-                .write_msg(Close::Portal(PortalId::UNNAMED))?;
-
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+            // only a statement kept in the cache can be invalidated by a schema change
+            if persistent {
+                reprepare = Some((sql, num_params, arguments));
+            }
 
             // prepared statements are binary
             PgValueFormat::Binary
@@ -287,7 +375,41 @@ impl PgConnection {
 
         Ok(try_stream! {
             loop {
-                let message = self.inner.stream.recv().await?;
+                let message = match self.inner.stream.recv().await {
+                    Ok(message) => message,
+
+                    Err(Error::Database(error)) if is_stale_cached_plan(&*error) => {
+                        let Some((sql, num_params, arguments)) = reprepare.take() else {
+                            return Err(Error::Database(error));
+                        };
+
+                        // the error aborted the query before it returned anything
+                        self.wait_until_ready().await?;
+
+                        // the failed query also aborted the transaction it was part of,
+                        // in which case executing the statement again would fail as well
+                        if !matches!(self.inner.transaction_status, TransactionStatus::Idle) {
+                            return Err(Error::Database(error));
+                        }
+
+                        self.deallocate_cached_statement(sql).await?;
+
+                        let (statement, metadata_) = self
+                            .get_or_prepare(sql, &arguments.types, true, None)
+                            .await?;
+
+                        metadata = metadata_;
+
+                        self.wait_until_ready().await?;
+                        self.write_bind_execute(statement, num_params, &arguments, limit)
+                            .await?;
+                        self.inner.stream.flush().await?;
+
+                        continue;
+                    }
+
+                    Err(error) => return Err(error),
+                };
 
                 match message.format {
                     BackendMessageFormat::BindComplete
@@ -316,7 +438,9 @@ impl PgConnection {
                     }
 
                     BackendMessageFormat::EmptyQueryResponse => {
-                        // empty query string passed to an unprepared execute
+                        // the query string was empty or only contained comments,
+                        // which completes like a command that affected no rows
+                        r#yield!(Either::Left(PgQueryResult::default()));
                     }
 
                     // Message::ErrorResponse is handled in self.stream.recv()
@@ -346,6 +470,8 @@ impl PgConnection {
                             data,
                             format,
                             metadata: Arc::clone(&metadata),
+                            #[cfg(feature = "chrono-tz")]
+                            time_zone: self.inner.stream.time_zone.clone(),
                         };
 
                         r#yield!(Either::Right(row));
@@ -369,6 +495,80 @@ impl PgConnection {
             Ok(())
         })
     }
+
+    /// Execute `sql` as a prepared statement whose parameters have the given types,
+    /// without asking the server to describe the statement first.
+    ///
+    /// Preparing a statement normally costs a `Describe` of its parameters and columns.
+    /// Here `Parse` is sent with `parameter_types` instead, and the statement is executed
+    /// right away; it is not added to the statement cache. Any rows returned by the
+    /// statement are discarded.
+    ///
+    /// # Note: types are not checked
+    /// `parameter_types` is trusted as-is. If a type does not match the encoding of the
+    /// corresponding argument (e.g. `INT8` for an `i32`), the server reads the bytes as the
+    /// declared type, which either fails or silently stores a different value.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::{Arguments, Connection, Type};
+    /// # use sqlx::postgres::{PgArguments, PgConnection, Postgres};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut arguments = PgArguments::default();
+    /// arguments.add(1_i64).map_err(sqlx::Error::Encode)?;
+    ///
+    /// conn.execute_prepared_unchecked(
+    ///     "DELETE FROM users WHERE id = $1",
+    ///     &[<i64 as Type<Postgres>>::type_info()],
+    ///     arguments,
+    /// )
+    /// .await?;
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn execute_prepared_unchecked(
+        &mut self,
+        sql: &str,
+        parameter_types: &[PgTypeInfo],
+        mut arguments: PgArguments,
+    ) -> Result<PgQueryResult, Error> {
+        if parameter_types.len() != arguments.len() {
+            return Err(Error::Encode(
+                format!(
+                    "PgConnection::execute_prepared_unchecked(): {} parameter types given \
+                     for {} arguments",
+                    parameter_types.len(),
+                    arguments.len()
+                )
+                .into(),
+            ));
+        }
+
+        arguments.types = parameter_types.to_vec();
+
+        // metadata supplied up front is what lets `prepare()` skip the `Describe`
+        let metadata = Arc::new(PgStatementMetadata {
+            parameters: parameter_types.to_vec(),
+            ..PgStatementMetadata::default()
+        });
+
+        let mut s = pin!(
+            self.run(sql, Some(arguments), 0, false, Some(metadata))
+                .await?
+        );
+        let mut result = PgQueryResult::default();
+
+        while let Some(v) = s.try_next().await? {
+            if let Either::Left(done) = v {
+                result.extend(Some(done));
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 impl<'c> Executor<'c> for &'c mut PgConnection {
@@ -0,0 +1,443 @@
+use std::cmp;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use futures_core::stream::{BoxStream, Stream};
+use sqlx_core::arguments::Arguments;
+use sqlx_core::Either;
+
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::io::PortalId;
+use crate::message::{self, BackendMessageFormat, Bind, Close};
+use crate::statement::PgStatementMetadata;
+use crate::{PgConnection, PgQueryResult, PgRow, PgValueFormat, Postgres};
+
+// Rows requested by the first round-trip of an adaptive fetch, before any row sizes are known
+const ADAPTIVE_INITIAL_ROWS: u32 = 64;
+
+// Postgres reads the row limit of `Execute` as a signed 32-bit integer
+const MAX_ROWS: u32 = i32::MAX.unsigned_abs();
+
+/// How many rows [`PgConnection::fetch_batches()`] asks for in each round-trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgFetchSize {
+    /// Always fetch this many rows per round-trip. Zero fetches every row in one round-trip.
+    Fixed(u32),
+
+    /// Size each round-trip so that it returns roughly `byte_budget` bytes of row data,
+    /// based on the average size of the rows received so far.
+    Adaptive { byte_budget: usize },
+}
+
+impl PgFetchSize {
+    fn next_limit(&self, rows: u64, bytes: u64) -> u32 {
+        match *self {
+            PgFetchSize::Fixed(limit) => cmp::min(limit, MAX_ROWS),
+
+            PgFetchSize::Adaptive { .. } if rows == 0 => ADAPTIVE_INITIAL_ROWS,
+
+            PgFetchSize::Adaptive { byte_budget } => {
+                let average = cmp::max(bytes.div_ceil(rows), 1);
+                let limit = (byte_budget as u64) / average;
+
+                u32::try_from(limit).unwrap_or(MAX_ROWS).clamp(1, MAX_ROWS)
+            }
+        }
+    }
+}
+
+/// A stream of the rows returned by a query, which also collects the query's [`PgQueryResult`].
+///
+/// Returned by [`PgConnection::fetch_with_result()`].
+pub struct PgFetchWithResult<'e> {
+    stream: BoxStream<'e, Result<Either<PgQueryResult, PgRow>, Error>>,
+    result: PgQueryResult,
+}
+
+impl PgFetchWithResult<'_> {
+    /// The result of the statements that have completed so far, such as the number of rows
+    /// an `UPDATE` affected.
+    ///
+    /// This is only final once the stream has ended.
+    pub fn query_result(&self) -> &PgQueryResult {
+        &self.result
+    }
+}
+
+impl Stream for PgFetchWithResult<'_> {
+    type Item = Result<PgRow, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.stream.as_mut().poll_next(cx)) {
+                Some(Ok(Either::Left(result))) => self.result.extend(Some(result)),
+                Some(Ok(Either::Right(row))) => return Poll::Ready(Some(Ok(row))),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl PgConnection {
+    /// Execute `query` and stream the rows it returns, keeping its [`PgQueryResult`] for
+    /// once the stream has ended.
+    ///
+    /// This is useful for statements that both return rows and affect them,
+    /// such as `UPDATE ... RETURNING`, where both the rows and the number of rows affected
+    /// are of interest.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use futures_util::TryStreamExt;
+    /// # use sqlx::{Connection, Row};
+    /// # use sqlx::postgres::PgConnection;
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut rows =
+    ///     conn.fetch_with_result(sqlx::query("UPDATE users SET active = false RETURNING id"));
+    ///
+    /// while let Some(row) = rows.try_next().await? {
+    ///     let id: i64 = row.try_get("id")?;
+    /// }
+    ///
+    /// let deactivated = rows.query_result().rows_affected();
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub fn fetch_with_result<'e, 'q: 'e, E>(&'e mut self, query: E) -> PgFetchWithResult<'e>
+    where
+        E: Execute<'q, Postgres> + 'q,
+    {
+        PgFetchWithResult {
+            stream: self.fetch_many(query),
+            result: PgQueryResult::default(),
+        }
+    }
+
+    /// Execute `query` and stream its rows in batches, sizing each round-trip to return
+    /// about `byte_budget` bytes.
+    ///
+    /// This is shorthand for
+    /// [`fetch_batches(query, PgFetchSize::Adaptive { byte_budget })`][Self::fetch_batches].
+    ///
+    /// Narrow rows are fetched many at a time, keeping the number of round-trips low,
+    /// while wide rows are fetched a few at a time, bounding how much is buffered at once.
+    pub fn adaptive_fetch<'e, 'q: 'e, E>(
+        &'e mut self,
+        query: E,
+        byte_budget: usize,
+    ) -> BoxStream<'e, Result<Vec<PgRow>, Error>>
+    where
+        E: Execute<'q, Postgres> + 'q,
+    {
+        self.fetch_batches(query, PgFetchSize::Adaptive { byte_budget })
+    }
+
+    /// Execute `query` and stream its rows in batches, one batch per round-trip.
+    ///
+    /// Unlike [`fetch()`][crate::query::Query::fetch], which asks for every row at once
+    /// and leaves the server to push them as fast as it can, this suspends the query after
+    /// each batch and only resumes it once the batch has been consumed.
+    ///
+    /// The query always uses the extended query protocol, even without arguments.
+    ///
+    /// If the stream is dropped before it is exhausted, the rest of the result set is
+    /// discarded the next time the connection is used.
+    pub fn fetch_batches<'e, 'q: 'e, E>(
+        &'e mut self,
+        query: E,
+        size: PgFetchSize,
+    ) -> BoxStream<'e, Result<Vec<PgRow>, Error>>
+    where
+        E: Execute<'q, Postgres> + 'q,
+    {
+        Box::pin(try_stream! {
+            let mut portal = self.bind_portal(query).await?;
+
+            let mut rows_fetched = 0;
+            let mut bytes_fetched = 0;
+
+            while !portal.is_complete() {
+                let limit = size.next_limit(rows_fetched, bytes_fetched);
+
+                let batch = portal.execute(limit).await?;
+
+                rows_fetched += batch.len() as u64;
+                bytes_fetched += batch
+                    .iter()
+                    .map(|row| row.data.storage.len() as u64)
+                    .sum::<u64>();
+
+                if !batch.is_empty() {
+                    r#yield!(batch);
+                }
+            }
+
+            portal.close().await?;
+
+            Ok(())
+        })
+    }
+
+    /// Bind the arguments of `query` to a portal, which can then be executed a few rows at a
+    /// time with [`PgPortal::execute()`].
+    ///
+    /// The statement is prepared (or taken from the cache) first, as for any other query.
+    /// Nothing is executed until the first call to `execute()`.
+    ///
+    /// The portal keeps the connection borrowed until it is closed or dropped. Like any
+    /// unnamed portal, it only lives until the end of the current transaction, which without
+    /// `BEGIN` is the end of the query itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::{Connection, Row};
+    /// # use sqlx::postgres::PgConnection;
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut portal = conn.bind_portal(sqlx::query("SELECT id FROM users")).await?;
+    ///
+    /// // the first 100 users
+    /// let first = portal.execute(100).await?;
+    ///
+    /// if portal.is_suspended() {
+    ///     // and the next 100
+    ///     let next = portal.execute(100).await?;
+    /// }
+    ///
+    /// portal.close().await?;
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn bind_portal<'c, 'q, E>(&'c mut self, mut query: E) -> Result<PgPortal<'c>, Error>
+    where
+        E: Execute<'q, Postgres> + 'q,
+    {
+        let sql = query.sql();
+        // False positive: https://github.com/rust-lang/rust-clippy/issues/12560
+        #[allow(clippy::map_clone)]
+        let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
+        let mut arguments = query
+            .take_arguments()
+            .map_err(Error::Encode)?
+            .unwrap_or_default();
+        let persistent = query.persistent();
+
+        self.wait_until_ready().await?;
+
+        let num_params = u16::try_from(arguments.len()).map_err(|_| {
+            err_protocol!(
+                "PgConnection::bind_portal(): too many arguments for query: {}",
+                arguments.len()
+            )
+        })?;
+
+        arguments.check_bind_size(self.inner.max_bind_size)?;
+        arguments.buffer.check_no_external("to a portal")?;
+
+        let (statement, metadata) = self
+            .get_or_prepare(sql, &arguments.types, persistent, metadata)
+            .await?;
+
+        arguments.apply_patches(self, &metadata.parameters).await?;
+
+        self.wait_until_ready().await?;
+
+        // From here until the `Sync` the unnamed portal stays open between round-trips,
+        // so the guard makes sure a `Sync` is sent even if we bail out early.
+        let mut conn = SyncOnDrop(Some(self));
+
+        conn.conn().inner.stream.write_msg(Bind {
+            portal: PortalId::UNNAMED,
+            statement,
+            formats: arguments.formats(),
+            num_params,
+            params: &arguments.buffer,
+            result_formats: &[PgValueFormat::Binary],
+        })?;
+
+        Ok(PgPortal {
+            conn,
+            metadata,
+            complete: false,
+            executed: false,
+            failed: false,
+        })
+    }
+}
+
+/// A query bound to a portal, whose rows can be fetched a few at a time.
+///
+/// Returned by [`PgConnection::bind_portal()`].
+pub struct PgPortal<'c> {
+    conn: SyncOnDrop<'c>,
+    metadata: Arc<PgStatementMetadata>,
+    // the query has run to completion, so there are no more rows
+    complete: bool,
+    executed: bool,
+    // an earlier call returned an error, which ended the query on the server
+    failed: bool,
+}
+
+impl PgPortal<'_> {
+    /// Run the query until it has returned up to `max_rows` more rows, and return them.
+    ///
+    /// The query is then suspended, to be resumed by the next call, unless it ran out of rows
+    /// first. A `max_rows` of zero fetches every remaining row. Once the query is complete,
+    /// this returns no rows without contacting the server.
+    ///
+    /// If the query fails, the portal is closed by the server, so this and every later call
+    /// return an error. The connection can still be used once the portal is closed or dropped.
+    pub async fn execute(&mut self, max_rows: u32) -> Result<Vec<PgRow>, Error> {
+        if self.failed {
+            return Err(err_protocol!(
+                "PgPortal::execute: the query failed on an earlier call"
+            ));
+        }
+
+        if self.complete {
+            return Ok(Vec::new());
+        }
+
+        let result = self.execute_inner(max_rows).await;
+
+        if result.is_err() {
+            self.failed = true;
+
+            // the server skips everything up to the next `Sync` after an error, so buffer
+            // one now for `wait_until_ready()` to find the end of the query by
+            self.conn.conn().write_sync();
+        }
+
+        result
+    }
+
+    async fn execute_inner(&mut self, max_rows: u32) -> Result<Vec<PgRow>, Error> {
+        let conn = self.conn.conn();
+
+        conn.inner.stream.write_msg(message::Execute {
+            portal: PortalId::UNNAMED,
+            limit: cmp::min(max_rows, MAX_ROWS),
+        })?;
+
+        // `Flush` rather than `Sync`, which would end the implicit transaction
+        // and destroy the portal along with it
+        conn.inner.stream.write_msg(message::Flush)?;
+        conn.inner.stream.flush().await?;
+
+        self.executed = true;
+
+        let mut rows = Vec::new();
+
+        loop {
+            let message = conn.inner.stream.recv().await?;
+
+            match message.format {
+                BackendMessageFormat::BindComplete => {}
+
+                BackendMessageFormat::DataRow => {
+                    rows.push(PgRow {
+                        data: message.decode()?,
+                        format: PgValueFormat::Binary,
+                        metadata: Arc::clone(&self.metadata),
+                        #[cfg(feature = "chrono-tz")]
+                        time_zone: conn.inner.stream.time_zone.clone(),
+                    });
+                }
+
+                // the row limit was reached, the rest of the rows are still pending
+                BackendMessageFormat::PortalSuspended => break,
+
+                BackendMessageFormat::CommandComplete
+                | BackendMessageFormat::EmptyQueryResponse => {
+                    self.complete = true;
+                    break;
+                }
+
+                _ => {
+                    return Err(err_protocol!(
+                        "PgPortal::execute: unexpected message: {:?}",
+                        message.format
+                    ));
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Whether the query was stopped at the row limit of the last call to
+    /// [`execute()`][Self::execute], with more rows possibly still to come.
+    pub fn is_suspended(&self) -> bool {
+        self.executed && !self.complete && !self.failed
+    }
+
+    /// Whether the query has run to completion, so that there are no more rows.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Close the portal, discarding any rows that were not fetched, and wait for the
+    /// connection to be ready for the next query.
+    ///
+    /// Dropping the portal closes it too, but leaves the wait to the next query.
+    pub async fn close(self) -> Result<(), Error> {
+        self.conn.finish()?.wait_until_ready().await
+    }
+}
+
+/// Ends an extended query that is kept open between round-trips, such as the one started by
+/// [`PgConnection::bind_portal()`], if it is dropped before [`finish()`][Self::finish] is called.
+///
+/// The `Close` and `Sync` are only buffered; the next call to `wait_until_ready()` sends them
+/// and discards whatever the server still had to say about the query.
+pub(super) struct SyncOnDrop<'c>(pub(super) Option<&'c mut PgConnection>);
+
+impl<'c> SyncOnDrop<'c> {
+    pub(super) fn conn(&mut self) -> &mut PgConnection {
+        self.0
+            .as_deref_mut()
+            .expect("BUG: connection taken from SyncOnDrop")
+    }
+
+    // end the extended query now, handing back the connection
+    pub(super) fn finish(mut self) -> Result<&'c mut PgConnection, Error> {
+        let conn = self
+            .0
+            .take()
+            .expect("BUG: connection taken from SyncOnDrop");
+
+        conn.inner
+            .stream
+            .write_msg(Close::Portal(PortalId::UNNAMED))?;
+        conn.write_sync();
+
+        Ok(conn)
+    }
+}
+
+impl Drop for SyncOnDrop<'_> {
+    fn drop(&mut self) {
+        let Some(conn) = self.0.as_deref_mut() else {
+            return;
+        };
+
+        // an error here means the buffer could not be written to, in which case
+        // the connection is unusable anyway
+        if conn
+            .inner
+            .stream
+            .write_msg(Close::Portal(PortalId::UNNAMED))
+            .is_ok()
+        {
+            conn.write_sync();
+        }
+    }
+}
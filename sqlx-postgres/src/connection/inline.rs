@@ -0,0 +1,926 @@
+use std::fmt::{self, Display, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use sqlx_core::logger::{parse_query_summary, private_level_filter_to_levels};
+
+use crate::error::{BoxDynError, Error};
+use crate::options::ParameterMask;
+use crate::type_info::{PgType, PgTypeKind};
+use crate::types::numeric::PgNumeric;
+use crate::types::Oid;
+use crate::{PgArguments, PgConnection, PgTypeInfo, PgValueFormat};
+
+// Days from 1970-01-01 to 2000-01-01, the epoch of dates and timestamps in Postgres
+const POSTGRES_EPOCH_DAYS: i64 = 10_957;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+impl PgConnection {
+    // With `prefer_simple_protocol` there is no `Bind` to send the arguments with,
+    // so they are inlined into the query text as literals instead
+    pub(super) async fn inline_arguments(
+        &mut self,
+        query: &str,
+        mut arguments: PgArguments,
+    ) -> Result<String, Error> {
+        arguments.check_bind_size(self.inner.max_bind_size)?;
+
+        // there is no prepared statement to tell us the parameter types,
+        // so patches are applied against the types that were bound
+        let types = arguments.types.clone();
+        arguments.apply_patches(self, &types).await?;
+
+        let literals = arguments
+            .buffer
+            .values()
+            .zip(&arguments.types)
+            .enumerate()
+            .map(|(index, (value, ty))| {
+                literal(ty, arguments.format(index), value).map_err(|error| {
+                    Error::Encode(
+                        format!("cannot inline bind parameter ${}: {error}", index + 1).into(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // with this turned off, backslashes are escapes in all string literals, not just `E'...'`
+        let backslash_escapes = self
+            .inner
+            .stream
+            .parameter_statuses
+            .get("standard_conforming_strings")
+            .is_some_and(|value| value == "off");
+
+        substitute(query, &literals, backslash_escapes)
+    }
+
+    // Logs the values bound to `query`, if enabled, at the level statements are logged at
+    pub(super) fn log_parameters(&self, query: &str, arguments: &PgArguments) {
+        if !self.inner.log_parameters {
+            return;
+        }
+
+        let Some((tracing_level, log_level)) =
+            private_level_filter_to_levels(self.inner.log_settings.statements_level)
+        else {
+            return;
+        };
+
+        let log_is_enabled = log::log_enabled!(target: "sqlx::query", log_level)
+            || sqlx_core::private_tracing_dynamic_enabled!(target: "sqlx::query", tracing_level);
+
+        if log_is_enabled {
+            let summary = parse_query_summary(query);
+            let parameters = describe_parameters(query, arguments, &self.inner.parameter_mask);
+
+            sqlx_core::private_tracing_dynamic_event!(
+                target: "sqlx::query",
+                tracing_level,
+                summary,
+                db.parameters = parameters,
+                "bound parameters"
+            );
+        }
+    }
+}
+
+// Renders the values bound to `query` for logging, e.g. `$1 = 42, $2 = ***, $3 = NULL`
+fn describe_parameters(query: &str, arguments: &PgArguments, mask: &ParameterMask) -> String {
+    let mut out = String::new();
+
+    for (index, (value, ty)) in arguments.buffer.values().zip(&arguments.types).enumerate() {
+        let position = index + 1;
+
+        if index > 0 {
+            out.push_str(", ");
+        }
+
+        let _ = write!(out, "${position} = ");
+
+        if mask.is_masked(query, position) || arguments.buffer.is_secret(index) {
+            out.push_str("***");
+            continue;
+        }
+
+        let Some(value) = value else {
+            out.push_str("NULL");
+            continue;
+        };
+
+        let mut text = String::new();
+
+        match write_value(&mut text, ty, arguments.format(index), value) {
+            Ok(()) => out.push_str(&text),
+            // e.g. a custom type, whose binary format is unknown
+            Err(_) => {
+                let _ = write!(out, "<{} value>", ty.0.display_name());
+            }
+        }
+    }
+
+    out
+}
+
+// Renders a bound value as an escaped string literal cast to its type,
+// e.g. `(E'O''Brien'::pg_catalog."text")`
+fn literal(
+    ty: &PgTypeInfo,
+    format: PgValueFormat,
+    value: Option<&[u8]>,
+) -> Result<String, BoxDynError> {
+    let type_name = match &ty.0 {
+        PgType::Custom(_)
+        | PgType::DeclareWithOid(_)
+        | PgType::DeclareWithName(_)
+        | PgType::DeclareArrayOf(_) => {
+            return Err(format!("values of type {} cannot be inlined", ty.0.display_name()).into());
+        }
+        builtin => builtin.name(),
+    };
+
+    let Some(value) = value else {
+        return Ok(format!("(NULL::pg_catalog.\"{type_name}\")"));
+    };
+
+    let mut text = String::new();
+    write_value(&mut text, ty, format, value)?;
+
+    let mut literal = String::with_capacity(text.len() + type_name.len() + 24);
+    literal.push_str("(E'");
+
+    // an `E'...'` string treats backslashes as escapes no matter how the server is configured
+    for c in text.chars() {
+        match c {
+            // this would end the query message early
+            '\0' => return Err("text cannot contain NUL characters".into()),
+            '\'' => literal.push_str("''"),
+            '\\' => literal.push_str("\\\\"),
+            c => literal.push(c),
+        }
+    }
+
+    write!(literal, "'::pg_catalog.\"{type_name}\")")?;
+
+    Ok(literal)
+}
+
+// Converts a value in either format to its text representation
+fn write_value(
+    out: &mut String,
+    ty: &PgTypeInfo,
+    format: PgValueFormat,
+    value: &[u8],
+) -> Result<(), BoxDynError> {
+    match format {
+        // bound with `add_with_type()`, so already the text Postgres parses as `ty`
+        PgValueFormat::Text => out.push_str(std::str::from_utf8(value)?),
+        PgValueFormat::Binary => write_text(out, ty, value)?,
+    }
+
+    Ok(())
+}
+
+// Converts a value from its binary representation to its text representation
+fn write_text(out: &mut String, ty: &PgTypeInfo, value: &[u8]) -> Result<(), BoxDynError> {
+    match ty.kind() {
+        PgTypeKind::Array(_) => return write_array(out, value),
+        PgTypeKind::Range(subtype) => return write_range(out, subtype, value),
+        _ => {}
+    }
+
+    match &ty.0 {
+        PgType::Bool => out.push(if exact::<1>(value)?[0] != 0 { 't' } else { 'f' }),
+
+        PgType::Char => match exact::<1>(value)?[0] {
+            0 => {}
+            byte @ 1..=0x7f => out.push(char::from(byte)),
+            byte => write!(out, "\\{byte:03o}")?,
+        },
+
+        PgType::Int2 => write!(out, "{}", i16::from_be_bytes(exact(value)?))?,
+        PgType::Int4 => write!(out, "{}", i32::from_be_bytes(exact(value)?))?,
+        PgType::Int8 => write!(out, "{}", i64::from_be_bytes(exact(value)?))?,
+        PgType::Oid => write!(out, "{}", u32::from_be_bytes(exact(value)?))?,
+
+        PgType::Float4 => write_float(out, f32::from_be_bytes(exact(value)?))?,
+        PgType::Float8 => write_float(out, f64::from_be_bytes(exact(value)?))?,
+
+        PgType::Numeric => write!(out, "{}", PgNumeric::decode(value)?)?,
+
+        PgType::Text
+        | PgType::Varchar
+        | PgType::Bpchar
+        | PgType::Name
+        | PgType::Unknown
+        | PgType::Json => out.push_str(std::str::from_utf8(value)?),
+
+        PgType::Jsonb | PgType::Jsonpath => {
+            let text = value
+                .strip_prefix(&[1])
+                .ok_or_else(|| format!("unsupported {} format version", ty.0.display_name()))?;
+
+            out.push_str(std::str::from_utf8(text)?);
+        }
+
+        PgType::Bytea => {
+            out.push_str("\\x");
+            out.push_str(&hex::encode(value));
+        }
+
+        PgType::Uuid => {
+            let hex = hex::encode(exact::<16>(value)?);
+
+            write!(
+                out,
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            )?;
+        }
+
+        PgType::Date => match i32::from_be_bytes(exact(value)?) {
+            i32::MAX => out.push_str("infinity"),
+            i32::MIN => out.push_str("-infinity"),
+            days => {
+                let bc = write_date(out, days.into())?;
+
+                if bc {
+                    out.push_str(" BC");
+                }
+            }
+        },
+
+        PgType::Time => write_time(out, i64::from_be_bytes(exact(value)?))?,
+
+        PgType::Timetz => {
+            let mut buf = value;
+
+            write_time(out, i64::from_be_bytes(take(&mut buf)?))?;
+
+            // the offset is in seconds *west* of UTC
+            let offset = -i64::from(i32::from_be_bytes(take(&mut buf)?));
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.abs();
+
+            write!(
+                out,
+                "{sign}{:02}:{:02}:{:02}",
+                offset / 3600,
+                offset / 60 % 60,
+                offset % 60
+            )?;
+        }
+
+        PgType::Timestamp | PgType::Timestamptz => match i64::from_be_bytes(exact(value)?) {
+            i64::MAX => out.push_str("infinity"),
+            i64::MIN => out.push_str("-infinity"),
+            micros => {
+                let bc = write_date(out, micros.div_euclid(MICROS_PER_DAY))?;
+
+                out.push(' ');
+                write_time(out, micros.rem_euclid(MICROS_PER_DAY))?;
+
+                if *ty == PgTypeInfo::TIMESTAMPTZ {
+                    out.push_str("+00");
+                }
+
+                if bc {
+                    out.push_str(" BC");
+                }
+            }
+        },
+
+        PgType::Interval => {
+            let mut buf = value;
+
+            let micros = i64::from_be_bytes(take(&mut buf)?);
+            let days = i32::from_be_bytes(take(&mut buf)?);
+            let months = i32::from_be_bytes(take(&mut buf)?);
+
+            write!(out, "{months} mons {days} days {micros} microseconds")?;
+        }
+
+        PgType::Bit | PgType::Varbit => {
+            let mut buf = value;
+
+            let len = usize::try_from(i32::from_be_bytes(take(&mut buf)?))?;
+
+            for i in 0..len {
+                let byte = buf.get(i / 8).ok_or("VARBIT length mismatch")?;
+
+                out.push(if byte & (0x80 >> (i % 8)) != 0 {
+                    '1'
+                } else {
+                    '0'
+                });
+            }
+        }
+
+        PgType::Inet | PgType::Cidr => {
+            let mut buf = value;
+
+            let [family, bits, _is_cidr, _len] = take::<4>(&mut buf)?;
+
+            let addr: IpAddr = match family {
+                // PGSQL_AF_INET
+                2 => Ipv4Addr::from(exact::<4>(buf)?).into(),
+                // PGSQL_AF_INET6
+                3 => Ipv6Addr::from(exact::<16>(buf)?).into(),
+                _ => return Err(format!("unknown INET address family: {family}").into()),
+            };
+
+            write!(out, "{addr}/{bits}")?;
+        }
+
+        PgType::Macaddr => out.push_str(&mac_address(&exact::<6>(value)?)),
+        PgType::Macaddr8 => out.push_str(&mac_address(&exact::<8>(value)?)),
+
+        PgType::Point => {
+            let [x, y] = floats(value)?;
+
+            write_point(out, x, y)?;
+        }
+
+        PgType::Line => {
+            let [a, b, c] = floats(value)?;
+
+            out.push('{');
+            write_float(out, a)?;
+            out.push(',');
+            write_float(out, b)?;
+            out.push(',');
+            write_float(out, c)?;
+            out.push('}');
+        }
+
+        PgType::Lseg | PgType::Box => {
+            let [x1, y1, x2, y2] = floats(value)?;
+            let lseg = *ty == PgTypeInfo::LSEG;
+
+            if lseg {
+                out.push('[');
+            }
+
+            write_point(out, x1, y1)?;
+            out.push(',');
+            write_point(out, x2, y2)?;
+
+            if lseg {
+                out.push(']');
+            }
+        }
+
+        PgType::Circle => {
+            let [x, y, radius] = floats(value)?;
+
+            out.push('<');
+            write_point(out, x, y)?;
+            out.push(',');
+            write_float(out, radius)?;
+            out.push('>');
+        }
+
+        PgType::Path | PgType::Polygon => {
+            let mut buf = value;
+
+            let closed = *ty == PgTypeInfo::POLYGON || take::<1>(&mut buf)?[0] != 0;
+            let points = usize::try_from(i32::from_be_bytes(take(&mut buf)?))?;
+
+            out.push(if closed { '(' } else { '[' });
+
+            for i in 0..points {
+                if i > 0 {
+                    out.push(',');
+                }
+
+                let x = f64::from_be_bytes(take(&mut buf)?);
+                let y = f64::from_be_bytes(take(&mut buf)?);
+
+                write_point(out, x, y)?;
+            }
+
+            out.push(if closed { ')' } else { ']' });
+        }
+
+        _ => {
+            return Err(format!("values of type {} cannot be inlined", ty.0.display_name()).into());
+        }
+    }
+
+    Ok(())
+}
+
+fn write_array(out: &mut String, value: &[u8]) -> Result<(), BoxDynError> {
+    let mut buf = value;
+
+    let ndim = i32::from_be_bytes(take(&mut buf)?);
+    let _flags = take::<4>(&mut buf)?;
+    let element_oid = Oid(u32::from_be_bytes(take(&mut buf)?));
+
+    let element = PgTypeInfo::try_from_oid(element_oid)
+        .ok_or_else(|| format!("unknown array element type: {}", element_oid.0))?;
+
+    let mut dimensions = Vec::new();
+
+    for _ in 0..ndim {
+        let len = i32::from_be_bytes(take(&mut buf)?);
+        let lower = i32::from_be_bytes(take(&mut buf)?);
+
+        dimensions.push((len, lower));
+    }
+
+    // lower bounds other than 1 are written as a decoration, e.g. `[0:1]={1,2}`
+    if dimensions.iter().any(|&(_, lower)| lower != 1) {
+        for &(len, lower) in &dimensions {
+            write!(out, "[{lower}:{}]", i64::from(lower) + i64::from(len) - 1)?;
+        }
+
+        out.push('=');
+    }
+
+    let lengths = dimensions
+        .iter()
+        .map(|&(len, _)| usize::try_from(len))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if lengths.is_empty() {
+        out.push_str("{}");
+        return Ok(());
+    }
+
+    // `box` is the only builtin type that does not use a comma
+    let delimiter = if element == PgTypeInfo::BOX { ';' } else { ',' };
+
+    write_array_dimension(out, &element, delimiter, &lengths, &mut buf)
+}
+
+fn write_array_dimension(
+    out: &mut String,
+    element: &PgTypeInfo,
+    delimiter: char,
+    lengths: &[usize],
+    buf: &mut &[u8],
+) -> Result<(), BoxDynError> {
+    out.push('{');
+
+    for i in 0..lengths[0] {
+        if i > 0 {
+            out.push(delimiter);
+        }
+
+        if lengths.len() > 1 {
+            write_array_dimension(out, element, delimiter, &lengths[1..], buf)?;
+        } else {
+            match take_value(buf)? {
+                Some(value) => write_quoted(out, element, value)?,
+                None => out.push_str("NULL"),
+            }
+        }
+    }
+
+    out.push('}');
+
+    Ok(())
+}
+
+fn write_range(out: &mut String, subtype: &PgTypeInfo, value: &[u8]) -> Result<(), BoxDynError> {
+    const EMPTY: u8 = 0x01;
+    const LB_INC: u8 = 0x02;
+    const UB_INC: u8 = 0x04;
+    const LB_INF: u8 = 0x08;
+    const UB_INF: u8 = 0x10;
+
+    let mut buf = value;
+    let [flags] = take::<1>(&mut buf)?;
+
+    if flags & EMPTY != 0 {
+        out.push_str("empty");
+        return Ok(());
+    }
+
+    out.push(if flags & LB_INC != 0 { '[' } else { '(' });
+
+    if flags & LB_INF == 0 {
+        let lower = take_value(&mut buf)?.ok_or("range bound cannot be NULL")?;
+        write_quoted(out, subtype, lower)?;
+    }
+
+    out.push(',');
+
+    if flags & UB_INF == 0 {
+        let upper = take_value(&mut buf)?.ok_or("range bound cannot be NULL")?;
+        write_quoted(out, subtype, upper)?;
+    }
+
+    out.push(if flags & UB_INC != 0 { ']' } else { ')' });
+
+    Ok(())
+}
+
+// Array elements and range bounds are double-quoted, with backslash escapes
+fn write_quoted(out: &mut String, ty: &PgTypeInfo, value: &[u8]) -> Result<(), BoxDynError> {
+    let mut text = String::new();
+    write_text(&mut text, ty, value)?;
+
+    out.push('"');
+
+    for c in text.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+
+        out.push(c);
+    }
+
+    out.push('"');
+
+    Ok(())
+}
+
+// Writes the date `days` after 2000-01-01 as `YYYY-MM-DD`, returning whether the year is BC
+fn write_date(out: &mut String, days: i64) -> Result<bool, fmt::Error> {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + POSTGRES_EPOCH_DAYS + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    // there is no year 0, 1 BC is followed by 1 AD
+    let (year, bc) = if year <= 0 {
+        (1 - year, true)
+    } else {
+        (year, false)
+    };
+
+    write!(out, "{year:04}-{month:02}-{day:02}")?;
+
+    Ok(bc)
+}
+
+fn write_time(out: &mut String, micros: i64) -> fmt::Result {
+    write!(
+        out,
+        "{:02}:{:02}:{:02}.{:06}",
+        micros / 3_600_000_000,
+        micros / 60_000_000 % 60,
+        micros / 1_000_000 % 60,
+        micros % 1_000_000
+    )
+}
+
+fn write_float<F: Display + Into<f64> + Copy>(out: &mut String, value: F) -> fmt::Result {
+    let float: f64 = value.into();
+
+    if float.is_nan() {
+        out.push_str("NaN");
+    } else if float.is_infinite() {
+        out.push_str(if float > 0.0 { "Infinity" } else { "-Infinity" });
+    } else {
+        // `Display` prints the shortest representation that parses back to the same value
+        write!(out, "{value}")?;
+    }
+
+    Ok(())
+}
+
+fn write_point(out: &mut String, x: f64, y: f64) -> fmt::Result {
+    out.push('(');
+    write_float(out, x)?;
+    out.push(',');
+    write_float(out, y)?;
+    out.push(')');
+
+    Ok(())
+}
+
+fn mac_address(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn exact<const N: usize>(value: &[u8]) -> Result<[u8; N], BoxDynError> {
+    value
+        .try_into()
+        .map_err(|_| format!("expected a value of {N} bytes, got {}", value.len()).into())
+}
+
+fn floats<const N: usize>(value: &[u8]) -> Result<[f64; N], BoxDynError> {
+    let mut buf = value;
+    let mut floats = [0.0; N];
+
+    for float in &mut floats {
+        *float = f64::from_be_bytes(take(&mut buf)?);
+    }
+
+    if !buf.is_empty() {
+        return Err(format!("expected a value of {} bytes, got {}", N * 8, value.len()).into());
+    }
+
+    Ok(floats)
+}
+
+fn take<const N: usize>(buf: &mut &[u8]) -> Result<[u8; N], BoxDynError> {
+    let (bytes, rest) = buf
+        .split_first_chunk::<N>()
+        .ok_or("unexpected end of value")?;
+
+    *buf = rest;
+
+    Ok(*bytes)
+}
+
+// Takes a length-prefixed value, as used for array elements and range bounds
+fn take_value<'a>(buf: &mut &'a [u8]) -> Result<Option<&'a [u8]>, BoxDynError> {
+    let Ok(len) = usize::try_from(i32::from_be_bytes(take(buf)?)) else {
+        return Ok(None);
+    };
+
+    let value = buf.get(..len).ok_or("unexpected end of value")?;
+    *buf = &buf[len..];
+
+    Ok(Some(value))
+}
+
+// Replaces the `$N` placeholders in `query` with `literals[N - 1]`, skipping over string literals,
+// quoted identifiers, dollar-quoted strings and comments where `$N` is not a placeholder
+fn substitute(query: &str, literals: &[String], backslash_escapes: bool) -> Result<String, Error> {
+    let bytes = query.as_bytes();
+
+    let mut out =
+        String::with_capacity(query.len() + literals.iter().map(String::len).sum::<usize>());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while let Some(&byte) = bytes.get(i) {
+        match byte {
+            b'\'' => i = skip_quoted(bytes, i, backslash_escapes),
+            b'"' => i = skip_quoted(bytes, i, false),
+
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = bytes[i..]
+                    .iter()
+                    .position(|&byte| byte == b'\n')
+                    .map_or(bytes.len(), |pos| i + pos + 1);
+            }
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(bytes, i),
+
+            b'$' => {
+                let digits = bytes[i + 1..]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count();
+
+                if digits == 0 {
+                    i = skip_dollar_quoted(bytes, i);
+                    continue;
+                }
+
+                let end = i + 1 + digits;
+                let placeholder = &query[i + 1..end];
+
+                let literal = placeholder
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| literals.get(n.checked_sub(1)?))
+                    .ok_or_else(|| {
+                        err_protocol!(
+                            "query references ${placeholder} but {} arguments were bound",
+                            literals.len()
+                        )
+                    })?;
+
+                out.push_str(&query[copied..i]);
+                out.push_str(literal);
+
+                copied = end;
+                i = end;
+            }
+
+            byte if is_ident_start(byte) => {
+                let start = i;
+
+                i += 1 + bytes[i + 1..]
+                    .iter()
+                    .take_while(|&&byte| is_ident_continue(byte))
+                    .count();
+
+                // `E'...'` strings always use backslash escapes
+                if i - start == 1 && matches!(byte, b'e' | b'E') && bytes.get(i) == Some(&b'\'') {
+                    i = skip_quoted(bytes, i, true);
+                }
+            }
+
+            // numeric constants, which may contain letters (`1e5`, `0x1f`)
+            byte if byte.is_ascii_digit() => {
+                i += bytes[i..]
+                    .iter()
+                    .take_while(|&&byte| byte.is_ascii_alphanumeric() || byte == b'_')
+                    .count();
+            }
+
+            _ => i += 1,
+        }
+    }
+
+    out.push_str(&query[copied..]);
+
+    Ok(out)
+}
+
+fn is_ident_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_' || !byte.is_ascii()
+}
+
+fn is_ident_continue(byte: u8) -> bool {
+    is_ident_start(byte) || byte.is_ascii_digit() || byte == b'$'
+}
+
+// Returns the index after the closing quote; a doubled quote does not close the string
+fn skip_quoted(bytes: &[u8], start: usize, backslash_escapes: bool) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+
+    while let Some(&byte) = bytes.get(i) {
+        i += 1;
+
+        if byte == b'\\' && backslash_escapes {
+            i += 1;
+        } else if byte == quote {
+            if bytes.get(i) != Some(&quote) {
+                return i;
+            }
+
+            i += 1;
+        }
+    }
+
+    bytes.len()
+}
+
+// Block comments nest
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0_usize;
+    let mut i = start;
+
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    bytes.len()
+}
+
+// `$tag$ ... $tag$` or `$$ ... $$`
+fn skip_dollar_quoted(bytes: &[u8], start: usize) -> usize {
+    let tag_len = bytes[start + 1..]
+        .iter()
+        .take_while(|&&byte| byte != b'$' && is_ident_continue(byte))
+        .count();
+
+    let tag_end = start + 1 + tag_len;
+
+    if bytes.get(tag_end) != Some(&b'$') {
+        return start + 1;
+    }
+
+    let delimiter = &bytes[start..=tag_end];
+
+    bytes[tag_end + 1..]
+        .windows(delimiter.len())
+        .position(|window| window == delimiter)
+        .map_or(bytes.len(), |pos| tag_end + 1 + pos + delimiter.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe_parameters, substitute};
+    use crate::options::ParameterMask;
+    use crate::{PgArguments, PgConnectOptions};
+
+    fn literals() -> Vec<String> {
+        vec!["(A)".into(), "(B)".into()]
+    }
+
+    #[test]
+    fn it_substitutes_placeholders() {
+        assert_eq!(
+            substitute("SELECT $1, $2, $1", &literals(), false).unwrap(),
+            "SELECT (A), (B), (A)"
+        );
+        assert_eq!(
+            substitute("SELECT $2::int4+$1", &literals(), false).unwrap(),
+            "SELECT (B)::int4+(A)"
+        );
+    }
+
+    #[test]
+    fn it_skips_placeholders_that_are_not_placeholders() {
+        let query = concat!(
+            "SELECT '$1', 'it''s $1', E'\\'$1', \"$1\", $$ $1 $$, $tag$ $1 $$ $tag$, ",
+            "foo$1 -- $1\n",
+            "/* $1 /* $1 */ $1 */ $2"
+        );
+
+        assert_eq!(
+            substitute(query, &literals(), false).unwrap(),
+            concat!(
+                "SELECT '$1', 'it''s $1', E'\\'$1', \"$1\", $$ $1 $$, $tag$ $1 $$ $tag$, ",
+                "foo$1 -- $1\n",
+                "/* $1 /* $1 */ $1 */ (B)"
+            )
+        );
+    }
+
+    #[test]
+    fn it_respects_standard_conforming_strings() {
+        assert_eq!(
+            substitute("SELECT 'a\\', $1", &literals(), false).unwrap(),
+            "SELECT 'a\\', (A)"
+        );
+        assert_eq!(
+            substitute("SELECT 'a\\', $1'", &literals(), true).unwrap(),
+            "SELECT 'a\\', $1'"
+        );
+    }
+
+    #[test]
+    fn it_rejects_unbound_placeholders() {
+        assert!(substitute("SELECT $3", &literals(), false).is_err());
+        assert!(substitute("SELECT $0", &literals(), false).is_err());
+    }
+
+    #[test]
+    fn it_describes_parameters_with_masked_values_hidden() {
+        let mut arguments = PgArguments::default();
+        arguments.add(42_i32).unwrap();
+        arguments.add("hunter2").unwrap();
+        arguments.add(None::<String>).unwrap();
+
+        let query = "SELECT * FROM users WHERE id = $1 AND password = $2 AND name = $3";
+
+        assert_eq!(
+            describe_parameters(query, &arguments, &ParameterMask::default()),
+            "$1 = 42, $2 = hunter2, $3 = NULL"
+        );
+
+        let by_position = PgConnectOptions::new_without_pgpass().mask_parameters([2]);
+        let logged = describe_parameters(query, &arguments, &by_position.parameter_mask);
+
+        assert_eq!(logged, "$1 = 42, $2 = ***, $3 = NULL");
+        assert!(!logged.contains("hunter2"));
+
+        let by_predicate = PgConnectOptions::new_without_pgpass()
+            .mask_parameters_where(|sql, position| sql.contains("password") && position == 2);
+        let logged = describe_parameters(query, &arguments, &by_predicate.parameter_mask);
+
+        assert_eq!(logged, "$1 = 42, $2 = ***, $3 = NULL");
+
+        let logged = describe_parameters("SELECT $1, $2", &arguments, &by_predicate.parameter_mask);
+
+        assert_eq!(logged, "$1 = 42, $2 = hunter2, $3 = NULL");
+    }
+
+    #[cfg(feature = "secrecy")]
+    #[test]
+    fn it_describes_secrets_as_masked() {
+        use secrecy::SecretString;
+
+        let mut arguments = PgArguments::default();
+        arguments.add(42_i32).unwrap();
+        arguments.add(SecretString::from("hunter2")).unwrap();
+        arguments.add("public").unwrap();
+
+        assert_eq!(
+            describe_parameters("SELECT $1, $2, $3", &arguments, &ParameterMask::default()),
+            "$1 = 42, $2 = ***, $3 = public"
+        );
+
+        let debug = format!("{arguments:?}");
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains(&format!("{:?}", b"hunter2")));
+    }
+}
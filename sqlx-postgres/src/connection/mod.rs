@@ -1,5 +1,7 @@
 use std::fmt::{self, Debug, Formatter};
+use std::io;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::HashMap;
 use futures_core::future::BoxFuture;
@@ -7,27 +9,38 @@ use futures_util::FutureExt;
 
 use crate::common::StatementCache;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::ext::ustr::UStr;
+use crate::ident::ident;
 use crate::io::StatementId;
 use crate::message::{
     BackendMessageFormat, Close, Query, ReadyForQuery, ReceivedMessage, Terminate,
     TransactionStatus,
 };
+use crate::options::ParameterMask;
+use crate::sqlstate;
 use crate::statement::PgStatementMetadata;
 use crate::transaction::Transaction;
 use crate::types::Oid;
-use crate::{PgConnectOptions, PgTypeInfo, Postgres};
+use crate::{PgConnectOptions, PgNotice, PgTypeInfo, Postgres};
 
 pub(crate) use sqlx_core::connection::*;
 
+pub use self::fetch::{PgFetchSize, PgFetchWithResult, PgPortal};
 pub use self::stream::PgStream;
+pub use self::trace::{PgProtocolDirection, PgProtocolMessage};
 
+mod batch;
+mod cancel;
 pub(crate) mod describe;
 mod establish;
 mod executor;
+mod fetch;
+mod inline;
 mod sasl;
 mod stream;
 mod tls;
+mod trace;
 
 /// A connection to a PostgreSQL database.
 pub struct PgConnection {
@@ -42,14 +55,19 @@ pub struct PgConnectionInner {
 
     // process id of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
     process_id: u32,
 
     // secret key of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
     secret_key: u32,
 
+    // the options this connection was established with,
+    // used to open the separate connection a cancel request is sent on
+    options: PgConnectOptions,
+
+    // set by `with_deadline()`, after which no more queries are sent
+    deadline: Option<Instant>,
+
     // sequence of statement IDs for use in preparing statements
     // in PostgreSQL, the statement is prepared to a user-supplied identifier
     next_statement_id: StatementId,
@@ -65,11 +83,29 @@ pub struct PgConnectionInner {
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
+    // a `PgCopyIn` was dropped and sent `CopyFail`,
+    // so the error the server responds with is expected
+    pub(crate) copy_in_aborted: bool,
+
+    // a `Bind` was being sent a chunk at a time when the query was cancelled,
+    // so the server is still reading the rest of it and the connection cannot be reused
+    pub(crate) bind_interrupted: bool,
+
     // current transaction status
     transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
 
     log_settings: LogSettings,
+
+    // log the values bound to each statement, hiding those masked
+    log_parameters: bool,
+    parameter_mask: ParameterMask,
+
+    // maximum size of the parameter data in a single `Bind` message
+    max_bind_size: usize,
+
+    // inline arguments and send every query with the simple query protocol
+    prefer_simple_protocol: bool,
 }
 
 impl PgConnection {
@@ -78,14 +114,251 @@ impl PgConnection {
         self.inner.stream.server_version_num
     }
 
+    /// The current value of a parameter the server reports to the client,
+    /// such as `server_version`, `TimeZone` or `application_name`.
+    ///
+    /// The server reports a fixed set of parameters when the connection is established,
+    /// and again whenever one of them changes. Other settings can be read with
+    /// `SHOW` or `current_setting()`.
+    ///
+    /// See [the Postgres manual, Section 55.2.7][PG.55.2.7] for the list of reported parameters.
+    ///
+    /// [PG.55.2.7]: https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-ASYNC
+    pub fn parameter_status(&self, name: &str) -> Option<&str> {
+        self.inner
+            .stream
+            .parameter_statuses
+            .get(name)
+            .map(String::as_str)
+    }
+
+    /// The character set the database stores text in, as reported by the server.
+    ///
+    /// Text is always exchanged with the client as `UTF8`, whatever the server encoding.
+    pub fn server_encoding(&self) -> Option<&str> {
+        self.parameter_status("server_encoding")
+    }
+
+    /// Whether the server stores dates and times as 64-bit integers, as reported by the server.
+    ///
+    /// This is always `true` on Postgres 10 and later. Connecting to a server that reports
+    /// `false` fails, as SQLx only decodes integer dates and times.
+    pub fn integer_datetimes(&self) -> Option<bool> {
+        match self.parameter_status("integer_datetimes")? {
+            "on" => Some(true),
+            "off" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// The most recent notices and warnings sent by the server on this connection,
+    /// oldest first.
+    ///
+    /// Up to [`PgConnectOptions::notice_history()`] notices are kept, including those sent
+    /// while connecting. None are kept by default.
+    pub fn recent_notices(&self) -> impl ExactSizeIterator<Item = &PgNotice> + '_ {
+        self.inner.stream.notice_history.iter()
+    }
+
+    /// Forget the notices kept for [`recent_notices()`][Self::recent_notices].
+    pub fn clear_notice_history(&mut self) {
+        self.inner.stream.notice_history.clear();
+    }
+
+    /// Call `callback` with the type and length of every protocol message sent to or
+    /// received from the server on this connection from now on, replacing any previous
+    /// callback.
+    ///
+    /// This is meant for debugging, e.g. logging the conversation with the server. The
+    /// contents of the messages are not passed, as they may hold query parameters or rows.
+    /// The callback runs while messages are sent and received, so it should return quickly.
+    pub fn on_protocol_message(
+        &mut self,
+        callback: impl Fn(&PgProtocolMessage) + Send + Sync + 'static,
+    ) {
+        self.inner.stream.protocol_message_hook = Some(Box::new(callback));
+    }
+
+    /// The prepared statements in the statement cache, as the name of each on the server with
+    /// its SQL.
+    ///
+    /// The least recently used statements come first. The names are those listed in the `name`
+    /// column of `pg_prepared_statements`.
+    pub fn prepared_statements(&self) -> impl Iterator<Item = (String, &str)> + '_ {
+        self.inner
+            .cache_statement
+            .iter()
+            // statements are always named to be cached
+            .filter_map(|(sql, (id, _))| Some((id.display()?.to_string(), sql)))
+    }
+
+    /// Change the number of prepared statements this connection keeps cached.
+    ///
+    /// This overrides [`PgConnectOptions::statement_cache_capacity()`] for this connection.
+    /// When shrinking, the least recently used statements that no longer fit are evicted
+    /// and closed on the server. Setting the capacity to `0` disables the cache.
+    pub async fn set_statement_cache_capacity(&mut self, capacity: usize) -> Result<(), Error> {
+        self.wait_until_ready().await?;
+
+        let evicted = self.inner.cache_statement.set_capacity(capacity);
+
+        if evicted.is_empty() {
+            return Ok(());
+        }
+
+        for (id, _) in &evicted {
+            self.inner.stream.write_msg(Close::Statement(*id))?;
+        }
+
+        self.write_sync();
+        self.inner.stream.flush().await?;
+
+        self.wait_for_close_complete(evicted.len()).await?;
+        self.recv_ready_for_query().await?;
+
+        Ok(())
+    }
+
+    /// Reset the session to the state it was in when the connection was opened, with
+    /// `DISCARD ALL`.
+    ///
+    /// This deallocates every prepared statement, drops temporary tables, resets every
+    /// setting changed with `SET`, closes cursors, stops listening on every channel and
+    /// releases all session-level advisory locks. The statement cache is emptied to match,
+    /// as are the cached types, which may have been temporary.
+    ///
+    /// Fails if a transaction is open, as `DISCARD ALL` cannot be run inside one.
+    pub async fn discard_all(&mut self) -> Result<(), Error> {
+        self.execute("DISCARD ALL").await?;
+
+        // the statements were deallocated by the server, so there is nothing left to close
+        self.inner.cache_statement.clear();
+        self.inner.cache_type_info.clear();
+        self.inner.cache_type_oid.clear();
+        self.inner.cache_elem_type_to_array.clear();
+
+        Ok(())
+    }
+
+    /// Set the `search_path` of the session to `schemas`, in order, with `SET search_path`.
+    ///
+    /// Unlike setting `search_path` in [`PgConnectOptions::options()`], this can be called at
+    /// any time, e.g. to switch between the schemas of tenants without reconnecting. Every
+    /// name is quoted, so it is matched exactly, including case; `"$user"` still stands for
+    /// the schema named after the current user. An empty slice leaves no schema to search.
+    /// A name containing a NUL byte is rejected with [`Error::Encode`].
+    ///
+    /// The cached OIDs of types looked up by name are cleared, as they may resolve to different
+    /// types now. In a transaction, the change is undone if the transaction is rolled back.
+    pub async fn set_search_path(&mut self, schemas: &[&str]) -> Result<(), Error> {
+        let search_path = if schemas.is_empty() {
+            "''".to_owned()
+        } else {
+            schemas
+                .iter()
+                .map(|schema| Ok(format!(r#""{}""#, ident(schema)?)))
+                .collect::<Result<Vec<_>, Error>>()?
+                .join(", ")
+        };
+
+        self.execute(&*format!("SET search_path = {search_path}"))
+            .await?;
+
+        self.inner.cache_type_oid.clear();
+
+        Ok(())
+    }
+
+    /// Run `callback` in a transaction with the session user and current user set to `user`.
+    ///
+    /// This issues `SET SESSION AUTHORIZATION` at the start of the transaction and
+    /// `RESET SESSION AUTHORIZATION` before committing it, so the connection is back to the
+    /// user it was opened with afterwards. If `callback` returns an error, the transaction is
+    /// rolled back instead, which undoes the `SET` as well.
+    ///
+    /// Changing the session authorization requires the user the connection was opened with
+    /// to be a superuser.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::{Connection, postgres::PgConnection};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// let mut conn = PgConnection::connect("postgres:// ...").await?;
+    ///
+    /// let visible_orders: i64 = conn
+    ///     .with_session_authorization("app_user", |conn| {
+    ///         Box::pin(async move {
+    ///             // row-level security policies are applied for `app_user`
+    ///             sqlx::query_scalar("SELECT count(*) FROM orders")
+    ///                 .fetch_one(conn)
+    ///                 .await
+    ///         })
+    ///     })
+    ///     .await?;
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn with_session_authorization<F, R, E>(
+        &mut self,
+        user: &str,
+        callback: F,
+    ) -> Result<R, E>
+    where
+        for<'c> F: FnOnce(&'c mut PgConnection) -> BoxFuture<'c, Result<R, E>>,
+        E: From<Error>,
+    {
+        let mut transaction = self.begin().await?;
+
+        transaction
+            .execute(&*format!(r#"SET SESSION AUTHORIZATION "{}""#, ident(user)?))
+            .await?;
+
+        match callback(&mut transaction).await {
+            Ok(ret) => {
+                transaction.execute("RESET SESSION AUTHORIZATION").await?;
+                transaction.commit().await?;
+
+                Ok(ret)
+            }
+            Err(err) => {
+                transaction.rollback().await?;
+
+                Err(err)
+            }
+        }
+    }
+
     // will return when the connection is ready for another query
     pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        if self.inner.bind_interrupted {
+            return Err(Error::Io(io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                "a query was cancelled in the middle of sending its arguments, \
+                 so the connection has to be closed",
+            )));
+        }
+
         if !self.inner.stream.write_buffer_mut().is_empty() {
             self.inner.stream.flush().await?;
         }
 
         while self.inner.pending_ready_for_query_count > 0 {
-            let message = self.inner.stream.recv().await?;
+            let message = match self.inner.stream.recv().await {
+                Ok(message) => message,
+
+                // `query_canceled` is how the server acknowledges the `CopyFail`
+                Err(Error::Database(e))
+                    if self.inner.copy_in_aborted
+                        && e.code().as_deref() == Some(sqlstate::QUERY_CANCELED) =>
+                {
+                    self.inner.copy_in_aborted = false;
+                    continue;
+                }
+
+                Err(e) => return Err(e),
+            };
 
             if let BackendMessageFormat::ReadyForQuery = message.format {
                 self.handle_ready_for_query(message)?;
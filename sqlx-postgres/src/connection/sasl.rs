@@ -1,7 +1,7 @@
 use crate::connection::stream::PgStream;
 use crate::error::Error;
 use crate::message::{Authentication, AuthenticationSasl, SaslInitialResponse, SaslResponse};
-use crate::PgConnectOptions;
+use crate::{PgChannelBinding, PgConnectOptions};
 use hmac::{Hmac, Mac};
 use rand::Rng;
 use sha2::{Digest, Sha256};
@@ -20,32 +20,7 @@ pub(crate) async fn authenticate(
     options: &PgConnectOptions,
     data: AuthenticationSasl,
 ) -> Result<(), Error> {
-    let mut has_sasl = false;
-    let mut has_sasl_plus = false;
-    let mut unknown = Vec::new();
-
-    for mechanism in data.mechanisms() {
-        match mechanism {
-            "SCRAM-SHA-256" => {
-                has_sasl = true;
-            }
-
-            "SCRAM-SHA-256-PLUS" => {
-                has_sasl_plus = true;
-            }
-
-            _ => {
-                unknown.push(mechanism.to_owned());
-            }
-        }
-    }
-
-    if !has_sasl_plus && !has_sasl {
-        return Err(err_protocol!(
-            "unsupported SASL authentication mechanisms: {}",
-            unknown.join(", ")
-        ));
-    }
+    check_mechanisms(data.mechanisms(), options.channel_binding)?;
 
     // channel-binding = "c=" base64
     let mut channel_binding = format!("{CHANNEL_ATTR}=");
@@ -85,6 +60,8 @@ pub(crate) async fn authenticate(
         }
     };
 
+    check_server_nonce(&nonce[NONCE_ATTR.len() + 1..], &cont.nonce)?;
+
     // SaltedPassword := Hi(Normalize(password), salt, i)
     let salted_password = hi(
         options.password.as_deref().unwrap_or_default(),
@@ -159,6 +136,70 @@ pub(crate) async fn authenticate(
     Ok(())
 }
 
+// Checks the mechanisms offered by the server before authenticating with `SCRAM-SHA-256`,
+// the only one supported, which has to be among them.
+//
+// Channel binding (`SCRAM-SHA-256-PLUS`) is not supported, so a policy requiring it
+// refuses to authenticate rather than silently going without it.
+fn check_mechanisms<'a>(
+    mechanisms: impl IntoIterator<Item = &'a str>,
+    channel_binding: PgChannelBinding,
+) -> Result<(), Error> {
+    let mut has_sasl = false;
+    let mut has_sasl_plus = false;
+    let mut unknown = Vec::new();
+
+    for mechanism in mechanisms {
+        match mechanism {
+            "SCRAM-SHA-256" => {
+                has_sasl = true;
+            }
+
+            "SCRAM-SHA-256-PLUS" => {
+                has_sasl_plus = true;
+            }
+
+            _ => {
+                unknown.push(mechanism);
+            }
+        }
+    }
+
+    if channel_binding == PgChannelBinding::Require {
+        return Err(if has_sasl_plus {
+            err_protocol!("channel binding is required, but SCRAM-SHA-256-PLUS is not supported")
+        } else {
+            err_protocol!("channel binding is required, but the server does not offer it")
+        });
+    }
+
+    if !has_sasl {
+        if has_sasl_plus {
+            return Err(err_protocol!(
+                "the server only offers SCRAM-SHA-256-PLUS, but channel binding is not supported"
+            ));
+        }
+
+        return Err(err_protocol!(
+            "unsupported SASL authentication mechanisms: {}",
+            unknown.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+// The nonce of the server has to start with ours and add to it,
+// or it is not answering this authentication exchange
+fn check_server_nonce(client_nonce: &str, server_nonce: &str) -> Result<(), Error> {
+    match server_nonce.strip_prefix(client_nonce) {
+        Some(rest) if !rest.is_empty() => Ok(()),
+        _ => Err(err_protocol!(
+            "SCRAM: the server nonce does not extend the client nonce"
+        )),
+    }
+}
+
 // nonce is a sequence of random printable bytes
 fn gen_nonce() -> String {
     let mut rng = rand::thread_rng();
@@ -205,6 +246,43 @@ fn hi<'a>(s: &'a str, salt: &'a [u8], iter_count: u32) -> Result<[u8; 32], Error
     Ok(hi.into())
 }
 
+#[test]
+fn test_check_mechanisms() {
+    let check = |mechanisms: &[&str], channel_binding| {
+        check_mechanisms(mechanisms.iter().copied(), channel_binding)
+    };
+
+    assert!(check(&["SCRAM-SHA-256"], PgChannelBinding::Prefer).is_ok());
+    assert!(check(
+        &["SCRAM-SHA-256-PLUS", "SCRAM-SHA-256"],
+        PgChannelBinding::Prefer
+    )
+    .is_ok());
+    assert!(check(&["SCRAM-SHA-256", "SCRAM-SHA-1"], PgChannelBinding::Disable).is_ok());
+
+    // a tampered list without the mechanism used is refused
+    assert!(check(&["SCRAM-SHA-256-PLUS"], PgChannelBinding::Prefer).is_err());
+    assert!(check(&["SCRAM-SHA-1"], PgChannelBinding::Prefer).is_err());
+    assert!(check(&[], PgChannelBinding::Prefer).is_err());
+
+    // channel binding is not silently dropped when required
+    assert!(check(
+        &["SCRAM-SHA-256-PLUS", "SCRAM-SHA-256"],
+        PgChannelBinding::Require
+    )
+    .is_err());
+    assert!(check(&["SCRAM-SHA-256"], PgChannelBinding::Require).is_err());
+}
+
+#[test]
+fn test_check_server_nonce() {
+    assert!(check_server_nonce("abc", "abcdef").is_ok());
+
+    assert!(check_server_nonce("abc", "abc").is_err());
+    assert!(check_server_nonce("abc", "xyzdef").is_err());
+    assert!(check_server_nonce("abc", "").is_err());
+}
+
 #[cfg(all(test, not(debug_assertions)))]
 #[bench]
 fn bench_sasl_hi(b: &mut test::Bencher) {
@@ -1,20 +1,26 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::ops::{ControlFlow, Deref, DerefMut};
 use std::str::FromStr;
+#[cfg(feature = "chrono-tz")]
+use std::sync::Arc;
 
 use futures_channel::mpsc::UnboundedSender;
 use futures_util::SinkExt;
 use log::Level;
-use sqlx_core::bytes::Buf;
+use sqlx_core::bytes::{Buf, Bytes};
 
 use crate::connection::tls::MaybeUpgradeTls;
+use crate::connection::trace::{PgProtocolDirection, PgProtocolMessage, ProtocolMessageHook};
 use crate::error::Error;
 use crate::message::{
-    BackendMessage, BackendMessageFormat, EncodeMessage, FrontendMessage, Notice, Notification,
-    ParameterStatus, ReceivedMessage,
+    BackendMessage, BackendMessageFormat, Bind, EncodeMessage, FrontendMessage, Notice,
+    Notification, ParameterStatus, ReceivedMessage,
 };
 use crate::net::{self, BufferedSocket, Socket};
-use crate::{PgConnectOptions, PgDatabaseError, PgSeverity};
+use crate::{PgConnectOptions, PgDatabaseError, PgNotice, PgSeverity};
+
+// how much of a value bound with `PgLargeBytes` is written to the socket at a time
+const EXTERNAL_CHUNK_SIZE: usize = 64 * 1024;
 
 // the stream is a separate type from the connection to uphold the invariant where an instantiated
 // [PgConnection] is a **valid** connection to postgres
@@ -38,6 +44,20 @@ pub struct PgStream {
     pub(crate) parameter_statuses: BTreeMap<String, String>,
 
     pub(crate) server_version_num: Option<u32>,
+
+    // the session `TimeZone`, kept separately so rows can share it without copying
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) time_zone: Option<Arc<str>>,
+
+    // the notices received while this is set, in addition to logging them
+    pub(crate) captured_notices: Option<Vec<Notice>>,
+
+    // the most recent notices, oldest first, up to `notice_history_capacity`
+    pub(crate) notice_history: VecDeque<PgNotice>,
+    pub(crate) notice_history_capacity: usize,
+
+    // called with the type and length of every message sent or received after the handshake
+    pub(crate) protocol_message_hook: Option<ProtocolMessageHook>,
 }
 
 impl PgStream {
@@ -54,12 +74,84 @@ impl PgStream {
             notifications: None,
             parameter_statuses: BTreeMap::default(),
             server_version_num: None,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: None,
+            captured_notices: None,
+            notice_history: VecDeque::new(),
+            notice_history_capacity: options.notice_history_capacity,
+            protocol_message_hook: None,
         })
     }
 
     #[inline(always)]
-    pub(crate) fn write_msg(&mut self, message: impl FrontendMessage) -> Result<(), Error> {
-        self.write(EncodeMessage(message))
+    pub(crate) fn write_msg<F: FrontendMessage>(&mut self, message: F) -> Result<(), Error> {
+        if self.protocol_message_hook.is_none() {
+            return self.write(EncodeMessage(message));
+        }
+
+        let unflushed = self.write_buffer().get().len();
+
+        self.write(EncodeMessage(message))?;
+
+        // the format code is not counted in the length
+        let length = self.write_buffer().get().len() - unflushed - 1;
+        self.trace(PgProtocolDirection::Outbound, F::FORMAT.name(), length);
+
+        Ok(())
+    }
+
+    // Writes a `Bind` whose parameters include values kept out of `bind.params`, at the offsets
+    // in `external`, sending those a chunk at a time instead of copying them into the buffer.
+    //
+    // Everything written before is flushed along with the first chunk.
+    pub(crate) async fn write_bind_external(
+        &mut self,
+        bind: Bind<'_>,
+        external: &[(usize, Bytes)],
+    ) -> Result<(), Error> {
+        // the parameters are followed by the result format codes and their count
+        let result_formats_len = 2 + 2 * bind.result_formats.len();
+        let params_len = bind.params.len();
+
+        let mut message = Vec::new();
+        bind.encode_msg(&mut message)?;
+
+        let params_start = message.len() - result_formats_len - params_len;
+
+        // the format code is not counted in the length
+        let length =
+            message.len() - 1 + external.iter().map(|(_, bytes)| bytes.len()).sum::<usize>();
+        let length_prefix = i32::try_from(length)
+            .map_err(|_| err_protocol!("Bind message is too large: {length} bytes"))?;
+        message[1..5].copy_from_slice(&length_prefix.to_be_bytes());
+
+        let mut written = 0;
+
+        for (offset, bytes) in external {
+            let at = params_start + offset;
+            self.write_buffer_mut().put_slice(&message[written..at]);
+            written = at;
+
+            for chunk in bytes.chunks(EXTERNAL_CHUNK_SIZE) {
+                self.write_buffer_mut().put_slice(chunk);
+                self.flush().await?;
+            }
+        }
+
+        self.write_buffer_mut().put_slice(&message[written..]);
+        self.trace(PgProtocolDirection::Outbound, Bind::FORMAT.name(), length);
+
+        Ok(())
+    }
+
+    fn trace(&self, direction: PgProtocolDirection, name: &'static str, length: usize) {
+        if let Some(hook) = &self.protocol_message_hook {
+            hook(&PgProtocolMessage {
+                direction,
+                name,
+                length,
+            });
+        }
     }
 
     pub(crate) async fn send<T>(&mut self, message: T) -> Result<(), Error>
@@ -79,7 +171,8 @@ impl PgStream {
     pub(crate) async fn recv_unchecked(&mut self) -> Result<ReceivedMessage, Error> {
         // NOTE: to not break everything, this should be cancel-safe;
         // DO NOT modify `buf` unless a full message has been read
-        self.inner
+        let message = self
+            .inner
             .try_read(|buf| {
                 // all packets in postgres start with a 5-byte header
                 // this header contains the message type and the total length of the message
@@ -115,7 +208,16 @@ impl PgStream {
 
                 Ok(ControlFlow::Break(ReceivedMessage { format, contents }))
             })
-            .await
+            .await?;
+
+        // count the length prefix cut off above
+        self.trace(
+            PgProtocolDirection::Inbound,
+            message.format.name(),
+            message.contents.len() + 4,
+        );
+
+        Ok(message)
     }
 
     // Get the next message from the server
@@ -150,11 +252,15 @@ impl PgStream {
                         "server_version" => {
                             self.server_version_num = parse_server_version(&value);
                         }
-                        _ => {
-                            self.parameter_statuses.insert(name, value);
+                        #[cfg(feature = "chrono-tz")]
+                        "TimeZone" => {
+                            self.time_zone = Some(Arc::from(&*value));
                         }
+                        _ => {}
                     }
 
+                    self.parameter_statuses.insert(name, value);
+
                     continue;
                 }
 
@@ -164,6 +270,18 @@ impl PgStream {
 
                     let notice: Notice = message.decode()?;
 
+                    if let Some(captured) = &mut self.captured_notices {
+                        captured.push(notice.clone());
+                    }
+
+                    if self.notice_history_capacity > 0 {
+                        if self.notice_history.len() == self.notice_history_capacity {
+                            self.notice_history.pop_front();
+                        }
+
+                        self.notice_history.push_back(PgNotice(notice.clone()));
+                    }
+
                     let (log_level, tracing_level) = match notice.severity() {
                         PgSeverity::Fatal | PgSeverity::Panic | PgSeverity::Error => {
                             (Level::Error, tracing::Level::ERROR)
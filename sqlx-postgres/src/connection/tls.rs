@@ -58,6 +58,11 @@ async fn maybe_upgrade<S: Socket>(
         root_cert_path: options.ssl_root_cert.as_ref(),
         client_cert_path: options.ssl_client_cert.as_ref(),
         client_key_path: options.ssl_client_key.as_ref(),
+        client_key_password: options
+            .ssl_client_key_password
+            .as_ref()
+            .map(|password| password.expose()),
+        min_version: options.ssl_min_protocol_version,
     };
 
     tls::handshake(socket, config, SocketIntoBox).await
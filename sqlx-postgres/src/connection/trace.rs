@@ -0,0 +1,42 @@
+/// Whether a protocol message was sent to or received from the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgProtocolDirection {
+    /// Sent to the server.
+    Outbound,
+
+    /// Received from the server.
+    Inbound,
+}
+
+/// The type and length of a protocol message, as passed to the callback set with
+/// [`PgConnection::on_protocol_message()`][crate::PgConnection::on_protocol_message].
+///
+/// The contents of the message are left out, as they may hold query parameters or rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgProtocolMessage {
+    pub(crate) direction: PgProtocolDirection,
+    pub(crate) name: &'static str,
+    pub(crate) length: usize,
+}
+
+impl PgProtocolMessage {
+    /// Whether the message was sent or received.
+    pub fn direction(&self) -> PgProtocolDirection {
+        self.direction
+    }
+
+    /// The name of the message type in the Postgres documentation, such as `Parse` or `DataRow`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The length of the message in its header, in bytes.
+    ///
+    /// Like in the protocol, this counts the 4 bytes of the length itself but not the byte
+    /// of the message type.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
+
+pub(crate) type ProtocolMessageHook = Box<dyn Fn(&PgProtocolMessage) + Send + Sync>;
@@ -1,21 +1,31 @@
 use std::borrow::Cow;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
+use crc::{Crc, Digest, CRC_32_ISO_HDLC};
 use futures_core::future::BoxFuture;
-use futures_core::stream::BoxStream;
+use futures_core::stream::{BoxStream, Stream};
+use futures_util::{StreamExt, TryStreamExt};
 
-use sqlx_core::bytes::{BufMut, Bytes};
+use sqlx_core::bytes::{Buf, BufMut, Bytes, BytesMut};
 
 use crate::connection::PgConnection;
-use crate::error::{Error, Result};
+use crate::encode::Encode;
+use crate::error::{BoxDynError, Error, Result};
 use crate::ext::async_stream::TryAsyncStream;
-use crate::io::AsyncRead;
+use crate::ext::ustr::UStr;
+use crate::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use crate::message::{
-    BackendMessageFormat, CommandComplete, CopyData, CopyDone, CopyFail, CopyInResponse,
-    CopyOutResponse, CopyResponseData, Query, ReadyForQuery,
+    BackendMessage, BackendMessageFormat, CommandComplete, CopyBothResponse, CopyData, CopyDone,
+    CopyFail, CopyInResponse, CopyOutResponse, CopyResponseData, DataRow, Query, ReadyForQuery,
 };
 use crate::pool::{Pool, PoolConnection};
-use crate::Postgres;
+use crate::statement::PgStatementMetadata;
+use crate::transaction::{PgTransactionManager, TransactionManager};
+use crate::type_info::PgType;
+use crate::types::Type;
+use crate::{PgArguments, PgColumn, PgRow, PgTypeInfo, PgValueFormat, Postgres};
 
 impl PgConnection {
     /// Issue a `COPY FROM STDIN` statement and transition the connection to streaming data
@@ -29,12 +39,131 @@ impl PgConnection {
     /// <https://www.postgresql.org/docs/current/sql-copy.html>
     ///
     /// ### Note
-    /// [PgCopyIn::finish] or [PgCopyIn::abort] *must* be called when finished or the connection
-    /// will return an error the next time it is used.
+    /// [PgCopyIn::finish] must be called to commit the copied data. If the `PgCopyIn` is dropped
+    /// before then, the `COPY` is aborted the next time the connection is used, the same as
+    /// calling [PgCopyIn::abort].
     pub async fn copy_in_raw(&mut self, statement: &str) -> Result<PgCopyIn<&mut Self>> {
         PgCopyIn::begin(self, statement).await
     }
 
+    /// Issue the `COPY FROM STDIN` statement built by `options` and transition the connection
+    /// to streaming data to Postgres, like [`copy_in_raw()`][Self::copy_in_raw].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::Connection;
+    /// # use sqlx::postgres::{PgConnection, PgCopyFormat, PgCopyInOptions, PgCopyOnError};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let options = PgCopyInOptions::new("users")
+    ///     .columns(["id", "name"])
+    ///     .format(PgCopyFormat::Csv)
+    ///     .on_error(PgCopyOnError::Ignore);
+    ///
+    /// let mut copy = conn.copy_in_with(&options).await?;
+    /// copy.send(&b"1,foo\nnot a number,bar\n"[..]).await?;
+    ///
+    /// let result = copy.finish_with_result().await?;
+    ///
+    /// assert_eq!(result.rows_copied(), 1);
+    /// assert_eq!(result.rows_skipped(), Some(1));
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn copy_in_with(&mut self, options: &PgCopyInOptions) -> Result<PgCopyIn<&mut Self>> {
+        let copy = PgCopyIn::begin(self, &options.to_sql()).await?;
+
+        Ok(if options.checksum {
+            copy.with_checksum()
+        } else {
+            copy
+        })
+    }
+
+    /// Issue a `COPY FROM STDIN` statement and send every chunk of `data` as it is produced,
+    /// such as the body of an upstream HTTP request.
+    ///
+    /// Each chunk is flushed to the server before the next one is polled for, so a fast
+    /// producer is held back to the pace the connection can keep up with.
+    ///
+    /// If `data` yields an error, the `COPY` is aborted, discarding everything sent so far,
+    /// and that error is returned. Otherwise, the `COPY` is completed once `data` ends and
+    /// the number of rows copied is returned.
+    ///
+    /// If `statement` is anything other than a `COPY ... FROM STDIN ...` command, an error is
+    /// returned.
+    ///
+    /// Command examples and accepted formats for `COPY` data are shown here:
+    /// <https://www.postgresql.org/docs/current/sql-copy.html>
+    pub async fn copy_in_from_stream(
+        &mut self,
+        statement: &str,
+        data: impl Stream<Item = Result<Bytes>>,
+    ) -> Result<u64> {
+        let mut copy = self.copy_in_raw(statement).await?;
+
+        futures_util::pin_mut!(data);
+
+        while let Some(chunk) = data.next().await {
+            match chunk {
+                Ok(chunk) => {
+                    copy.send(chunk).await?;
+                }
+                Err(e) => {
+                    copy.abort(e.to_string()).await?;
+                    return Err(e);
+                }
+            }
+        }
+
+        copy.finish().await
+    }
+
+    /// Start building a binary `COPY FROM STDIN` into `table` whose rows are tuples of type `T`,
+    /// so the number and Rust types of the values in each row are checked at compile time.
+    ///
+    /// The columns default to every column of `table`; use
+    /// [`columns()`][PgBulkCopyBuilder::columns] to copy into only some of them, in a
+    /// given order. Once started, the number of columns is checked against the arity of `T`.
+    ///
+    /// Binary `COPY` data is not converted by the server, so each Rust type must encode to the
+    /// exact type of its column, e.g. `i32` for `INT4` but `i64` for `INT8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::Connection;
+    /// # use sqlx::postgres::PgConnection;
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut copy = conn
+    ///     .bulk_copy_builder::<(i32, String)>("users")
+    ///     .columns(["id", "name"])
+    ///     .begin()
+    ///     .await?;
+    ///
+    /// copy.write_row((1, "foo".to_owned())).await?;
+    /// copy.write_row((2, "bar".to_owned())).await?;
+    ///
+    /// assert_eq!(copy.finish().await?, 2);
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub fn bulk_copy_builder<T: PgCopyRow>(
+        &mut self,
+        table: impl Into<String>,
+    ) -> PgBulkCopyBuilder<&mut Self, T> {
+        PgBulkCopyBuilder {
+            conn: self,
+            options: PgCopyInOptions::new(table).format(PgCopyFormat::Binary),
+            validator: None,
+            row: PhantomData,
+        }
+    }
+
     /// Issue a `COPY TO STDOUT` statement and transition the connection to streaming data
     /// from Postgres. This is a more efficient way to export data from Postgres but
     /// arrives in chunks of one of a few data formats (text/CSV/binary).
@@ -61,6 +190,313 @@ impl PgConnection {
     ) -> Result<BoxStream<'c, Result<Bytes>>> {
         pg_begin_copy_out(self, statement).await
     }
+
+    /// Issue a `COPY TO STDOUT` statement and write the data directly to `sink` as it arrives,
+    /// without collecting it in memory first.
+    ///
+    /// Returns the number of bytes written to `sink`, which is flushed at the end.
+    ///
+    /// If `statement` is anything other than a `COPY ... TO STDOUT ...` command,
+    /// an error is returned.
+    ///
+    /// If writing to `sink` fails, the rest of the data is read and discarded so the connection
+    /// can be reused, and the I/O error is returned.
+    ///
+    /// Command examples and accepted formats for `COPY` data are shown here:
+    /// <https://www.postgresql.org/docs/current/sql-copy.html>
+    ///
+    /// ### Note: Runtime Features
+    /// This method uses the `AsyncWrite` trait which is re-exported from either Tokio or
+    /// `async-std` depending on which runtime feature is used.
+    ///
+    /// If both `runtime-async-std` and `runtime-tokio` features are enabled, the Tokio version
+    /// takes precedent.
+    pub async fn copy_out_to(
+        &mut self,
+        statement: &str,
+        mut sink: impl AsyncWrite + Unpin,
+    ) -> Result<u64> {
+        let mut stream = pg_begin_copy_out(self, statement).await?;
+        let mut written = 0_u64;
+
+        while let Some(chunk) = stream.try_next().await? {
+            if let Err(e) = sink.write_all(&chunk).await {
+                // finish the `COPY` so the connection is left in a usable state
+                while stream.try_next().await?.is_some() {}
+
+                return Err(e.into());
+            }
+
+            written += chunk.len() as u64;
+        }
+
+        sink.flush().await?;
+
+        Ok(written)
+    }
+
+    /// Issue a `COPY ... TO STDOUT (FORMAT binary)` statement and decode the data it returns
+    /// into one row per copied tuple.
+    ///
+    /// Binary `COPY` data does not describe its own columns, so `columns` gives the type of
+    /// each one. Values are read by index with [`Row::try_get()`][sqlx_core::row::Row::try_get],
+    /// which checks them against these types; the columns have no names.
+    ///
+    /// If `statement` is anything other than a binary `COPY ... TO STDOUT ...` command, or it
+    /// copies a different number of columns than given, an error is returned.
+    ///
+    /// As with [`copy_out_raw()`][Self::copy_out_raw], if you don't read the stream to
+    /// completion, the next time the connection is used it will need to read and discard all
+    /// the remaining queued data.
+    ///
+    /// Binary `COPY` data is described here:
+    /// <https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.4>
+    pub async fn copy_out_binary<'c>(
+        &'c mut self,
+        statement: &str,
+        columns: &[PgTypeInfo],
+    ) -> Result<BoxStream<'c, Result<PgRow>>> {
+        #[cfg(feature = "chrono-tz")]
+        let time_zone = self.inner.stream.time_zone.clone();
+        let (response, mut chunks) = pg_begin_copy_out_with_response(self, statement).await?;
+
+        let mismatch = if response.format != 1 {
+            Some(err_protocol!(
+                "copy_out_binary: expected a binary COPY, got a text or CSV one"
+            ))
+        } else if response.num_columns.unsigned_abs() as usize != columns.len() {
+            Some(err_protocol!(
+                "copy_out_binary: COPY returns {} columns, but {} column types were given",
+                response.num_columns,
+                columns.len()
+            ))
+        } else {
+            None
+        };
+
+        if let Some(e) = mismatch {
+            // finish the `COPY` so the connection is left in a usable state
+            while chunks.try_next().await?.is_some() {}
+
+            return Err(e);
+        }
+
+        let metadata = Arc::new(PgStatementMetadata {
+            columns: columns
+                .iter()
+                .enumerate()
+                .map(|(ordinal, type_info)| PgColumn {
+                    ordinal,
+                    name: UStr::Static("?column?"),
+                    type_info: type_info.clone(),
+                    relation_id: None,
+                    relation_attribute_no: None,
+                })
+                .collect(),
+            column_names: Default::default(),
+            parameters: Vec::new(),
+        });
+
+        let num_columns = columns.len();
+
+        let stream: TryAsyncStream<'c, PgRow> = try_stream! {
+            let mut decoder = BinaryCopyDecoder::new(num_columns);
+
+            while let Some(chunk) = chunks.try_next().await? {
+                decoder.buf.extend_from_slice(&chunk);
+
+                loop {
+                    match decoder.next_row() {
+                        Ok(Some(data)) => r#yield!(PgRow {
+                            data,
+                            format: PgValueFormat::Binary,
+                            metadata: Arc::clone(&metadata),
+                            #[cfg(feature = "chrono-tz")]
+                            time_zone: time_zone.clone(),
+                        }),
+                        Ok(None) => break,
+                        Err(e) => {
+                            while chunks.try_next().await?.is_some() {}
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+
+            if !matches!(decoder.state, BinaryCopyState::Done) {
+                return Err(err_protocol!("copy_out_binary: COPY data ended without a trailer"));
+            }
+
+            Ok(())
+        };
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Issue a statement that starts `COPY BOTH` mode, such as `START_REPLICATION`, and
+    /// transition the connection to exchanging `CopyData` messages with Postgres in both
+    /// directions.
+    ///
+    /// This is the building block of streaming replication. Messages are passed through as raw
+    /// bytes, so encoding and decoding the replication protocol is left to the caller.
+    /// Replication commands are only accepted on a connection opened with
+    /// [`PgConnectOptions::logical_replication`][crate::PgConnectOptions::logical_replication].
+    ///
+    /// If `statement` does not start `COPY BOTH` mode, an error is returned.
+    ///
+    /// The replication protocol and the messages exchanged are described here:
+    /// <https://www.postgresql.org/docs/current/protocol-replication.html>
+    ///
+    /// ### Note
+    /// [PgCopyBoth::finish] should be called to end the stream. If the `PgCopyBoth` is dropped
+    /// before then, the stream is ended the next time the connection is used, discarding any
+    /// data the server still sends.
+    pub async fn copy_both(&mut self, statement: &str) -> Result<PgCopyBoth<&mut Self>> {
+        PgCopyBoth::begin(self, statement).await
+    }
+}
+
+// "PGCOPY\n\377\r\n\0"
+const BINARY_COPY_SIGNATURE: &[u8] = b"PGCOPY\n\xff\r\n\0";
+
+// set in the header flags if each tuple is preceded by its OID
+const BINARY_COPY_HAS_OIDS: u32 = 1 << 16;
+
+enum BinaryCopyState {
+    Header,
+    Tuples,
+    Done,
+}
+
+/// Splits binary `COPY` data, which may arrive in arbitrary chunks, into rows.
+struct BinaryCopyDecoder {
+    buf: BytesMut,
+    num_columns: usize,
+    state: BinaryCopyState,
+}
+
+impl BinaryCopyDecoder {
+    fn new(num_columns: usize) -> Self {
+        Self {
+            buf: BytesMut::new(),
+            num_columns,
+            state: BinaryCopyState::Header,
+        }
+    }
+
+    /// Returns the next complete row, or `None` if more data is needed.
+    fn next_row(&mut self) -> Result<Option<DataRow>> {
+        if let BinaryCopyState::Header = self.state {
+            if !self.read_header()? {
+                return Ok(None);
+            }
+
+            self.state = BinaryCopyState::Tuples;
+        }
+
+        if let BinaryCopyState::Done = self.state {
+            if !self.buf.is_empty() {
+                return Err(err_protocol!(
+                    "copy_out_binary: {} bytes of COPY data after the trailer",
+                    self.buf.len()
+                ));
+            }
+
+            return Ok(None);
+        }
+
+        let Some(len) = self.tuple_len()? else {
+            return Ok(None);
+        };
+
+        if len == 0 {
+            // the trailer: a field count of -1
+            self.buf.advance(2);
+            self.state = BinaryCopyState::Done;
+
+            return self.next_row();
+        }
+
+        // a tuple is laid out exactly like the body of a `DataRow` message
+        DataRow::decode_body(self.buf.split_to(len).freeze()).map(Some)
+    }
+
+    fn read_header(&mut self) -> Result<bool> {
+        // signature, flags and the length of the header extension
+        let fixed_len = BINARY_COPY_SIGNATURE.len() + 8;
+
+        if self.buf.len() < fixed_len {
+            return Ok(false);
+        }
+
+        if !self.buf.starts_with(BINARY_COPY_SIGNATURE) {
+            return Err(err_protocol!(
+                "copy_out_binary: COPY data does not start with the binary signature"
+            ));
+        }
+
+        let mut header = &self.buf[BINARY_COPY_SIGNATURE.len()..];
+        let flags = header.get_u32();
+        let extension_len = header.get_u32() as usize;
+
+        if flags & BINARY_COPY_HAS_OIDS != 0 {
+            return Err(err_protocol!(
+                "copy_out_binary: COPY data WITH OIDS is not supported"
+            ));
+        }
+
+        if self.buf.len() < fixed_len + extension_len {
+            return Ok(false);
+        }
+
+        // the header extension has no defined contents, so it is skipped
+        self.buf.advance(fixed_len + extension_len);
+
+        Ok(true)
+    }
+
+    /// Returns the length of the next tuple if it has been received in full,
+    /// or `Some(0)` for the trailer.
+    fn tuple_len(&self) -> Result<Option<usize>> {
+        let mut buf = &self.buf[..];
+
+        if buf.len() < 2 {
+            return Ok(None);
+        }
+
+        let num_fields = buf.get_i16();
+
+        if num_fields == -1 {
+            return Ok(Some(0));
+        }
+
+        if num_fields.unsigned_abs() as usize != self.num_columns {
+            return Err(err_protocol!(
+                "copy_out_binary: expected {} fields in COPY tuple, got {num_fields}",
+                self.num_columns
+            ));
+        }
+
+        let mut len = 2;
+
+        for _ in 0..self.num_columns {
+            if buf.len() < 4 {
+                return Ok(None);
+            }
+
+            // -1 is NULL, with no bytes following
+            let field_len = usize::try_from(buf.get_i32()).unwrap_or(0);
+
+            if buf.len() < field_len {
+                return Ok(None);
+            }
+
+            buf.advance(field_len);
+            len += 4 + field_len;
+        }
+
+        Ok(Some(len))
+    }
 }
 
 /// Implements methods for directly executing `COPY FROM/TO STDOUT` on a [`PgPool`][crate::PgPool].
@@ -81,8 +517,9 @@ pub trait PgPoolCopyExt {
     /// <https://www.postgresql.org/docs/current/sql-copy.html>
     ///
     /// ### Note
-    /// [PgCopyIn::finish] or [PgCopyIn::abort] *must* be called when finished or the connection
-    /// will return an error the next time it is used.
+    /// [PgCopyIn::finish] must be called to commit the copied data. If the `PgCopyIn` is dropped
+    /// before then, the `COPY` is aborted the next time the connection is used, the same as
+    /// calling [PgCopyIn::abort].
     fn copy_in_raw<'a>(
         &'a self,
         statement: &'a str,
@@ -132,17 +569,180 @@ impl PgPoolCopyExt for Pool<Postgres> {
 // (1 GiB - 1) - 1 - length prefix (4 bytes)
 pub const PG_COPY_MAX_DATA_LEN: usize = 0x3fffffff - 1 - 4;
 
+/// The format of the data sent to `COPY FROM STDIN`.
+///
+/// See <https://www.postgresql.org/docs/current/sql-copy.html#SQL-COPY-FILE-FORMATS>
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum PgCopyFormat {
+    #[default]
+    Text,
+    Csv,
+    Binary,
+}
+
+/// What `COPY FROM STDIN` does with a row containing a value that cannot be converted to the
+/// type of its column.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum PgCopyOnError {
+    /// Fail the `COPY`, the default.
+    #[default]
+    Stop,
+
+    /// Skip the row and carry on (`ON_ERROR ignore`).
+    ///
+    /// Requires Postgres 17 or later, and the text or CSV format. The number of rows skipped is
+    /// reported by [`PgCopyInResult::rows_skipped()`].
+    Ignore,
+}
+
+/// Builds the statement issued by [`PgConnection::copy_in_with()`]:
+/// `COPY <table> [(<columns>)] FROM STDIN [WITH (<options>)]`.
+///
+/// The table and column names are inserted as-is, so they may be schema-qualified,
+/// and must be quoted if they need to be.
+#[derive(Debug, Clone)]
+pub struct PgCopyInOptions {
+    table: String,
+    columns: Vec<String>,
+    format: PgCopyFormat,
+    on_error: PgCopyOnError,
+    checksum: bool,
+}
+
+impl PgCopyInOptions {
+    /// Copy into every column of `table`, in the text format.
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            table: table.into(),
+            columns: Vec::new(),
+            format: PgCopyFormat::default(),
+            on_error: PgCopyOnError::default(),
+            checksum: false,
+        }
+    }
+
+    /// Copy into only these columns of the table, in this order.
+    pub fn columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the format of the data that will be sent.
+    pub fn format(mut self, format: PgCopyFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set what happens to rows with values that cannot be converted to their column's type.
+    pub fn on_error(mut self, on_error: PgCopyOnError) -> Self {
+        self.on_error = on_error;
+        self
+    }
+
+    /// Compute a CRC-32 checksum of the data sent, to compare against one of the source data.
+    ///
+    /// See [`PgCopyIn::with_checksum()`].
+    pub fn checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// The `COPY` statement built from these options.
+    pub fn to_sql(&self) -> String {
+        let mut sql = format!("COPY {}", self.table);
+
+        if !self.columns.is_empty() {
+            sql.push_str(" (");
+            sql.push_str(&self.columns.join(", "));
+            sql.push(')');
+        }
+
+        sql.push_str(" FROM STDIN");
+
+        let mut options = Vec::new();
+
+        match self.format {
+            PgCopyFormat::Text => {}
+            PgCopyFormat::Csv => options.push("FORMAT csv"),
+            PgCopyFormat::Binary => options.push("FORMAT binary"),
+        }
+
+        match self.on_error {
+            PgCopyOnError::Stop => {}
+            PgCopyOnError::Ignore => options.push("ON_ERROR ignore"),
+        }
+
+        if !options.is_empty() {
+            sql.push_str(" WITH (");
+            sql.push_str(&options.join(", "));
+            sql.push(')');
+        }
+
+        sql
+    }
+}
+
+/// The outcome of a `COPY FROM STDIN`, returned by [`PgCopyIn::finish_with_result()`].
+#[derive(Debug, Clone, Default)]
+pub struct PgCopyInResult {
+    rows_copied: u64,
+    rows_skipped: Option<u64>,
+    checksum: Option<u32>,
+}
+
+impl PgCopyInResult {
+    /// The number of rows copied into the table.
+    pub fn rows_copied(&self) -> u64 {
+        self.rows_copied
+    }
+
+    /// The number of rows skipped under [`PgCopyOnError::Ignore`].
+    ///
+    /// This is taken from the notice the server sends at the end of the `COPY` if any rows were
+    /// skipped. The notice can only be parsed if `lc_messages` is set to English;
+    /// otherwise this is `None`.
+    pub fn rows_skipped(&self) -> Option<u64> {
+        self.rows_skipped
+    }
+
+    /// The CRC-32 checksum of the data sent, if it was [computed][PgCopyIn::with_checksum].
+    pub fn checksum(&self) -> Option<u32> {
+        self.checksum
+    }
+}
+
+// CRC-32 as used by zlib, gzip and PNG, for which checksums of the source data are easy to get
+static COPY_CHECKSUM: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+// Parse the number of rows from the notice sent at the end of a `COPY` with `ON_ERROR ignore`,
+// such as "3 rows were skipped due to data type incompatibility".
+fn parse_rows_skipped(notice: &str) -> Option<u64> {
+    let (count, rest) = notice.split_once(' ')?;
+
+    if !rest.ends_with("skipped due to data type incompatibility") {
+        return None;
+    }
+
+    count.parse().ok()
+}
+
 /// A connection in streaming `COPY FROM STDIN` mode.
 ///
 /// Created by [PgConnection::copy_in_raw] or [Pool::copy_out_raw].
 ///
 /// ### Note
-/// [PgCopyIn::finish] or [PgCopyIn::abort] *must* be called when finished or the connection
-/// will return an error the next time it is used.
-#[must_use = "connection will error on next use if `.finish()` or `.abort()` is not called"]
+/// [PgCopyIn::finish] must be called to commit the copied data. If the `PgCopyIn` is dropped
+/// before then, the `COPY` is aborted the next time the connection is used, the same as
+/// calling [PgCopyIn::abort].
+#[must_use = "the `COPY` is aborted if `.finish()` is not called"]
 pub struct PgCopyIn<C: DerefMut<Target = PgConnection>> {
     conn: Option<C>,
     response: CopyResponseData,
+    checksum: Option<Digest<'static, u32>>,
 }
 
 impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
@@ -161,9 +761,26 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
         Ok(PgCopyIn {
             conn: Some(conn),
             response,
+            checksum: None,
         })
     }
 
+    /// Compute a CRC-32 checksum of the data sent from now on, which is reported by
+    /// [`finish_with_result()`][Self::finish_with_result] so it can be compared against a
+    /// checksum of the source data.
+    ///
+    /// This is the CRC-32 of zlib and gzip (`CRC-32/ISO-HDLC`), computed over the bytes as
+    /// they are sent, before the server parses them.
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = Some(COPY_CHECKSUM.digest());
+        self
+    }
+
+    /// The CRC-32 checksum of the data sent so far, if [enabled][Self::with_checksum].
+    pub fn checksum(&self) -> Option<u32> {
+        self.checksum.clone().map(|checksum| checksum.finalize())
+    }
+
     /// Returns `true` if Postgres is expecting data in text or CSV format.
     pub fn is_textual(&self) -> bool {
         self.response.format == 0
@@ -195,6 +812,10 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     /// If you're copying data from an `AsyncRead`, maybe consider [Self::read_from] instead.
     pub async fn send(&mut self, data: impl Deref<Target = [u8]>) -> Result<&mut Self> {
         for chunk in data.deref().chunks(PG_COPY_MAX_DATA_LEN) {
+            if let Some(checksum) = &mut self.checksum {
+                checksum.update(chunk);
+            }
+
             self.conn
                 .as_deref_mut()
                 .expect("send_data: conn taken")
@@ -223,6 +844,8 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     /// takes precedent.
     pub async fn read_from(&mut self, mut source: impl AsyncRead + Unpin) -> Result<&mut Self> {
         let conn: &mut PgConnection = self.conn.as_deref_mut().expect("copy_from: conn taken");
+        let checksum = &mut self.checksum;
+
         loop {
             let buf = conn.inner.stream.write_buffer_mut();
 
@@ -243,6 +866,10 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
 
             (&mut buf.get_mut()[1..]).put_i32(read32 + 4);
 
+            if let Some(checksum) = checksum {
+                checksum.update(&buf.get_mut()[5..][..read]);
+            }
+
             conn.inner.stream.flush().await?;
         }
 
@@ -255,6 +882,11 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     ///
     /// The server is expected to respond with an error, so only _unexpected_ errors are returned.
     pub async fn abort(mut self, msg: impl Into<String>) -> Result<()> {
+        self.abort_in_place(msg).await
+    }
+
+    // Like `abort()`, but leaves `self` without a connection instead of consuming it.
+    async fn abort_in_place(&mut self, msg: impl Into<String>) -> Result<()> {
         let mut conn = self
             .conn
             .take()
@@ -284,12 +916,42 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     /// Signal that the `COPY` process is complete.
     ///
     /// The number of rows affected is returned.
-    pub async fn finish(mut self) -> Result<u64> {
+    pub async fn finish(self) -> Result<u64> {
+        Ok(self.finish_with_result().await?.rows_copied())
+    }
+
+    /// Signal that the `COPY` process is complete, returning both the number of rows copied and
+    /// the number of rows skipped under [`PgCopyOnError::Ignore`].
+    pub async fn finish_with_result(mut self) -> Result<PgCopyInResult> {
         let mut conn = self
             .conn
             .take()
             .expect("CopyWriter::finish: conn taken illegally");
 
+        let checksum = self.checksum();
+
+        let capture = CaptureNotices::start(&mut conn);
+
+        let result = Self::recv_finish(capture.conn).await;
+        let notices = capture.conn.inner.stream.captured_notices.take();
+
+        // the server only reports skipped rows if there were any
+        let rows_skipped = notices
+            .iter()
+            .flatten()
+            .filter(|notice| notice.get(b'R') == Some("CopyFrom"))
+            .try_fold(0, |skipped, notice| {
+                Some(skipped + parse_rows_skipped(notice.message())?)
+            });
+
+        Ok(PgCopyInResult {
+            rows_copied: result?,
+            rows_skipped,
+            checksum,
+        })
+    }
+
+    async fn recv_finish(conn: &mut PgConnection) -> Result<u64> {
         conn.inner.stream.send(CopyDone).await?;
         let cc: CommandComplete = match conn.inner.stream.recv_expect().await {
             Ok(cc) => cc,
@@ -314,18 +976,516 @@ impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyIn<C> {
                     "PgCopyIn dropped without calling finish() or fail()",
                 ))
                 .expect("BUG: PgCopyIn abort message should not be too large");
+
+            // the server answers `CopyFail` with an error and then `ReadyForQuery`,
+            // which `wait_until_ready()` consumes before the connection is used again
+            conn.inner.pending_ready_for_query_count += 1;
+            conn.inner.copy_in_aborted = true;
         }
     }
 }
 
+// Captures the notices the server sends until dropped, even if `finish_with_result()` is cancelled
+struct CaptureNotices<'a> {
+    conn: &'a mut PgConnection,
+}
+
+impl<'a> CaptureNotices<'a> {
+    fn start(conn: &'a mut PgConnection) -> Self {
+        conn.inner.stream.captured_notices = Some(Vec::new());
+
+        Self { conn }
+    }
+}
+
+impl Drop for CaptureNotices<'_> {
+    fn drop(&mut self) {
+        self.conn.inner.stream.captured_notices = None;
+    }
+}
+
+/// A connection in `COPY BOTH` mode, exchanging raw `CopyData` messages with Postgres in both
+/// directions.
+///
+/// Created by [PgConnection::copy_both].
+///
+/// ### Note
+/// [PgCopyBoth::finish] should be called to end the stream. If the `PgCopyBoth` is dropped
+/// before then, the stream is ended the next time the connection is used, discarding any
+/// data the server still sends.
+#[must_use = "the stream should be ended by calling `.finish()`"]
+pub struct PgCopyBoth<C: DerefMut<Target = PgConnection>> {
+    conn: C,
+    // the server sent `CopyDone`, so it will not send any more data
+    server_done: bool,
+    // `CopyDone` was sent, or the server ended the `COPY` with an error,
+    // and the `ReadyForQuery` that follows is left to `wait_until_ready()`
+    ended: bool,
+}
+
+impl<C: DerefMut<Target = PgConnection>> PgCopyBoth<C> {
+    async fn begin(mut conn: C, statement: &str) -> Result<Self> {
+        conn.wait_until_ready().await?;
+        conn.inner.stream.send(Query(statement)).await?;
+
+        if let Err(e) = conn.inner.stream.recv_expect::<CopyBothResponse>().await {
+            // the rest of the response is read the next time the connection is used
+            conn.inner.pending_ready_for_query_count += 1;
+            return Err(e);
+        }
+
+        Ok(PgCopyBoth {
+            conn,
+            server_done: false,
+            ended: false,
+        })
+    }
+
+    /// Send one `CopyData` message.
+    ///
+    /// In replication, each message carries exactly one replication message, such as a
+    /// standby status update, so `data` is never split across several.
+    pub async fn send(&mut self, data: impl Deref<Target = [u8]>) -> Result<&mut Self> {
+        if self.ended {
+            return Err(err_protocol!(
+                "PgCopyBoth::send: the COPY has already ended"
+            ));
+        }
+
+        if data.len() > PG_COPY_MAX_DATA_LEN {
+            return Err(err_protocol!(
+                "PgCopyBoth::send: message of {} bytes exceeds the maximum of {}",
+                data.len(),
+                PG_COPY_MAX_DATA_LEN
+            ));
+        }
+
+        self.conn.inner.stream.send(CopyData(data)).await?;
+
+        Ok(self)
+    }
+
+    /// Receive the next `CopyData` message from the server.
+    ///
+    /// Returns `None` once the server has ended its side of the stream, after which
+    /// [`finish()`][Self::finish] should be called. If the server ends the `COPY` with an
+    /// error, the error is returned and the connection can be used again.
+    pub async fn recv(&mut self) -> Result<Option<Bytes>> {
+        if self.server_done || self.ended {
+            return Ok(None);
+        }
+
+        let msg = match self.conn.inner.stream.recv().await {
+            Ok(msg) => msg,
+            Err(e) => {
+                // the server has left `COPY BOTH` mode and sends `ReadyForQuery` next
+                self.ended = true;
+                self.conn.inner.pending_ready_for_query_count += 1;
+                return Err(e);
+            }
+        };
+
+        match msg.format {
+            BackendMessageFormat::CopyData => Ok(Some(msg.decode::<CopyData<Bytes>>()?.0)),
+            BackendMessageFormat::CopyDone => {
+                let _ = msg.decode::<CopyDone>()?;
+                self.server_done = true;
+                Ok(None)
+            }
+            _ => Err(err_protocol!(
+                "unexpected message format during copy both: {:?}",
+                msg.format
+            )),
+        }
+    }
+
+    /// Send `CopyDone` to end the stream, and read and discard whatever the server sends until
+    /// it is ready for other queries.
+    pub async fn finish(mut self) -> Result<()> {
+        self.end();
+        self.conn.wait_until_ready().await
+    }
+
+    fn end(&mut self) {
+        if self.ended {
+            return;
+        }
+
+        self.conn
+            .inner
+            .stream
+            .write_msg(CopyDone)
+            .expect("BUG: CopyDone should not be too large");
+
+        // the server answers with `CopyDone` if it had not sent it yet, `CommandComplete`
+        // and `ReadyForQuery`, which `wait_until_ready()` reads
+        self.conn.inner.pending_ready_for_query_count += 1;
+        self.ended = true;
+    }
+}
+
+impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyBoth<C> {
+    fn drop(&mut self) {
+        self.end();
+    }
+}
+
+/// A row of values for [`PgBulkCopy`], implemented for tuples of up to 9 values.
+pub trait PgCopyRow {
+    /// The types of the values in a row, one per column.
+    fn column_types() -> Vec<PgTypeInfo>;
+
+    /// Encode the values of this row into `arguments`, in order.
+    fn encode_row(self, arguments: &mut PgArguments) -> Result<(), BoxDynError>;
+}
+
+macro_rules! impl_copy_row_for_tuple {
+    ($( $idx:tt : $T:ident ),*) => {
+        impl<$($T,)*> PgCopyRow for ($($T,)*)
+        where
+            $($T: for<'q> Encode<'q, Postgres> + Type<Postgres>,)*
+        {
+            fn column_types() -> Vec<PgTypeInfo> {
+                vec![$($T::type_info(),)*]
+            }
+
+            fn encode_row(self, arguments: &mut PgArguments) -> Result<(), BoxDynError> {
+                $(arguments.add(self.$idx)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_copy_row_for_tuple!(0: T1);
+impl_copy_row_for_tuple!(0: T1, 1: T2);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7, 7: T8);
+impl_copy_row_for_tuple!(0: T1, 1: T2, 2: T3, 3: T4, 4: T5, 5: T6, 6: T7, 7: T8, 8: T9);
+
+/// Builds a binary `COPY FROM STDIN` of rows of type `T`.
+///
+/// Created by [`PgConnection::bulk_copy_builder()`].
+#[must_use = "the `COPY` is not started until `.begin()` is called"]
+pub struct PgBulkCopyBuilder<C: DerefMut<Target = PgConnection>, T> {
+    conn: C,
+    options: PgCopyInOptions,
+    validator: Option<RowValidator<T>>,
+    row: PhantomData<fn(T)>,
+}
+
+type RowValidator<T> = Box<dyn FnMut(T) -> Result<T, BoxDynError> + Send>;
+
+impl<C: DerefMut<Target = PgConnection>, T: PgCopyRow> PgBulkCopyBuilder<C, T> {
+    /// Copy into only these columns of the table, in the order of the values of `T`.
+    pub fn columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.options = self.options.columns(columns);
+        self
+    }
+
+    /// Pass every row through `validator` before it is written.
+    ///
+    /// The row returned by `validator` is written in place of the original, so it may also
+    /// transform rows. If it returns an error instead, the `COPY` is aborted, discarding every
+    /// row written so far, and [`write_row()`][PgBulkCopy::write_row] returns the error as
+    /// [`Error::Encode`].
+    pub fn validate_rows<F>(mut self, validator: F) -> Self
+    where
+        F: FnMut(T) -> Result<T, BoxDynError> + Send + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Issue the `COPY` statement and transition the connection to streaming rows to Postgres.
+    ///
+    /// Returns an error, and leaves the connection usable, if the `COPY` expects a different
+    /// number of columns than there are values in `T`.
+    pub async fn begin(self) -> Result<PgBulkCopy<C, T>> {
+        PgBulkCopy::begin(self.conn, &self.options, self.validator).await
+    }
+
+    /// Copy `rows` in batches of up to `batch_size` rows, each in its own transaction.
+    ///
+    /// Every batch is a separate `COPY`, begun like
+    /// [`Connection::begin()`][sqlx_core::connection::Connection::begin]: in a transaction
+    /// that is committed once the batch is copied, or in a savepoint that is released if a
+    /// transaction is already open. If a batch fails, only that batch is rolled back and the
+    /// returned [`PgCopyBatchError`] tells how many rows were committed before it, so the load
+    /// can be resumed by calling this again with the rows that follow them.
+    ///
+    /// The number of rows copied is returned.
+    ///
+    /// ### Panics
+    /// If `batch_size` is zero.
+    pub async fn copy_in_batches<I>(
+        mut self,
+        rows: I,
+        batch_size: usize,
+    ) -> Result<u64, PgCopyBatchError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        assert!(batch_size > 0, "batch_size must be greater than zero");
+
+        let mut rows = rows.into_iter().peekable();
+        let mut rows_committed = 0;
+
+        while rows.peek().is_some() {
+            match self.copy_batch(rows.by_ref().take(batch_size)).await {
+                Ok(rows_copied) => rows_committed += rows_copied,
+                Err(error) => {
+                    return Err(PgCopyBatchError {
+                        rows_committed,
+                        error,
+                    })
+                }
+            }
+        }
+
+        Ok(rows_committed)
+    }
+
+    async fn copy_batch(&mut self, rows: impl Iterator<Item = T>) -> Result<u64> {
+        let conn = &mut *self.conn;
+        let options = &self.options;
+        let validator = &mut self.validator;
+
+        PgTransactionManager::begin(conn).await?;
+
+        let result: Result<u64> = async {
+            let mut copy = PgBulkCopy::<_, T>::begin(&mut *conn, options, None).await?;
+
+            for row in rows {
+                // a rejected row fails the batch like any other error
+                let row = match validator {
+                    Some(validate) => validate(row).map_err(Error::Encode)?,
+                    None => row,
+                };
+
+                copy.write_row(row).await?;
+            }
+
+            copy.finish().await
+        }
+        .await;
+
+        let result = match result {
+            Ok(rows_copied) => PgTransactionManager::commit(conn)
+                .await
+                .map(|()| rows_copied),
+            Err(e) => Err(e),
+        };
+
+        // the error that failed the batch is the one to report, even if rolling it back fails too
+        if result.is_err() {
+            if let Err(error) = PgTransactionManager::rollback(conn).await {
+                tracing::warn!(%error, "failed to roll back a failed bulk copy batch");
+            }
+        }
+
+        result
+    }
+}
+
+/// The error returned by [`PgBulkCopyBuilder::copy_in_batches()`] when a batch fails.
+#[derive(Debug, thiserror::Error)]
+#[error("{error} (after {rows_committed} rows were committed)")]
+pub struct PgCopyBatchError {
+    rows_committed: u64,
+    #[source]
+    error: Error,
+}
+
+impl PgCopyBatchError {
+    /// The number of rows in the batches committed before the one that failed.
+    ///
+    /// These are the rows to skip when resuming the load.
+    pub fn rows_committed(&self) -> u64 {
+        self.rows_committed
+    }
+
+    /// The error that failed the batch.
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+}
+
+// rows are sent once this much data has been buffered
+const BULK_COPY_BUFFER_LEN: usize = 64 * 1024;
+
+/// A connection in binary `COPY FROM STDIN` mode, taking rows of type `T`.
+///
+/// Created by [`PgBulkCopyBuilder::begin()`].
+///
+/// ### Note
+/// [`PgBulkCopy::finish`] must be called to commit the copied rows. If the `PgBulkCopy` is
+/// dropped before then, the `COPY` is aborted, the same as with [`PgCopyIn`].
+#[must_use = "the `COPY` is aborted if `.finish()` is not called"]
+pub struct PgBulkCopy<C: DerefMut<Target = PgConnection>, T> {
+    copy: PgCopyIn<C>,
+    buf: Vec<u8>,
+    num_columns: i16,
+    validator: Option<RowValidator<T>>,
+    row: PhantomData<fn(T)>,
+}
+
+impl<C: DerefMut<Target = PgConnection>, T: PgCopyRow> PgBulkCopy<C, T> {
+    async fn begin(
+        mut conn: C,
+        options: &PgCopyInOptions,
+        validator: Option<RowValidator<T>>,
+    ) -> Result<Self> {
+        let column_types = T::column_types();
+
+        // the OIDs of types referred to by name cannot be looked up once the `COPY` has started
+        let names: Vec<&str> = column_types
+            .iter()
+            .filter_map(|ty| match &ty.0 {
+                PgType::DeclareWithName(name) => Some(&**name),
+                PgType::DeclareArrayOf(array) => Some(&*array.elem_name),
+                _ => None,
+            })
+            .collect();
+
+        conn.cache_type_oids(&names).await?;
+
+        let copy = PgCopyIn::begin(conn, &options.to_sql()).await?;
+
+        if copy.response.num_columns.unsigned_abs() as usize != column_types.len() {
+            let num_columns = copy.response.num_columns;
+
+            copy.abort("bulk copy: wrong number of columns").await?;
+
+            return Err(Error::Configuration(
+                format!(
+                    "bulk copy: COPY expects {num_columns} columns, but rows have {} values",
+                    column_types.len()
+                )
+                .into(),
+            ));
+        }
+
+        let mut buf = Vec::with_capacity(BULK_COPY_BUFFER_LEN);
+
+        buf.extend_from_slice(BINARY_COPY_SIGNATURE);
+        // flags
+        buf.extend_from_slice(&0_u32.to_be_bytes());
+        // header extension length
+        buf.extend_from_slice(&0_u32.to_be_bytes());
+
+        Ok(PgBulkCopy {
+            copy,
+            buf,
+            num_columns: i16::try_from(column_types.len())
+                .expect("BUG: tuples have at most 9 values"),
+            validator,
+            row: PhantomData,
+        })
+    }
+
+    /// Encode a row and queue it to be sent.
+    ///
+    /// Rows are buffered and sent in batches, so a row the server rejects may only be
+    /// reported by a later call, or by [`finish()`][Self::finish].
+    ///
+    /// Returns an error if the `COPY` was aborted because the
+    /// [validator][PgBulkCopyBuilder::validate_rows] rejected a row.
+    pub async fn write_row(&mut self, row: T) -> Result<&mut Self> {
+        self.check_not_aborted()?;
+
+        let row = match &mut self.validator {
+            Some(validate) => match validate(row) {
+                Ok(row) => row,
+                Err(e) => {
+                    self.copy
+                        .abort_in_place(format!("bulk copy: row rejected: {e}"))
+                        .await?;
+
+                    return Err(Error::Encode(e));
+                }
+            },
+            None => row,
+        };
+
+        let mut arguments = PgArguments::default();
+
+        row.encode_row(&mut arguments).map_err(Error::Encode)?;
+        arguments.buffer.check_no_external("in a bulk copy")?;
+        arguments
+            .apply_patches_cached(self.copy.conn.as_deref().expect("write_row: conn taken"))?;
+
+        self.buf.extend_from_slice(&self.num_columns.to_be_bytes());
+        // each value is already prefixed with its length, or `-1` for `NULL`
+        self.buf.extend_from_slice(&arguments.buffer);
+
+        if self.buf.len() >= BULK_COPY_BUFFER_LEN {
+            self.copy.send(&*self.buf).await?;
+            self.buf.clear();
+        }
+
+        Ok(self)
+    }
+
+    /// Signal that the `COPY` is complete, sending any buffered rows.
+    ///
+    /// The number of rows copied is returned.
+    pub async fn finish(mut self) -> Result<u64> {
+        self.check_not_aborted()?;
+
+        // file trailer
+        self.buf.extend_from_slice(&(-1_i16).to_be_bytes());
+        self.copy.send(&*self.buf).await?;
+
+        self.copy.finish().await
+    }
+
+    /// Abort the `COPY`, discarding every row sent; see [`PgCopyIn::abort()`].
+    pub async fn abort(self, msg: impl Into<String>) -> Result<()> {
+        if self.copy.conn.is_none() {
+            // already aborted after a row was rejected
+            return Ok(());
+        }
+
+        self.copy.abort(msg).await
+    }
+
+    fn check_not_aborted(&self) -> Result<()> {
+        if self.copy.conn.is_none() {
+            return Err(Error::Configuration(
+                "bulk copy: the COPY was aborted after a row was rejected".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
-    mut conn: C,
+    conn: C,
     statement: &str,
 ) -> Result<BoxStream<'c, Result<Bytes>>> {
+    let (_, stream) = pg_begin_copy_out_with_response(conn, statement).await?;
+
+    Ok(stream)
+}
+
+async fn pg_begin_copy_out_with_response<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
+    mut conn: C,
+    statement: &str,
+) -> Result<(CopyResponseData, BoxStream<'c, Result<Bytes>>)> {
     conn.wait_until_ready().await?;
     conn.inner.stream.send(Query(statement)).await?;
 
-    let _: CopyOutResponse = conn.inner.stream.recv_expect().await?;
+    let response: CopyOutResponse = conn.inner.stream.recv_expect().await?;
 
     let stream: TryAsyncStream<'c, Bytes> = try_stream! {
         loop {
@@ -348,5 +1508,45 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
         }
     };
 
-    Ok(Box::pin(stream))
+    Ok((response.0, Box::pin(stream)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rows_skipped, PgCopyFormat, PgCopyInOptions, PgCopyOnError};
+
+    #[test]
+    fn it_builds_copy_in_statements() {
+        assert_eq!(
+            PgCopyInOptions::new("users").to_sql(),
+            "COPY users FROM STDIN"
+        );
+
+        assert_eq!(
+            PgCopyInOptions::new("app.users")
+                .columns(["id", r#""Name""#])
+                .format(PgCopyFormat::Csv)
+                .on_error(PgCopyOnError::Ignore)
+                .to_sql(),
+            r#"COPY app.users (id, "Name") FROM STDIN WITH (FORMAT csv, ON_ERROR ignore)"#
+        );
+    }
+
+    #[test]
+    fn it_parses_rows_skipped_notices() {
+        assert_eq!(
+            parse_rows_skipped("1 row was skipped due to data type incompatibility"),
+            Some(1)
+        );
+        assert_eq!(
+            parse_rows_skipped("3 rows were skipped due to data type incompatibility"),
+            Some(3)
+        );
+        assert_eq!(
+            parse_rows_skipped(
+                "skipping row due to data type incompatibility at line 2 for column \"id\""
+            ),
+            None
+        );
+    }
 }
@@ -7,6 +7,7 @@ use sqlx_core::bytes::Bytes;
 pub(crate) use sqlx_core::error::*;
 
 use crate::message::{BackendMessage, BackendMessageFormat, Notice, PgSeverity};
+use crate::sqlstate;
 
 /// An error returned from the PostgreSQL database.
 pub struct PgDatabaseError(pub(crate) Notice);
@@ -162,6 +163,45 @@ impl Display for PgDatabaseError {
 
 impl StdError for PgDatabaseError {}
 
+/// Read the SQLSTATE of an error returned by Postgres without downcasting it to
+/// [`PgDatabaseError`].
+pub trait PgErrorExt {
+    /// The SQLSTATE code of this error, if it is a [`PgDatabaseError`].
+    ///
+    /// Well-known codes are in [`sqlstate`][crate::sqlstate].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example(conn: &mut sqlx_postgres::PgConnection) -> sqlx_core::Result<()> {
+    /// use sqlx_postgres::{sqlstate, PgErrorExt};
+    ///
+    /// let result = sqlx_core::query::query("INSERT INTO users (name) VALUES ('alice')")
+    ///     .execute(conn)
+    ///     .await;
+    ///
+    /// match result {
+    ///     Err(e) if e.sqlstate() == Some(sqlstate::UNIQUE_VIOLATION) => {
+    ///         // the user already exists
+    ///     }
+    ///     result => {
+    ///         result?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sqlstate(&self) -> Option<&str>;
+}
+
+impl PgErrorExt for Error {
+    fn sqlstate(&self) -> Option<&str> {
+        self.as_database_error()?
+            .try_downcast_ref::<PgDatabaseError>()
+            .map(PgDatabaseError::code)
+    }
+}
+
 impl DatabaseError for PgDatabaseError {
     fn message(&self) -> &str {
         self.message()
@@ -187,15 +227,12 @@ impl DatabaseError for PgDatabaseError {
     }
 
     fn is_transient_in_connect_phase(&self) -> bool {
-        // https://www.postgresql.org/docs/current/errcodes-appendix.html
         [
-            // too_many_connections
             // This may be returned if we just un-gracefully closed a connection,
             // give the database a chance to notice it and clean it up.
-            "53300",
-            // cannot_connect_now
+            sqlstate::TOO_MANY_CONNECTIONS,
             // Returned if the database is still starting up.
-            "57P03",
+            sqlstate::CANNOT_CONNECT_NOW,
         ]
         .contains(&self.code())
     }
@@ -210,10 +247,10 @@ impl DatabaseError for PgDatabaseError {
 
     fn kind(&self) -> ErrorKind {
         match self.code() {
-            error_codes::UNIQUE_VIOLATION => ErrorKind::UniqueViolation,
-            error_codes::FOREIGN_KEY_VIOLATION => ErrorKind::ForeignKeyViolation,
-            error_codes::NOT_NULL_VIOLATION => ErrorKind::NotNullViolation,
-            error_codes::CHECK_VIOLATION => ErrorKind::CheckViolation,
+            sqlstate::UNIQUE_VIOLATION => ErrorKind::UniqueViolation,
+            sqlstate::FOREIGN_KEY_VIOLATION => ErrorKind::ForeignKeyViolation,
+            sqlstate::NOT_NULL_VIOLATION => ErrorKind::NotNullViolation,
+            sqlstate::CHECK_VIOLATION => ErrorKind::CheckViolation,
             _ => ErrorKind::Other,
         }
     }
@@ -228,15 +265,3 @@ impl BackendMessage for PgDatabaseError {
         Ok(Self(Notice::decode_body(buf)?))
     }
 }
-
-/// For reference: <https://www.postgresql.org/docs/current/errcodes-appendix.html>
-pub(crate) mod error_codes {
-    /// Caused when a unique or primary key is violated.
-    pub const UNIQUE_VIOLATION: &str = "23505";
-    /// Caused when a foreign key is violated.
-    pub const FOREIGN_KEY_VIOLATION: &str = "23503";
-    /// Caused when a column marked as NOT NULL received a null value.
-    pub const NOT_NULL_VIOLATION: &str = "23502";
-    /// Caused when a check constraint is violated.
-    pub const CHECK_VIOLATION: &str = "23514";
-}
@@ -0,0 +1,100 @@
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+
+use crate::decode::Decode;
+use crate::error::Error;
+use crate::executor::Execute;
+use crate::query_scalar::query_scalar_with;
+use crate::types::Type;
+use crate::{PgArguments, PgExecutor, Postgres};
+use sqlx_core::row::Row;
+
+/// Convenience methods for any [`PgExecutor`], such as a connection, pool or transaction.
+pub trait PgExecutorExt<'c>: PgExecutor<'c> {
+    /// Execute `query` and return the first column of the first row, or `default` if
+    /// that value is `NULL` or the query returns no rows.
+    ///
+    /// Unlike [`query_scalar()`][crate::query_scalar::query_scalar] with a non-`Option` type,
+    /// a `NULL` is not an error. Any other decoding error is returned as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::Connection;
+    /// # use sqlx::postgres::{PgConnection, PgExecutorExt};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// // `max()` of no rows is `NULL`
+    /// let max_id: i64 = conn
+    ///     .fetch_scalar_or(sqlx::query("SELECT max(id) FROM users WHERE name = $1").bind("foo"), 0)
+    ///     .await?;
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    fn fetch_scalar_or<'e, 'q: 'e, E, T>(
+        self,
+        query: E,
+        default: T,
+    ) -> BoxFuture<'e, Result<T, Error>>
+    where
+        'c: 'e,
+        Self: Sized + 'e,
+        E: 'q + Execute<'q, Postgres>,
+        T: for<'r> Decode<'r, Postgres> + Type<Postgres> + Send + 'e,
+    {
+        Box::pin(async move {
+            let value = match self.fetch_optional(query).await? {
+                Some(row) => row.try_get::<Option<T>, _>(0)?,
+                None => None,
+            };
+
+            Ok(value.unwrap_or(default))
+        })
+    }
+
+    /// Execute `sql` with `arguments` and return a stream of the first column of each row.
+    ///
+    /// This is meant for statements returning one value per row, such as
+    /// `INSERT ... RETURNING id`, which are decoded as the rows arrive instead of being
+    /// collected first. It is the same as
+    /// [`query_scalar_with()`][crate::query_scalar::query_scalar_with] followed by `fetch()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use futures_util::TryStreamExt;
+    /// # use sqlx::{Arguments, Connection};
+    /// # use sqlx::postgres::{PgArguments, PgConnection, PgExecutorExt};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+    /// let mut arguments = PgArguments::default();
+    /// arguments.add(vec!["foo", "bar"]).map_err(sqlx::Error::Encode)?;
+    ///
+    /// let mut ids = conn.fetch_scalar_stream::<i64>(
+    ///     "INSERT INTO users (name) SELECT * FROM UNNEST($1::text[]) RETURNING id",
+    ///     arguments,
+    /// );
+    ///
+    /// while let Some(id) = ids.try_next().await? {
+    ///     println!("inserted {id}");
+    /// }
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    fn fetch_scalar_stream<'e, 'q: 'e, T>(
+        self,
+        sql: &'q str,
+        arguments: PgArguments,
+    ) -> BoxStream<'e, Result<T, Error>>
+    where
+        'c: 'e,
+        Self: Sized + 'e,
+        T: for<'r> Decode<'r, Postgres> + Type<Postgres> + Send + Unpin + 'e,
+    {
+        query_scalar_with(sql, arguments).fetch(self)
+    }
+}
+
+impl<'c, E: PgExecutor<'c>> PgExecutorExt<'c> for E {}
@@ -0,0 +1,15 @@
+use crate::error::Error;
+
+// Escapes `name` to be put between double quotes as an identifier, so it is matched exactly.
+//
+// Postgres cannot take a NUL byte anywhere in a query, so a name containing one is rejected
+// rather than cut short, which would quietly refer to a different object.
+pub(crate) fn ident(name: &str) -> Result<String, Error> {
+    if name.contains('\0') {
+        return Err(Error::Encode(
+            format!("identifier {name:?} contains a NUL byte").into(),
+        ));
+    }
+
+    Ok(name.replace('"', "\"\""))
+}
@@ -12,12 +12,16 @@ mod connection;
 mod copy;
 mod database;
 mod error;
+mod executor_ext;
+mod ident;
 mod io;
 mod listener;
 mod message;
+mod notice;
 mod options;
 mod query_result;
 mod row;
+pub mod sqlstate;
 mod statement;
 mod transaction;
 mod type_checking;
@@ -48,13 +52,23 @@ pub(crate) use sqlx_core::driver_prelude::*;
 pub use advisory_lock::{PgAdvisoryLock, PgAdvisoryLockGuard, PgAdvisoryLockKey};
 pub use arguments::{PgArgumentBuffer, PgArguments};
 pub use column::PgColumn;
-pub use connection::PgConnection;
-pub use copy::{PgCopyIn, PgPoolCopyExt};
+pub use connection::{
+    PgConnection, PgFetchSize, PgFetchWithResult, PgPortal, PgProtocolDirection, PgProtocolMessage,
+};
+pub use copy::{
+    PgBulkCopy, PgBulkCopyBuilder, PgCopyBatchError, PgCopyBoth, PgCopyFormat, PgCopyIn,
+    PgCopyInOptions, PgCopyInResult, PgCopyOnError, PgCopyRow, PgPoolCopyExt,
+};
 pub use database::Postgres;
-pub use error::{PgDatabaseError, PgErrorPosition};
-pub use listener::{PgListener, PgNotification};
+pub use error::{PgDatabaseError, PgErrorExt, PgErrorPosition};
+pub use executor_ext::PgExecutorExt;
+pub use listener::{PgListener, PgListenerHub, PgNotification, PgSubscription};
 pub use message::PgSeverity;
-pub use options::{PgConnectOptions, PgSslMode};
+pub use notice::PgNotice;
+pub use options::{
+    PgApplicationNameSuffix, PgChannelBinding, PgConnectOptions, PgSslMode, PgTargetSessionAttrs,
+    TlsVersion,
+};
 pub use query_result::PgQueryResult;
 pub use row::PgRow;
 pub use statement::PgStatement;
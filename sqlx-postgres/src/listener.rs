@@ -1,18 +1,22 @@
 use std::fmt::{self, Debug};
 use std::io;
+use std::pin::Pin;
 use std::str::from_utf8;
+use std::task::{Context, Poll};
+use std::time::Duration;
 
-use futures_channel::mpsc;
+use futures_channel::{mpsc, oneshot};
 use futures_core::future::BoxFuture;
 use futures_core::stream::{BoxStream, Stream};
-use futures_util::{FutureExt, StreamExt, TryFutureExt, TryStreamExt};
+use futures_util::{future, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use sqlx_core::acquire::Acquire;
 use sqlx_core::transaction::Transaction;
-use sqlx_core::Either;
+use sqlx_core::{Either, HashMap};
 
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::ident::ident;
 use crate::message::{BackendMessageFormat, Notification};
 use crate::pool::PoolOptions;
 use crate::pool::{Pool, PoolConnection};
@@ -35,6 +39,7 @@ pub struct PgListener {
 }
 
 /// An asynchronous notification from Postgres.
+#[derive(Clone)]
 pub struct PgNotification(Notification);
 
 impl PgListener {
@@ -115,7 +120,7 @@ impl PgListener {
     pub async fn listen(&mut self, channel: &str) -> Result<(), Error> {
         self.connection()
             .await?
-            .execute(&*format!(r#"LISTEN "{}""#, ident(channel)))
+            .execute(&*format!(r#"LISTEN "{}""#, ident(channel)?))
             .await?;
 
         self.channels.push(channel.to_owned());
@@ -128,10 +133,10 @@ impl PgListener {
         &mut self,
         channels: impl IntoIterator<Item = &str>,
     ) -> Result<(), Error> {
-        let beg = self.channels.len();
-        self.channels.extend(channels.into_iter().map(|s| s.into()));
+        let channels: Vec<String> = channels.into_iter().map(|s| s.into()).collect();
 
-        let query = build_listen_all_query(&self.channels[beg..]);
+        let query = build_listen_all_query(&channels)?;
+        self.channels.extend(channels);
         self.connection().await?.execute(&*query).await?;
 
         Ok(())
@@ -144,7 +149,7 @@ impl PgListener {
         // UNLISTEN (we've disconnected anyways)
         if let Some(connection) = self.connection.as_mut() {
             connection
-                .execute(&*format!(r#"UNLISTEN "{}""#, ident(channel)))
+                .execute(&*format!(r#"UNLISTEN "{}""#, ident(channel)?))
                 .await?;
         }
 
@@ -175,7 +180,7 @@ impl PgListener {
             connection.inner.stream.notifications = self.buffer_tx.take();
 
             connection
-                .execute(&*build_listen_all_query(&self.channels))
+                .execute(&*build_listen_all_query(&self.channels)?)
                 .await?;
 
             self.connection = Some(connection);
@@ -445,6 +450,98 @@ impl<'c> Executor<'c> for &'c mut PgListener {
     }
 }
 
+impl PgConnection {
+    /// Wait up to `timeout` for a single notification on `channel`.
+    ///
+    /// This issues `LISTEN` on `channel`, waits for the first notification raised on it and
+    /// then issues `UNLISTEN`, whether or not one arrived. Returns `None` if the timeout
+    /// elapsed first.
+    ///
+    /// Notifications raised on other channels while waiting are discarded. To receive more
+    /// than one notification, use [`PgListener`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use sqlx::{Connection, postgres::PgConnection};
+    /// #
+    /// # sqlx::__rt::test_block_on(async move {
+    /// let mut conn = PgConnection::connect("postgres:// ...").await?;
+    ///
+    /// if let Some(notification) = conn.wait_for_notify("jobs", Duration::from_secs(5)).await? {
+    ///     println!("job ready: {}", notification.payload());
+    /// }
+    /// # Result::<(), sqlx::Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn wait_for_notify(
+        &mut self,
+        channel: &str,
+        timeout: Duration,
+    ) -> Result<Option<PgNotification>, Error> {
+        let name = ident(channel)?;
+
+        // Notifications arriving while `LISTEN` or `UNLISTEN` run are buffered here,
+        // as the executor would otherwise see them as unexpected messages.
+        let (buffer_tx, mut buffer_rx) = mpsc::unbounded();
+        let previous = self.inner.stream.notifications.replace(buffer_tx);
+
+        let res = self.listen_once(channel, timeout, &mut buffer_rx).await;
+
+        let unlisten = self.execute(&*format!(r#"UNLISTEN "{name}""#)).await;
+
+        self.inner.stream.notifications = previous;
+
+        let notification = res?;
+        unlisten?;
+
+        Ok(notification)
+    }
+
+    async fn listen_once(
+        &mut self,
+        channel: &str,
+        timeout: Duration,
+        buffer: &mut mpsc::UnboundedReceiver<Notification>,
+    ) -> Result<Option<PgNotification>, Error> {
+        self.execute(&*format!(r#"LISTEN "{}""#, ident(channel)?))
+            .await?;
+
+        // the notification may have arrived before `LISTEN` completed
+        while let Ok(Some(notification)) = buffer.try_next() {
+            if notification.channel == channel.as_bytes() {
+                return Ok(Some(PgNotification(notification)));
+            }
+        }
+
+        // with no buffer set, `recv()` hands notifications back to us
+        let buffer_tx = self.inner.stream.notifications.take();
+
+        let res = crate::rt::timeout(timeout, async {
+            loop {
+                let message = self.inner.stream.recv().await?;
+
+                if message.format == BackendMessageFormat::NotificationResponse {
+                    let notification: Notification = message.decode()?;
+
+                    if notification.channel == channel.as_bytes() {
+                        return Ok(PgNotification(notification));
+                    }
+                }
+            }
+        })
+        .await;
+
+        self.inner.stream.notifications = buffer_tx;
+
+        match res {
+            Ok(res) => res.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
 impl PgNotification {
     /// The process ID of the notifying backend process.
     #[inline]
@@ -465,6 +562,216 @@ impl PgNotification {
     pub fn payload(&self) -> &str {
         from_utf8(&self.0.payload).unwrap()
     }
+
+    /// Deserialize the payload of the notification from JSON, e.g. one sent by
+    /// `pg_notify('channel', row_to_json(row)::text)`.
+    ///
+    /// Returns an error if the payload is not valid JSON or does not match `T`.
+    #[cfg(feature = "json")]
+    pub fn payload_json<'a, T>(&'a self) -> Result<T, serde_json::Error>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        serde_json::from_str(self.payload())
+    }
+}
+
+/// A single [`PgListener`] connection shared by many in-process subscribers.
+///
+/// Each call to [`subscribe()`][Self::subscribe] returns a [`PgSubscription`] that receives
+/// every notification raised on its channel. Subscribers of the same channel share one
+/// `LISTEN` on the underlying connection, so an application can fan notifications out to
+/// any number of tasks while holding only one connection.
+///
+/// Dispatch happens on a background task which owns the listener. It stops, ending every
+/// subscription, when all handles to the hub are dropped or the listener returns an error.
+/// A channel is unlistened once the last of its subscriptions has been dropped and another
+/// notification arrives for it.
+///
+/// Each subscription buffers up to [`SUBSCRIPTION_CAPACITY`][Self::SUBSCRIPTION_CAPACITY]
+/// notifications that have not been received yet. Once a subscription is full, further
+/// notifications on its channel are discarded for that subscription alone (and a warning is
+/// logged) until it catches up, so one slow subscriber never holds up the others.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use sqlx::postgres::PgListenerHub;
+/// #
+/// # sqlx::__rt::test_block_on(async move {
+/// let hub = PgListenerHub::connect("postgres:// ...").await?;
+///
+/// let mut orders = hub.subscribe("orders").await?;
+/// let mut audit = hub.subscribe("orders").await?;
+///
+/// // both subscriptions receive every notification raised on `orders`
+/// while let Some(notification) = orders.recv().await {
+///     // handle notification
+/// }
+/// # Result::<(), sqlx::Error>::Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct PgListenerHub {
+    commands: mpsc::UnboundedSender<HubCommand>,
+}
+
+/// A stream of notifications for one channel, returned by [`PgListenerHub::subscribe()`].
+pub struct PgSubscription {
+    channel: String,
+    receiver: mpsc::Receiver<PgNotification>,
+}
+
+struct HubCommand {
+    channel: String,
+    sender: mpsc::Sender<PgNotification>,
+    reply: oneshot::Sender<Result<(), Error>>,
+}
+
+impl PgListenerHub {
+    /// The number of notifications each [`PgSubscription`] buffers before further ones are
+    /// discarded.
+    pub const SUBSCRIPTION_CAPACITY: usize = 1024;
+
+    /// Connect a new listener to `url` and start dispatching its notifications.
+    pub async fn connect(url: &str) -> Result<Self, Error> {
+        Ok(Self::new(PgListener::connect(url).await?))
+    }
+
+    /// Take a connection from `pool` for a new listener and start dispatching its notifications.
+    pub async fn connect_with(pool: &Pool<Postgres>) -> Result<Self, Error> {
+        Ok(Self::new(PgListener::connect_with(pool).await?))
+    }
+
+    /// Start dispatching the notifications of an existing listener.
+    ///
+    /// Notifications for channels the listener is already subscribed to, but that have
+    /// no [`PgSubscription`], are discarded.
+    pub fn new(listener: PgListener) -> Self {
+        let (commands, rx) = mpsc::unbounded();
+
+        crate::rt::spawn(dispatch(listener, rx));
+
+        Self { commands }
+    }
+
+    /// Subscribe to notifications raised on `channel`.
+    ///
+    /// The first subscription to a channel issues `LISTEN` on the shared connection
+    /// and returns once it has completed.
+    pub async fn subscribe(&self, channel: &str) -> Result<PgSubscription, Error> {
+        // the channel has room for one more message per sender, of which there is one
+        let (sender, receiver) = mpsc::channel(Self::SUBSCRIPTION_CAPACITY - 1);
+        let (reply, reply_rx) = oneshot::channel();
+
+        self.commands
+            .unbounded_send(HubCommand {
+                channel: channel.to_owned(),
+                sender,
+                reply,
+            })
+            .map_err(|_| Error::WorkerCrashed)?;
+
+        reply_rx.await.map_err(|_| Error::WorkerCrashed)??;
+
+        Ok(PgSubscription {
+            channel: channel.to_owned(),
+            receiver,
+        })
+    }
+}
+
+impl PgSubscription {
+    /// The channel this subscription receives notifications for.
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+
+    /// Receives the next notification on this channel.
+    ///
+    /// Returns `None` once the hub has stopped dispatching.
+    pub async fn recv(&mut self) -> Option<PgNotification> {
+        self.receiver.next().await
+    }
+}
+
+impl Stream for PgSubscription {
+    type Item = PgNotification;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+async fn dispatch(mut listener: PgListener, mut commands: mpsc::UnboundedReceiver<HubCommand>) {
+    let mut subscribers: HashMap<String, Vec<mpsc::Sender<PgNotification>>> = HashMap::new();
+
+    loop {
+        // `PgListener::recv()` is cancel-safe, so it can be raced against new subscriptions.
+        let next = {
+            let recv = listener.recv();
+            futures_util::pin_mut!(recv);
+
+            match future::select(commands.next(), recv).await {
+                future::Either::Left((command, _)) => Either::Left(command),
+                future::Either::Right((notification, _)) => Either::Right(notification),
+            }
+        };
+
+        match next {
+            // every handle to the hub has been dropped
+            Either::Left(None) => break,
+
+            Either::Left(Some(command)) => {
+                if !subscribers.contains_key(&command.channel) {
+                    if let Err(error) = listener.listen(&command.channel).await {
+                        let _ = command.reply.send(Err(error));
+                        continue;
+                    }
+                }
+
+                subscribers
+                    .entry(command.channel)
+                    .or_default()
+                    .push(command.sender);
+
+                let _ = command.reply.send(Ok(()));
+            }
+
+            Either::Right(Ok(notification)) => {
+                let Some(senders) = subscribers.get_mut(notification.channel()) else {
+                    continue;
+                };
+
+                senders.retain_mut(|sender| match sender.try_send(notification.clone()) {
+                    Ok(()) => true,
+
+                    Err(error) if error.is_full() => {
+                        tracing::warn!(
+                            channel = notification.channel(),
+                            "PgSubscription is full, discarding a notification"
+                        );
+                        true
+                    }
+
+                    // the subscription has been dropped
+                    Err(_) => false,
+                });
+
+                if senders.is_empty() {
+                    subscribers.remove(notification.channel());
+
+                    // a failed `UNLISTEN` only means we keep discarding this channel
+                    let _ = listener.unlisten(notification.channel()).await;
+                }
+            }
+
+            Either::Right(Err(error)) => {
+                tracing::warn!(%error, "PgListenerHub stopped dispatching notifications");
+                break;
+            }
+        }
+    }
 }
 
 impl Debug for PgListener {
@@ -483,34 +790,27 @@ impl Debug for PgNotification {
     }
 }
 
-fn ident(mut name: &str) -> String {
-    // If the input string contains a NUL byte, we should truncate the
-    // identifier.
-    if let Some(index) = name.find('\0') {
-        name = &name[..index];
-    }
-
-    // Any double quotes must be escaped
-    name.replace('"', "\"\"")
-}
-
-fn build_listen_all_query(channels: impl IntoIterator<Item = impl AsRef<str>>) -> String {
-    channels.into_iter().fold(String::new(), |mut acc, chan| {
-        acc.push_str(r#"LISTEN ""#);
-        acc.push_str(&ident(chan.as_ref()));
-        acc.push_str(r#"";"#);
-        acc
-    })
+fn build_listen_all_query(
+    channels: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<String, Error> {
+    channels
+        .into_iter()
+        .try_fold(String::new(), |mut acc, chan| {
+            acc.push_str(r#"LISTEN ""#);
+            acc.push_str(&ident(chan.as_ref())?);
+            acc.push_str(r#"";"#);
+            Ok(acc)
+        })
 }
 
 #[test]
 fn test_build_listen_all_query_with_single_channel() {
-    let output = build_listen_all_query(&["test"]);
+    let output = build_listen_all_query(&["test"]).unwrap();
     assert_eq!(output.as_str(), r#"LISTEN "test";"#);
 }
 
 #[test]
 fn test_build_listen_all_query_with_multiple_channels() {
-    let output = build_listen_all_query(&["channel.0", "channel.1"]);
+    let output = build_listen_all_query(&["channel.0", "channel.1"]).unwrap();
     assert_eq!(output.as_str(), r#"LISTEN "channel.0";LISTEN "channel.1";"#);
 }
@@ -52,7 +52,7 @@ impl FrontendMessage for Bind<'_> {
 
         // Parameter formats and length prefix
         size += 2;
-        size += self.formats.len();
+        size += 2 * self.formats.len();
 
         // `num_params`
         size += 2;
@@ -61,7 +61,7 @@ impl FrontendMessage for Bind<'_> {
 
         // Result formats and length prefix
         size += 2;
-        size += self.result_formats.len();
+        size += 2 * self.result_formats.len();
 
         size
     }
@@ -89,8 +89,12 @@ impl FrontendMessage for Bind<'_> {
 
         buf.extend(self.params);
 
-        let result_formats_len = u16::try_from(self.formats.len())
-            .map_err(|_| err_protocol!("too many result format codes ({})", self.formats.len()))?;
+        let result_formats_len = u16::try_from(self.result_formats.len()).map_err(|_| {
+            err_protocol!(
+                "too many result format codes ({})",
+                self.result_formats.len()
+            )
+        })?;
 
         buf.extend(result_formats_len.to_be_bytes());
 
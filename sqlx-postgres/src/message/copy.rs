@@ -8,7 +8,7 @@ use sqlx_core::Error;
 use std::num::Saturating;
 use std::ops::Deref;
 
-/// The same structure is sent for both `CopyInResponse` and `CopyOutResponse`
+/// The same structure is sent for `CopyInResponse`, `CopyOutResponse` and `CopyBothResponse`
 pub struct CopyResponseData {
     pub format: i8,
     pub num_columns: i16,
@@ -20,6 +20,9 @@ pub struct CopyInResponse(pub CopyResponseData);
 #[allow(dead_code)]
 pub struct CopyOutResponse(pub CopyResponseData);
 
+#[allow(dead_code)]
+pub struct CopyBothResponse(pub CopyResponseData);
+
 pub struct CopyData<B>(pub B);
 
 pub struct CopyFail {
@@ -62,6 +65,15 @@ impl BackendMessage for CopyOutResponse {
     }
 }
 
+impl BackendMessage for CopyBothResponse {
+    const FORMAT: BackendMessageFormat = BackendMessageFormat::CopyBothResponse;
+
+    #[inline(always)]
+    fn decode_body(buf: Bytes) -> std::result::Result<Self, Error> {
+        Ok(Self(CopyResponseData::decode(buf)?))
+    }
+}
+
 impl BackendMessage for CopyData<Bytes> {
     const FORMAT: BackendMessageFormat = BackendMessageFormat::CopyData;
 
@@ -7,6 +7,7 @@ use crate::io::PgBufMutExt;
 mod authentication;
 mod backend_key_data;
 mod bind;
+mod cancel_request;
 mod close;
 mod command_complete;
 mod copy;
@@ -33,9 +34,13 @@ mod terminate;
 pub use authentication::{Authentication, AuthenticationSasl};
 pub use backend_key_data::BackendKeyData;
 pub use bind::Bind;
+pub use cancel_request::CancelRequest;
 pub use close::Close;
 pub use command_complete::CommandComplete;
-pub use copy::{CopyData, CopyDone, CopyFail, CopyInResponse, CopyOutResponse, CopyResponseData};
+pub use copy::{
+    CopyBothResponse, CopyData, CopyDone, CopyFail, CopyInResponse, CopyOutResponse,
+    CopyResponseData,
+};
 pub use data_row::DataRow;
 pub use describe::Describe;
 pub use execute::Execute;
@@ -87,6 +92,27 @@ pub enum FrontendMessageFormat {
     Terminate = b'X',
 }
 
+impl FrontendMessageFormat {
+    /// The name of this message format in the Postgres documentation.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FrontendMessageFormat::Bind => "Bind",
+            FrontendMessageFormat::Close => "Close",
+            FrontendMessageFormat::CopyData => "CopyData",
+            FrontendMessageFormat::CopyDone => "CopyDone",
+            FrontendMessageFormat::CopyFail => "CopyFail",
+            FrontendMessageFormat::Describe => "Describe",
+            FrontendMessageFormat::Execute => "Execute",
+            FrontendMessageFormat::Flush => "Flush",
+            FrontendMessageFormat::Parse => "Parse",
+            FrontendMessageFormat::PasswordPolymorphic => "PasswordMessage",
+            FrontendMessageFormat::Query => "Query",
+            FrontendMessageFormat::Sync => "Sync",
+            FrontendMessageFormat::Terminate => "Terminate",
+        }
+    }
+}
+
 #[derive(Debug, PartialOrd, PartialEq)]
 #[repr(u8)]
 pub enum BackendMessageFormat {
@@ -99,6 +125,7 @@ pub enum BackendMessageFormat {
     CopyDone,
     CopyInResponse,
     CopyOutResponse,
+    CopyBothResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -143,6 +170,34 @@ impl ReceivedMessage {
 }
 
 impl BackendMessageFormat {
+    /// The name of this message format in the Postgres documentation.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackendMessageFormat::Authentication => "Authentication",
+            BackendMessageFormat::BackendKeyData => "BackendKeyData",
+            BackendMessageFormat::BindComplete => "BindComplete",
+            BackendMessageFormat::CloseComplete => "CloseComplete",
+            BackendMessageFormat::CommandComplete => "CommandComplete",
+            BackendMessageFormat::CopyData => "CopyData",
+            BackendMessageFormat::CopyDone => "CopyDone",
+            BackendMessageFormat::CopyInResponse => "CopyInResponse",
+            BackendMessageFormat::CopyOutResponse => "CopyOutResponse",
+            BackendMessageFormat::CopyBothResponse => "CopyBothResponse",
+            BackendMessageFormat::DataRow => "DataRow",
+            BackendMessageFormat::EmptyQueryResponse => "EmptyQueryResponse",
+            BackendMessageFormat::ErrorResponse => "ErrorResponse",
+            BackendMessageFormat::NoData => "NoData",
+            BackendMessageFormat::NoticeResponse => "NoticeResponse",
+            BackendMessageFormat::NotificationResponse => "NotificationResponse",
+            BackendMessageFormat::ParameterDescription => "ParameterDescription",
+            BackendMessageFormat::ParameterStatus => "ParameterStatus",
+            BackendMessageFormat::ParseComplete => "ParseComplete",
+            BackendMessageFormat::PortalSuspended => "PortalSuspended",
+            BackendMessageFormat::ReadyForQuery => "ReadyForQuery",
+            BackendMessageFormat::RowDescription => "RowDescription",
+        }
+    }
+
     pub fn try_from_u8(v: u8) -> Result<Self, Error> {
         // https://www.postgresql.org/docs/current/protocol-message-formats.html
 
@@ -155,6 +210,7 @@ impl BackendMessageFormat {
             b'c' => BackendMessageFormat::CopyDone,
             b'G' => BackendMessageFormat::CopyInResponse,
             b'H' => BackendMessageFormat::CopyOutResponse,
+            b'W' => BackendMessageFormat::CopyBothResponse,
             b'D' => BackendMessageFormat::DataRow,
             b'E' => BackendMessageFormat::ErrorResponse,
             b'I' => BackendMessageFormat::EmptyQueryResponse,
@@ -187,7 +243,6 @@ pub(crate) trait FrontendMessage: Sized {
     fn encode_body(&self, buf: &mut Vec<u8>) -> Result<(), Error>;
 
     #[inline(always)]
-    #[cfg_attr(not(test), allow(dead_code))]
     fn encode_msg(self, buf: &mut Vec<u8>) -> Result<(), Error> {
         EncodeMessage(self).encode(buf)
     }
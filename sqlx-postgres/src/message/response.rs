@@ -52,7 +52,7 @@ impl TryFrom<&str> for PgSeverity {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Notice {
     storage: Bytes,
     severity: PgSeverity,
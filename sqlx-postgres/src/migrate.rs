@@ -14,7 +14,7 @@ use crate::executor::Executor;
 use crate::query::query;
 use crate::query_as::query_as;
 use crate::query_scalar::query_scalar;
-use crate::{PgConnectOptions, PgConnection, Postgres};
+use crate::{PgAdvisoryLock, PgAdvisoryLockKey, PgConnectOptions, PgConnection, Postgres};
 
 fn parse_for_maintenance(url: &str) -> Result<(PgConnectOptions, String), Error> {
     let mut options = PgConnectOptions::from_str(url)?;
@@ -169,8 +169,7 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
 
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            let database_name = current_database(self).await?;
-            let lock_id = generate_lock_id(&database_name);
+            let lock = migration_lock(self).await?;
 
             // create an application lock over the database
             // this function will not return until the lock is acquired
@@ -178,11 +177,8 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
             // https://www.postgresql.org/docs/current/functions-admin.html#FUNCTIONS-ADVISORY-LOCKS-TABLE
 
-            // language=SQL
-            let _ = query("SELECT pg_advisory_lock($1)")
-                .bind(lock_id)
-                .execute(self)
-                .await?;
+            // the lock is held until `unlock()` rather than released when the guard is dropped
+            lock.acquire(&mut *self).await?.leak();
 
             Ok(())
         })
@@ -190,14 +186,9 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
 
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            let database_name = current_database(self).await?;
-            let lock_id = generate_lock_id(&database_name);
+            let lock = migration_lock(self).await?;
 
-            // language=SQL
-            let _ = query("SELECT pg_advisory_unlock($1)")
-                .bind(lock_id)
-                .execute(self)
-                .await?;
+            let _ = lock.force_release(self).await?;
 
             Ok(())
         })
@@ -322,6 +313,15 @@ async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateErro
         .await?)
 }
 
+// the session-level advisory lock taken by a migrator for the current database
+async fn migration_lock(conn: &mut PgConnection) -> Result<PgAdvisoryLock, MigrateError> {
+    let database_name = current_database(conn).await?;
+
+    Ok(PgAdvisoryLock::with_key(PgAdvisoryLockKey::BigInt(
+        generate_lock_id(&database_name),
+    )))
+}
+
 // inspired from rails: https://github.com/rails/rails/blob/6e49cc77ab3d16c06e12f93158eaf3e507d4120e/activerecord/lib/active_record/migration.rb#L1308
 fn generate_lock_id(database_name: &str) -> i64 {
     const CRC_IEEE: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
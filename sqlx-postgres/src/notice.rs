@@ -0,0 +1,41 @@
+use crate::message::{Notice, PgSeverity};
+
+/// A notice or warning sent by the server, kept in a connection's
+/// [notice history][crate::PgConnection::recent_notices].
+#[derive(Debug, Clone)]
+pub struct PgNotice(pub(crate) Notice);
+
+// Notice message fields are the same as those of errors:
+// https://www.postgresql.org/docs/current/protocol-error-fields.html
+
+impl PgNotice {
+    #[inline]
+    pub fn severity(&self) -> PgSeverity {
+        self.0.severity()
+    }
+
+    /// The [SQLSTATE](https://www.postgresql.org/docs/current/errcodes-appendix.html) code for
+    /// this notice, such as `01000` for a warning raised with `RAISE WARNING`.
+    #[inline]
+    pub fn code(&self) -> &str {
+        self.0.code()
+    }
+
+    /// The primary human-readable message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        self.0.message()
+    }
+
+    /// An optional secondary message carrying more detail.
+    #[inline]
+    pub fn detail(&self) -> Option<&str> {
+        self.0.get(b'D')
+    }
+
+    /// An optional suggestion what to do about the problem.
+    #[inline]
+    pub fn hint(&self) -> Option<&str> {
+        self.0.get(b'H')
+    }
+}
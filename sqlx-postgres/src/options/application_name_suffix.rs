@@ -0,0 +1,56 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A suffix appended to `application_name` that differs for every connection.
+///
+/// This tells individual connections, such as those of a pool, apart in `pg_stat_activity`.
+///
+/// It is used by the
+/// [`application_name_suffix`](super::PgConnectOptions::application_name_suffix) method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgApplicationNameSuffix {
+    /// A number counting the connections opened by this process, starting at 1.
+    Counter,
+
+    /// A random (version 4) UUID.
+    Uuid,
+}
+
+static COUNTER: AtomicU64 = AtomicU64::new(1);
+
+impl PgApplicationNameSuffix {
+    pub(crate) fn generate(&self) -> String {
+        match self {
+            PgApplicationNameSuffix::Counter => COUNTER.fetch_add(1, Ordering::Relaxed).to_string(),
+
+            PgApplicationNameSuffix::Uuid => {
+                let mut value: u128 = rand::random();
+
+                // version 4, variant 1 (RFC 9562)
+                value = (value & !(0xf << 76)) | (0x4 << 76);
+                value = (value & !(0x3 << 62)) | (0x2 << 62);
+
+                format!(
+                    "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+                    value >> 96,
+                    (value >> 80) & 0xffff,
+                    (value >> 64) & 0xffff,
+                    (value >> 48) & 0xffff,
+                    value & 0xffff_ffff_ffff,
+                )
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generate_application_name_suffix() {
+    let first: u64 = PgApplicationNameSuffix::Counter.generate().parse().unwrap();
+    let second: u64 = PgApplicationNameSuffix::Counter.generate().parse().unwrap();
+    assert!(second > first);
+
+    let uuid = PgApplicationNameSuffix::Uuid.generate();
+    assert_eq!(uuid.len(), 36);
+    assert_eq!(uuid.as_bytes()[14], b'4');
+    assert!(matches!(uuid.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+    assert_ne!(uuid, PgApplicationNameSuffix::Uuid.generate());
+}
@@ -0,0 +1,48 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Whether SCRAM authentication has to be bound to the TLS channel.
+///
+/// Channel binding (`SCRAM-SHA-256-PLUS`) lets the client check that the server it
+/// authenticated with is the one at the other end of the TLS connection. SQLx does not
+/// implement it yet, so every connection authenticates without it.
+///
+/// It is used by the [`channel_binding`](super::PgConnectOptions::channel_binding) method.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PgChannelBinding {
+    /// Never use channel binding.
+    Disable,
+
+    /// Use channel binding if available.
+    ///
+    /// As it is not implemented, this is the same as [`Disable`](Self::Disable).
+    ///
+    /// This is the default if no other value is specified.
+    #[default]
+    Prefer,
+
+    /// Refuse to connect without channel binding.
+    ///
+    /// As it is not implemented, every connection is refused, rather than connecting without
+    /// the protection that was asked for. This includes servers that don't ask for a password
+    /// at all, or ask for it with a method other than SCRAM.
+    Require,
+}
+
+impl FromStr for PgChannelBinding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "disable" => PgChannelBinding::Disable,
+            "prefer" => PgChannelBinding::Prefer,
+            "require" => PgChannelBinding::Require,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {s:?} for `channel_binding`").into(),
+                ));
+            }
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::{PgConnectOptions, PgSslMode};
+use crate::{PgChannelBinding, PgConnectOptions, PgSslMode, PgTargetSessionAttrs};
 use sqlx_core::percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
 use sqlx_core::Url;
 use std::net::IpAddr;
@@ -61,6 +61,14 @@ impl PgConnectOptions {
 
                 "sslkey" | "ssl-key" => options = options.ssl_client_key(&*value),
 
+                "sslpassword" | "ssl-password" => {
+                    options = options.ssl_client_key_password(&value);
+                }
+
+                "ssl_min_protocol_version" | "ssl-min-protocol-version" => {
+                    options = options.min_tls_version(value.parse()?);
+                }
+
                 "statement-cache-capacity" => {
                     options =
                         options.statement_cache_capacity(value.parse().map_err(Error::config)?);
@@ -89,6 +97,23 @@ impl PgConnectOptions {
 
                 "application_name" => options = options.application_name(&value),
 
+                "target_session_attrs" => {
+                    options = options.target_session_attrs(value.parse()?);
+                }
+
+                "channel_binding" => options = options.channel_binding(value.parse()?),
+
+                // physical replication connections (`true`) are not supported
+                "replication" => match &*value {
+                    "database" => options = options.logical_replication(true),
+                    "false" | "off" | "no" | "0" => options = options.logical_replication(false),
+                    _ => {
+                        return Err(Error::Configuration(
+                            format!("unsupported value {value:?} for `replication`").into(),
+                        ));
+                    }
+                },
+
                 "options" => {
                     if let Some(options) = options.options.as_mut() {
                         options.push(' ');
@@ -161,6 +186,32 @@ impl PgConnectOptions {
                 .append_pair("sslkey", &ssl_client_key.to_string());
         }
 
+        if let Some(version) = self.ssl_min_protocol_version {
+            url.query_pairs_mut()
+                .append_pair("ssl_min_protocol_version", &version.to_string());
+        }
+
+        if self.target_session_attrs == PgTargetSessionAttrs::ReadWrite {
+            url.query_pairs_mut()
+                .append_pair("target_session_attrs", "read-write");
+        }
+
+        match self.channel_binding {
+            PgChannelBinding::Disable => {
+                url.query_pairs_mut()
+                    .append_pair("channel_binding", "disable");
+            }
+            PgChannelBinding::Prefer => {}
+            PgChannelBinding::Require => {
+                url.query_pairs_mut()
+                    .append_pair("channel_binding", "require");
+            }
+        }
+
+        if self.logical_replication {
+            url.query_pairs_mut().append_pair("replication", "database");
+        }
+
         url.query_pairs_mut().append_pair(
             "statement-cache-capacity",
             &self.statement_cache_capacity.to_string(),
@@ -174,10 +225,205 @@ impl FromStr for PgConnectOptions {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
+        // `Url` cannot parse a list of hosts, so they are set after the rest of the URL
+        let (s, hosts) = match split_hosts(s)? {
+            Some((s, hosts)) => (s, hosts),
+            None => (s.to_owned(), Vec::new()),
+        };
+
         let url: Url = s.parse().map_err(Error::config)?;
+        let mut options = Self::parse_from_url(&url)?;
+
+        // a host without a port uses the one given with `port=`, or the default
+        options.hosts = hosts
+            .into_iter()
+            .map(|(host, port)| (host, port.unwrap_or(options.port)))
+            .collect();
 
-        Self::parse_from_url(&url)
+        if let Some((host, port)) = options.hosts.first() {
+            options.host.clone_from(host);
+            options.port = *port;
+        }
+
+        Ok(options)
+    }
+}
+
+// Splits a comma-separated list of hosts out of a URL, as in `postgres://h1:5432,h2:5433/db`,
+// returning the URL with only the first of them, without its port, so `Url` can parse it.
+//
+// Returns `None` if the URL has a single host.
+#[allow(clippy::type_complexity)]
+fn split_hosts(s: &str) -> Result<Option<(String, Vec<(String, Option<u16>)>)>, Error> {
+    let Some((scheme, rest)) = s.split_once("://") else {
+        return Ok(None);
+    };
+
+    let authority_len = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_len);
+
+    // the password may contain an `@` itself
+    let (user_info, host_list) = match authority.rsplit_once('@') {
+        Some((user_info, host_list)) => (Some(user_info), host_list),
+        None => (None, authority),
+    };
+
+    if !host_list.contains(',') {
+        return Ok(None);
     }
+
+    let hosts = host_list
+        .split(',')
+        .map(parse_host_and_port)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let first_host = match hosts[0].0 {
+        host if host.contains(':') => format!("[{host}]"),
+        host => host.to_owned(),
+    };
+
+    let url = match user_info {
+        Some(user_info) => format!("{scheme}://{user_info}@{first_host}{path}"),
+        None => format!("{scheme}://{first_host}{path}"),
+    };
+
+    let hosts = hosts
+        .into_iter()
+        .map(|(host, port)| {
+            let host = percent_decode_str(host)
+                .decode_utf8()
+                .map_err(Error::config)?;
+
+            Ok((host.into_owned(), port))
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(Some((url, hosts)))
+}
+
+// Parses `host`, `host:port`, `[ipv6]` or `[ipv6]:port`
+fn parse_host_and_port(s: &str) -> Result<(&str, Option<u16>), Error> {
+    let invalid = || Error::Configuration(format!("invalid host {s:?} in URL").into());
+
+    let (host, port) = match s.strip_prefix('[') {
+        Some(rest) => {
+            let (host, port) = rest.split_once(']').ok_or_else(invalid)?;
+
+            let port = match port {
+                "" => None,
+                port => Some(port.strip_prefix(':').ok_or_else(invalid)?),
+            };
+
+            (host, port)
+        }
+        None => match s.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (s, None),
+        },
+    };
+
+    if host.is_empty() {
+        return Err(invalid());
+    }
+
+    let port = port
+        .map(|port| port.parse().map_err(|_| invalid()))
+        .transpose()?;
+
+    Ok((host, port))
+}
+
+#[test]
+fn it_parses_target_session_attrs() {
+    let url = "postgres://localhost/?target_session_attrs=read-write";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(opts.target_session_attrs, PgTargetSessionAttrs::ReadWrite);
+    assert!(opts
+        .build_url()
+        .query_pairs()
+        .any(|(k, v)| k == "target_session_attrs" && v == "read-write"));
+
+    let url = "postgres://localhost/?target_session_attrs=standby";
+    assert!(PgConnectOptions::from_str(url).is_err());
+}
+
+#[test]
+fn it_parses_multiple_hosts() {
+    let url = "postgres://user:p@ss@db-1:5433,db-2,[::1]:5434/database?port=5435";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        opts.hosts,
+        [
+            ("db-1".to_owned(), 5433),
+            ("db-2".to_owned(), 5435),
+            ("::1".to_owned(), 5434),
+        ]
+    );
+    assert_eq!("db-1", opts.host);
+    assert_eq!(5433, opts.port);
+    assert_eq!("user", opts.username);
+    assert_eq!(Some("p@ss"), opts.password.as_deref());
+    assert_eq!(Some("database"), opts.database.as_deref());
+
+    let url = "postgres://db-1,db-2";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        opts.hosts,
+        [("db-1".to_owned(), 5432), ("db-2".to_owned(), 5432)]
+    );
+
+    let url = "postgres://db%2D1.example.com,db%2D2.example.com:5433";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        opts.hosts,
+        [
+            ("db-1.example.com".to_owned(), 5432),
+            ("db-2.example.com".to_owned(), 5433),
+        ]
+    );
+    assert_eq!("db-1.example.com", opts.host);
+
+    // setting a single host or port replaces the list
+    let opts = PgConnectOptions::from_str("postgres://db-1,db-2").unwrap();
+    assert!(opts.clone().host("db-3").hosts.is_empty());
+    assert!(opts.port(5433).hosts.is_empty());
+
+    assert!(PgConnectOptions::from_str("postgres://db-1,/database").is_err());
+    assert!(PgConnectOptions::from_str("postgres://db-1,db-2:port/database").is_err());
+}
+
+#[test]
+fn it_parses_channel_binding() {
+    let url = "postgres://localhost/?channel_binding=require";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(opts.channel_binding, PgChannelBinding::Require);
+    assert!(opts
+        .build_url()
+        .query_pairs()
+        .any(|(k, v)| k == "channel_binding" && v == "require"));
+
+    let url = "postgres://localhost/?channel_binding=maybe";
+    assert!(PgConnectOptions::from_str(url).is_err());
+}
+
+#[test]
+fn it_parses_replication() {
+    let url = "postgres://localhost/?replication=database";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert!(opts.logical_replication);
+    assert!(opts
+        .build_url()
+        .query_pairs()
+        .any(|(k, v)| k == "replication" && v == "database"));
+
+    let url = "postgres://localhost/?replication=true";
+    assert!(PgConnectOptions::from_str(url).is_err());
 }
 
 #[test]
@@ -188,6 +434,38 @@ fn it_parses_socket_correctly_from_parameter() {
     assert_eq!(Some("/var/run/postgres/".into()), opts.socket);
 }
 
+#[test]
+fn it_parses_ssl_min_protocol_version_correctly_from_parameter() {
+    use crate::options::TlsVersion;
+
+    let url = "postgres://localhost/?ssl_min_protocol_version=TLSv1.3";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(Some(TlsVersion::Tls13), opts.ssl_min_protocol_version);
+    assert!(opts
+        .build_url()
+        .as_str()
+        .contains("ssl_min_protocol_version=TLSv1.3"));
+
+    let url = "postgres:///?ssl_min_protocol_version=TLSv1.1";
+    assert!(PgConnectOptions::from_str(url).is_err());
+}
+
+#[test]
+fn it_parses_ssl_client_key_password_correctly_from_parameter() {
+    let url = "postgres://localhost/?sslkey=client.key&sslpassword=p%40ss";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        Some("p@ss"),
+        opts.ssl_client_key_password.as_ref().map(|p| p.expose())
+    );
+
+    // the password is never written out
+    assert!(!opts.build_url().as_str().contains("sslpassword"));
+    assert!(!format!("{opts:?}").contains("p@ss"));
+}
+
 #[test]
 fn it_parses_host_correctly_from_parameter() {
     let url = "postgres:///?host=google.database.com";
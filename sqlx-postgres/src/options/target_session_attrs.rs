@@ -0,0 +1,39 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Which kind of server session is acceptable when connecting.
+///
+/// When several hosts are given, each is tried in turn until one of them offers an
+/// acceptable session.
+///
+/// It is used by the [`target_session_attrs`](super::PgConnectOptions::target_session_attrs)
+/// method.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PgTargetSessionAttrs {
+    /// Any successful connection is acceptable.
+    ///
+    /// This is the default if no other value is specified.
+    #[default]
+    Any,
+
+    /// Only a session that accepts read-write transactions by default is acceptable,
+    /// i.e. one on a primary rather than a hot standby.
+    ReadWrite,
+}
+
+impl FromStr for PgTargetSessionAttrs {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "any" => PgTargetSessionAttrs::Any,
+            "read-write" => PgTargetSessionAttrs::ReadWrite,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {s:?} for `target_session_attrs`").into(),
+                ));
+            }
+        })
+    }
+}
@@ -15,6 +15,8 @@ pub struct PgRow {
     pub(crate) data: DataRow,
     pub(crate) format: PgValueFormat,
     pub(crate) metadata: Arc<PgStatementMetadata>,
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) time_zone: Option<Arc<str>>,
 }
 
 impl Row for PgRow {
@@ -37,6 +39,8 @@ impl Row for PgRow {
             row: Some(&self.data.storage),
             type_info: column.type_info.clone(),
             value,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: self.time_zone.as_ref(),
         })
     }
 }
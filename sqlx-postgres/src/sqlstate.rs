@@ -0,0 +1,123 @@
+//! Well-known SQLSTATE codes returned by Postgres.
+//!
+//! Compare these to [`PgDatabaseError::code()`][crate::PgDatabaseError::code] or
+//! [`PgErrorExt::sqlstate()`][crate::PgErrorExt::sqlstate].
+//!
+//! For reference: <https://www.postgresql.org/docs/current/errcodes-appendix.html>
+
+// Class 08 — Connection Exception
+
+/// `connection_exception`
+pub const CONNECTION_EXCEPTION: &str = "08000";
+/// `connection_failure`
+pub const CONNECTION_FAILURE: &str = "08006";
+
+// Class 0A — Feature Not Supported
+
+/// `feature_not_supported`
+pub const FEATURE_NOT_SUPPORTED: &str = "0A000";
+
+// Class 22 — Data Exception
+
+/// `data_exception`
+pub const DATA_EXCEPTION: &str = "22000";
+/// `string_data_right_truncation`, e.g. a value too long for `VARCHAR(N)`.
+pub const STRING_DATA_RIGHT_TRUNCATION: &str = "22001";
+/// `numeric_value_out_of_range`
+pub const NUMERIC_VALUE_OUT_OF_RANGE: &str = "22003";
+/// `invalid_datetime_format`
+pub const INVALID_DATETIME_FORMAT: &str = "22007";
+/// `division_by_zero`
+pub const DIVISION_BY_ZERO: &str = "22012";
+/// `invalid_text_representation`, e.g. `'abc'::int4`.
+pub const INVALID_TEXT_REPRESENTATION: &str = "22P02";
+
+// Class 23 — Integrity Constraint Violation
+
+/// `integrity_constraint_violation`
+pub const INTEGRITY_CONSTRAINT_VIOLATION: &str = "23000";
+/// `restrict_violation`
+pub const RESTRICT_VIOLATION: &str = "23001";
+/// `not_null_violation`: a column marked as NOT NULL received a null value.
+pub const NOT_NULL_VIOLATION: &str = "23502";
+/// `foreign_key_violation`
+pub const FOREIGN_KEY_VIOLATION: &str = "23503";
+/// `unique_violation`: a unique or primary key is violated.
+pub const UNIQUE_VIOLATION: &str = "23505";
+/// `check_violation`
+pub const CHECK_VIOLATION: &str = "23514";
+/// `exclusion_violation`
+pub const EXCLUSION_VIOLATION: &str = "23P01";
+
+// Class 25 — Invalid Transaction State
+
+/// `active_sql_transaction`: the command cannot run inside a transaction block.
+pub const ACTIVE_SQL_TRANSACTION: &str = "25001";
+/// `read_only_sql_transaction`
+pub const READ_ONLY_SQL_TRANSACTION: &str = "25006";
+/// `in_failed_sql_transaction`: the transaction is aborted and must be rolled back.
+pub const IN_FAILED_SQL_TRANSACTION: &str = "25P02";
+
+// Class 28 — Invalid Authorization Specification
+
+/// `invalid_authorization_specification`
+pub const INVALID_AUTHORIZATION_SPECIFICATION: &str = "28000";
+/// `invalid_password`
+pub const INVALID_PASSWORD: &str = "28P01";
+
+// Class 40 — Transaction Rollback
+
+/// `transaction_rollback`
+pub const TRANSACTION_ROLLBACK: &str = "40000";
+/// `serialization_failure`: the transaction may succeed if retried.
+pub const SERIALIZATION_FAILURE: &str = "40001";
+/// `deadlock_detected`: the transaction may succeed if retried.
+pub const DEADLOCK_DETECTED: &str = "40P01";
+
+// Class 42 — Syntax Error or Access Rule Violation
+
+/// `insufficient_privilege`
+pub const INSUFFICIENT_PRIVILEGE: &str = "42501";
+/// `syntax_error`
+pub const SYNTAX_ERROR: &str = "42601";
+/// `ambiguous_column`
+pub const AMBIGUOUS_COLUMN: &str = "42702";
+/// `undefined_column`
+pub const UNDEFINED_COLUMN: &str = "42703";
+/// `duplicate_object`
+pub const DUPLICATE_OBJECT: &str = "42710";
+/// `datatype_mismatch`
+pub const DATATYPE_MISMATCH: &str = "42804";
+/// `undefined_function`
+pub const UNDEFINED_FUNCTION: &str = "42883";
+/// `undefined_table`
+pub const UNDEFINED_TABLE: &str = "42P01";
+/// `duplicate_table`
+pub const DUPLICATE_TABLE: &str = "42P07";
+
+// Class 53 — Insufficient Resources
+
+/// `insufficient_resources`
+pub const INSUFFICIENT_RESOURCES: &str = "53000";
+/// `disk_full`
+pub const DISK_FULL: &str = "53100";
+/// `out_of_memory`
+pub const OUT_OF_MEMORY: &str = "53200";
+/// `too_many_connections`
+pub const TOO_MANY_CONNECTIONS: &str = "53300";
+
+// Class 55 — Object Not In Prerequisite State
+
+/// `object_in_use`
+pub const OBJECT_IN_USE: &str = "55006";
+/// `lock_not_available`, e.g. from `SELECT ... FOR UPDATE NOWAIT`.
+pub const LOCK_NOT_AVAILABLE: &str = "55P03";
+
+// Class 57 — Operator Intervention
+
+/// `query_canceled`, also sent after a `statement_timeout` or a cancel request.
+pub const QUERY_CANCELED: &str = "57014";
+/// `admin_shutdown`
+pub const ADMIN_SHUTDOWN: &str = "57P01";
+/// `cannot_connect_now`: the server is starting up.
+pub const CANNOT_CONNECT_NOW: &str = "57P03";
@@ -38,8 +38,14 @@ impl_type_checking!(
 
         sqlx::postgres::types::PgLSeg,
 
+        sqlx::postgres::types::PgRefCursor,
+
         sqlx::postgres::types::PgBox,
 
+        sqlx::postgres::types::PgPolygon,
+
+        sqlx::postgres::types::PgCircle,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -73,6 +79,18 @@ impl_type_checking!(
         #[cfg(feature = "time")]
         sqlx::postgres::types::PgTimeTz<sqlx::types::time::Time, sqlx::types::time::UtcOffset>,
 
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx::types::jiff::Time,
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx::types::jiff::Date,
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx::types::jiff::DateTime,
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx::types::jiff::Timestamp,
+
         #[cfg(feature = "bigdecimal")]
         sqlx::types::BigDecimal,
 
@@ -134,6 +152,18 @@ impl_type_checking!(
         #[cfg(feature = "time")]
         Vec<sqlx::types::time::OffsetDateTime> | &[sqlx::types::time::OffsetDateTime],
 
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        Vec<sqlx::types::jiff::Time> | &[sqlx::types::jiff::Time],
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        Vec<sqlx::types::jiff::Date> | &[sqlx::types::jiff::Date],
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        Vec<sqlx::types::jiff::DateTime> | &[sqlx::types::jiff::DateTime],
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        Vec<sqlx::types::jiff::Timestamp> | &[sqlx::types::jiff::Timestamp],
+
         #[cfg(feature = "bigdecimal")]
         Vec<sqlx::types::BigDecimal> | &[sqlx::types::BigDecimal],
 
@@ -221,6 +251,36 @@ impl_type_checking!(
         #[cfg(feature = "time")]
         Vec<sqlx::postgres::types::PgRange<sqlx::types::time::OffsetDateTime>> |
             &[sqlx::postgres::types::PgRange<sqlx::types::time::OffsetDateTime>],
+
+        // Multiranges
+
+        sqlx::postgres::types::PgMultiRange<i32>,
+        sqlx::postgres::types::PgMultiRange<i64>,
+
+        #[cfg(feature = "bigdecimal")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::BigDecimal>,
+
+        #[cfg(feature = "rust_decimal")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::Decimal>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDate>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDateTime>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>> |
+            sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::DateTime<_>>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::time::Date>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::time::PrimitiveDateTime>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::time::OffsetDateTime>,
     },
     ParamChecking::Strong,
     feature-types: info => info.__type_feature_gate(),
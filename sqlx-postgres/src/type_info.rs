@@ -144,6 +144,20 @@ pub enum PgType {
     Int8RangeArray,
     Jsonpath,
     JsonpathArray,
+    Refcursor,
+    RefcursorArray,
+    Int4Multirange,
+    Int4MultirangeArray,
+    NumMultirange,
+    NumMultirangeArray,
+    TsMultirange,
+    TsMultirangeArray,
+    TstzMultirange,
+    TstzMultirangeArray,
+    DateMultirange,
+    DateMultirangeArray,
+    Int8Multirange,
+    Int8MultirangeArray,
     Money,
     MoneyArray,
 
@@ -170,6 +184,9 @@ pub struct PgCustomType {
     #[cfg_attr(feature = "offline", serde(skip))]
     pub(crate) oid: Oid,
     pub(crate) name: UStr,
+    // the name qualified with its schema, when looked up from the database
+    #[cfg_attr(feature = "offline", serde(default))]
+    pub(crate) qualified_name: Option<UStr>,
     pub(crate) kind: PgTypeKind,
 }
 
@@ -447,6 +464,20 @@ impl PgType {
             3927 => PgType::Int8RangeArray,
             4072 => PgType::Jsonpath,
             4073 => PgType::JsonpathArray,
+            1790 => PgType::Refcursor,
+            2201 => PgType::RefcursorArray,
+            4451 => PgType::Int4Multirange,
+            4532 => PgType::NumMultirange,
+            4533 => PgType::TsMultirange,
+            4534 => PgType::TstzMultirange,
+            4535 => PgType::DateMultirange,
+            4536 => PgType::Int8Multirange,
+            6150 => PgType::Int4MultirangeArray,
+            6151 => PgType::NumMultirangeArray,
+            6152 => PgType::TsMultirangeArray,
+            6153 => PgType::TstzMultirangeArray,
+            6155 => PgType::DateMultirangeArray,
+            6157 => PgType::Int8MultirangeArray,
 
             _ => {
                 return None;
@@ -555,6 +586,20 @@ impl PgType {
             PgType::Int8RangeArray => Oid(3927),
             PgType::Jsonpath => Oid(4072),
             PgType::JsonpathArray => Oid(4073),
+            PgType::Refcursor => Oid(1790),
+            PgType::RefcursorArray => Oid(2201),
+            PgType::Int4Multirange => Oid(4451),
+            PgType::Int4MultirangeArray => Oid(6150),
+            PgType::NumMultirange => Oid(4532),
+            PgType::NumMultirangeArray => Oid(6151),
+            PgType::TsMultirange => Oid(4533),
+            PgType::TsMultirangeArray => Oid(6152),
+            PgType::TstzMultirange => Oid(4534),
+            PgType::TstzMultirangeArray => Oid(6153),
+            PgType::DateMultirange => Oid(4535),
+            PgType::DateMultirangeArray => Oid(6155),
+            PgType::Int8Multirange => Oid(4536),
+            PgType::Int8MultirangeArray => Oid(6157),
 
             PgType::Custom(ty) => ty.oid,
 
@@ -659,6 +704,20 @@ impl PgType {
             PgType::Int8RangeArray => "INT8RANGE[]",
             PgType::Jsonpath => "JSONPATH",
             PgType::JsonpathArray => "JSONPATH[]",
+            PgType::Refcursor => "REFCURSOR",
+            PgType::RefcursorArray => "REFCURSOR[]",
+            PgType::Int4Multirange => "INT4MULTIRANGE",
+            PgType::Int4MultirangeArray => "INT4MULTIRANGE[]",
+            PgType::NumMultirange => "NUMMULTIRANGE",
+            PgType::NumMultirangeArray => "NUMMULTIRANGE[]",
+            PgType::TsMultirange => "TSMULTIRANGE",
+            PgType::TsMultirangeArray => "TSMULTIRANGE[]",
+            PgType::TstzMultirange => "TSTZMULTIRANGE",
+            PgType::TstzMultirangeArray => "TSTZMULTIRANGE[]",
+            PgType::DateMultirange => "DATEMULTIRANGE",
+            PgType::DateMultirangeArray => "DATEMULTIRANGE[]",
+            PgType::Int8Multirange => "INT8MULTIRANGE",
+            PgType::Int8MultirangeArray => "INT8MULTIRANGE[]",
             PgType::Money => "MONEY",
             PgType::MoneyArray => "MONEY[]",
             PgType::Void => "VOID",
@@ -760,6 +819,20 @@ impl PgType {
             PgType::Int8RangeArray => "_int8range",
             PgType::Jsonpath => "jsonpath",
             PgType::JsonpathArray => "_jsonpath",
+            PgType::Refcursor => "refcursor",
+            PgType::RefcursorArray => "_refcursor",
+            PgType::Int4Multirange => "int4multirange",
+            PgType::Int4MultirangeArray => "_int4multirange",
+            PgType::NumMultirange => "nummultirange",
+            PgType::NumMultirangeArray => "_nummultirange",
+            PgType::TsMultirange => "tsmultirange",
+            PgType::TsMultirangeArray => "_tsmultirange",
+            PgType::TstzMultirange => "tstzmultirange",
+            PgType::TstzMultirangeArray => "_tstzmultirange",
+            PgType::DateMultirange => "datemultirange",
+            PgType::DateMultirangeArray => "_datemultirange",
+            PgType::Int8Multirange => "int8multirange",
+            PgType::Int8MultirangeArray => "_int8multirange",
             PgType::Money => "money",
             PgType::MoneyArray => "_money",
             PgType::Void => "void",
@@ -861,6 +934,20 @@ impl PgType {
             PgType::Int8RangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Range)),
             PgType::Jsonpath => &PgTypeKind::Simple,
             PgType::JsonpathArray => &PgTypeKind::Array(PgTypeInfo(PgType::Jsonpath)),
+            PgType::Refcursor => &PgTypeKind::Simple,
+            PgType::RefcursorArray => &PgTypeKind::Array(PgTypeInfo(PgType::Refcursor)),
+            PgType::Int4Multirange => &PgTypeKind::Simple,
+            PgType::Int4MultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int4Multirange)),
+            PgType::NumMultirange => &PgTypeKind::Simple,
+            PgType::NumMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::NumMultirange)),
+            PgType::TsMultirange => &PgTypeKind::Simple,
+            PgType::TsMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::TsMultirange)),
+            PgType::TstzMultirange => &PgTypeKind::Simple,
+            PgType::TstzMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::TstzMultirange)),
+            PgType::DateMultirange => &PgTypeKind::Simple,
+            PgType::DateMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::DateMultirange)),
+            PgType::Int8Multirange => &PgTypeKind::Simple,
+            PgType::Int8MultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Multirange)),
             PgType::Money => &PgTypeKind::Simple,
             PgType::MoneyArray => &PgTypeKind::Array(PgTypeInfo(PgType::Money)),
 
@@ -883,6 +970,19 @@ impl PgType {
         }
     }
 
+    /// If `self` is a builtin multirange type, return the type info for its ranges.
+    pub(crate) fn try_multirange_element(&self) -> Option<PgTypeInfo> {
+        Some(PgTypeInfo(match self {
+            PgType::Int4Multirange => PgType::Int4Range,
+            PgType::NumMultirange => PgType::NumRange,
+            PgType::TsMultirange => PgType::TsRange,
+            PgType::TstzMultirange => PgType::TstzRange,
+            PgType::DateMultirange => PgType::DateRange,
+            PgType::Int8Multirange => PgType::Int8Range,
+            _ => return None,
+        }))
+    }
+
     /// If `self` is an array type, return the type info for its element.
     pub(crate) fn try_array_element(&self) -> Option<Cow<'_, PgTypeInfo>> {
         // We explicitly match on all the `None` cases to ensure an exhaustive match.
@@ -977,6 +1077,20 @@ impl PgType {
             PgType::Int8RangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int8Range))),
             PgType::Jsonpath => None,
             PgType::JsonpathArray => Some(Cow::Owned(PgTypeInfo(PgType::Jsonpath))),
+            PgType::Refcursor => None,
+            PgType::RefcursorArray => Some(Cow::Owned(PgTypeInfo(PgType::Refcursor))),
+            PgType::Int4Multirange => None,
+            PgType::Int4MultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int4Multirange))),
+            PgType::NumMultirange => None,
+            PgType::NumMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::NumMultirange))),
+            PgType::TsMultirange => None,
+            PgType::TsMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::TsMultirange))),
+            PgType::TstzMultirange => None,
+            PgType::TstzMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::TstzMultirange))),
+            PgType::DateMultirange => None,
+            PgType::DateMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::DateMultirange))),
+            PgType::Int8Multirange => None,
+            PgType::Int8MultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int8Multirange))),
             // There is no `UnknownArray`
             PgType::Unknown => None,
             // There is no `VoidArray`
@@ -1094,6 +1208,8 @@ impl PgTypeInfo {
 
     pub(crate) const JSONPATH: Self = Self(PgType::Jsonpath);
     pub(crate) const JSONPATH_ARRAY: Self = Self(PgType::JsonpathArray);
+    pub(crate) const REFCURSOR: Self = Self(PgType::Refcursor);
+    pub(crate) const REFCURSOR_ARRAY: Self = Self(PgType::RefcursorArray);
 
     //
     // network address types
@@ -1136,6 +1252,9 @@ impl PgTypeInfo {
     // unknown type, transmitted as text
     pub(crate) const UNKNOWN: Self = Self(PgType::Unknown);
 
+    // no type at all, leaving Postgres to infer the type of a parameter from the query
+    pub(crate) const UNSPECIFIED: Self = Self(PgType::DeclareWithOid(Oid(0)));
+
     //
     // numeric types
     // https://www.postgresql.org/docs/current/datatype-numeric.html
@@ -1273,6 +1392,29 @@ impl PgTypeInfo {
     pub(crate) const INT8_RANGE: Self = Self(PgType::Int8Range);
     pub(crate) const INT8_RANGE_ARRAY: Self = Self(PgType::Int8RangeArray);
 
+    //
+    // multirange types
+    // https://www.postgresql.org/docs/current/rangetypes.html#RANGETYPES-BUILTIN
+    //
+
+    pub(crate) const INT4_MULTIRANGE: Self = Self(PgType::Int4Multirange);
+    pub(crate) const INT4_MULTIRANGE_ARRAY: Self = Self(PgType::Int4MultirangeArray);
+
+    pub(crate) const NUM_MULTIRANGE: Self = Self(PgType::NumMultirange);
+    pub(crate) const NUM_MULTIRANGE_ARRAY: Self = Self(PgType::NumMultirangeArray);
+
+    pub(crate) const TS_MULTIRANGE: Self = Self(PgType::TsMultirange);
+    pub(crate) const TS_MULTIRANGE_ARRAY: Self = Self(PgType::TsMultirangeArray);
+
+    pub(crate) const TSTZ_MULTIRANGE: Self = Self(PgType::TstzMultirange);
+    pub(crate) const TSTZ_MULTIRANGE_ARRAY: Self = Self(PgType::TstzMultirangeArray);
+
+    pub(crate) const DATE_MULTIRANGE: Self = Self(PgType::DateMultirange);
+    pub(crate) const DATE_MULTIRANGE_ARRAY: Self = Self(PgType::DateMultirangeArray);
+
+    pub(crate) const INT8_MULTIRANGE: Self = Self(PgType::Int8Multirange);
+    pub(crate) const INT8_MULTIRANGE_ARRAY: Self = Self(PgType::Int8MultirangeArray);
+
     //
     // pseudo types
     // https://www.postgresql.org/docs/9.3/datatype-pseudo.html
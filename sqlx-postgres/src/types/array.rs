@@ -210,147 +210,184 @@ where
     T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
 {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let format = value.format();
+        let mut elements = Vec::new();
+        decode_elements(value, &mut elements)?;
+        Ok(elements)
+    }
+}
 
-        match format {
-            PgValueFormat::Binary => {
-                // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L1548
+/// A collection that the elements of an array are decoded into, one at a time.
+pub(crate) trait ArrayElements<T> {
+    /// Called with the number of elements, when it is known up front.
+    fn reserve_elements(&mut self, len: usize) -> Result<(), BoxDynError>;
 
-                let mut buf = value.as_bytes()?;
+    fn push_element(&mut self, element: T) -> Result<(), BoxDynError>;
+}
 
-                // number of dimensions in the array
-                let ndim = buf.get_i32();
+impl<T> ArrayElements<T> for Vec<T> {
+    fn reserve_elements(&mut self, len: usize) -> Result<(), BoxDynError> {
+        self.reserve(len);
+        Ok(())
+    }
 
-                if ndim == 0 {
-                    // zero dimensions is an empty array
-                    return Ok(Vec::new());
-                }
+    fn push_element(&mut self, element: T) -> Result<(), BoxDynError> {
+        self.push(element);
+        Ok(())
+    }
+}
 
-                if ndim != 1 {
-                    return Err(format!("encountered an array of {ndim} dimensions; only one-dimensional arrays are supported").into());
-                }
+/// Decode the elements of a one-dimensional array into `elements`.
+pub(crate) fn decode_elements<T, A>(
+    value: PgValueRef<'_>,
+    elements: &mut A,
+) -> Result<(), BoxDynError>
+where
+    T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
+    A: ArrayElements<T>,
+{
+    let format = value.format();
 
-                // appears to have been used in the past to communicate potential NULLS
-                // but reading source code back through our supported postgres versions (9.5+)
-                // this is never used for anything
-                let _flags = buf.get_i32();
-
-                // the OID of the element
-                let element_type_oid = Oid(buf.get_u32());
-                let element_type_info: PgTypeInfo = PgTypeInfo::try_from_oid(element_type_oid)
-                    .or_else(|| value.type_info.try_array_element().map(Cow::into_owned))
-                    .ok_or_else(|| {
-                        BoxDynError::from(format!(
-                            "failed to resolve array element type for oid {}",
-                            element_type_oid.0
-                        ))
-                    })?;
-
-                // length of the array axis
-                let len = buf.get_i32();
-
-                let len = usize::try_from(len)
-                    .map_err(|_| format!("overflow converting array len ({len}) to usize"))?;
-
-                // the lower bound, we only support arrays starting from "1"
-                let lower = buf.get_i32();
-
-                if lower != 1 {
-                    return Err(format!("encountered an array with a lower bound of {lower} in the first dimension; only arrays starting at one are supported").into());
-                }
+    match format {
+        PgValueFormat::Binary => {
+            // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L1548
 
-                let mut elements = Vec::with_capacity(len);
+            let mut buf = value.as_bytes()?;
 
-                for _ in 0..len {
-                    let value_ref = PgValueRef::get(&mut buf, format, element_type_info.clone())?;
+            // number of dimensions in the array
+            let ndim = buf.get_i32();
 
-                    elements.push(T::decode(value_ref)?);
-                }
+            if ndim == 0 {
+                // zero dimensions is an empty array
+                return Ok(());
+            }
 
-                Ok(elements)
+            if ndim != 1 {
+                return Err(format!("encountered an array of {ndim} dimensions; only one-dimensional arrays are supported").into());
             }
 
-            PgValueFormat::Text => {
-                // no type is provided from the database for the element
-                let element_type_info = T::type_info();
+            // appears to have been used in the past to communicate potential NULLS
+            // but reading source code back through our supported postgres versions (9.5+)
+            // this is never used for anything
+            let _flags = buf.get_i32();
+
+            // the OID of the element
+            let element_type_oid = Oid(buf.get_u32());
+            let element_type_info: PgTypeInfo = PgTypeInfo::try_from_oid(element_type_oid)
+                .or_else(|| value.type_info.try_array_element().map(Cow::into_owned))
+                .ok_or_else(|| {
+                    BoxDynError::from(format!(
+                        "failed to resolve array element type for oid {}",
+                        element_type_oid.0
+                    ))
+                })?;
+
+            // length of the array axis
+            let len = buf.get_i32();
+
+            let len = usize::try_from(len)
+                .map_err(|_| format!("overflow converting array len ({len}) to usize"))?;
+
+            // the lower bound, we only support arrays starting from "1"
+            let lower = buf.get_i32();
+
+            if lower != 1 {
+                return Err(format!("encountered an array with a lower bound of {lower} in the first dimension; only arrays starting at one are supported").into());
+            }
 
-                let s = value.as_str()?;
+            elements.reserve_elements(len)?;
 
-                // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L718
+            for _ in 0..len {
+                let value_ref = value.get_nested(&mut buf, element_type_info.clone())?;
 
-                // trim the wrapping braces
-                let s = &s[1..(s.len() - 1)];
+                elements.push_element(T::decode(value_ref)?)?;
+            }
 
-                if s.is_empty() {
-                    // short-circuit empty arrays up here
-                    return Ok(Vec::new());
-                }
+            Ok(())
+        }
 
-                // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
-                //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
-
-                // TODO: When we add support for BOX we need to figure out some way to make the
-                //       delimiter selection
-
-                let delimiter = ',';
-                let mut done = false;
-                let mut in_quotes = false;
-                let mut in_escape = false;
-                let mut value = String::with_capacity(10);
-                let mut chars = s.chars();
-                let mut elements = Vec::with_capacity(4);
-
-                while !done {
-                    loop {
-                        match chars.next() {
-                            Some(ch) => match ch {
-                                _ if in_escape => {
-                                    value.push(ch);
-                                    in_escape = false;
-                                }
-
-                                '"' => {
-                                    in_quotes = !in_quotes;
-                                }
-
-                                '\\' => {
-                                    in_escape = true;
-                                }
-
-                                _ if ch == delimiter && !in_quotes => {
-                                    break;
-                                }
-
-                                _ => {
-                                    value.push(ch);
-                                }
-                            },
-
-                            None => {
-                                done = true;
-                                break;
+        PgValueFormat::Text => {
+            // no type is provided from the database for the element
+            let element_type_info = T::type_info();
+            #[cfg(feature = "chrono-tz")]
+            let time_zone = value.time_zone;
+
+            let s = value.as_str()?;
+
+            // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L718
+
+            // trim the wrapping braces
+            let s = &s[1..(s.len() - 1)];
+
+            if s.is_empty() {
+                // short-circuit empty arrays up here
+                return Ok(());
+            }
+
+            // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
+            //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
+
+            // TODO: When we add support for BOX we need to figure out some way to make the
+            //       delimiter selection
+
+            let delimiter = ',';
+            let mut done = false;
+            let mut in_quotes = false;
+            let mut in_escape = false;
+            let mut value = String::with_capacity(10);
+            let mut chars = s.chars();
+
+            while !done {
+                loop {
+                    match chars.next() {
+                        Some(ch) => match ch {
+                            _ if in_escape => {
+                                value.push(ch);
+                                in_escape = false;
+                            }
+
+                            '"' => {
+                                in_quotes = !in_quotes;
                             }
-                        }
-                    }
 
-                    let value_opt = if value == "NULL" {
-                        None
-                    } else {
-                        Some(value.as_bytes())
-                    };
+                            '\\' => {
+                                in_escape = true;
+                            }
 
-                    elements.push(T::decode(PgValueRef {
-                        value: value_opt,
-                        row: None,
-                        type_info: element_type_info.clone(),
-                        format,
-                    })?);
+                            _ if ch == delimiter && !in_quotes => {
+                                break;
+                            }
 
-                    value.clear();
+                            _ => {
+                                value.push(ch);
+                            }
+                        },
+
+                        None => {
+                            done = true;
+                            break;
+                        }
+                    }
                 }
 
-                Ok(elements)
+                let value_opt = if value == "NULL" {
+                    None
+                } else {
+                    Some(value.as_bytes())
+                };
+
+                elements.push_element(T::decode(PgValueRef {
+                    value: value_opt,
+                    row: None,
+                    type_info: element_type_info.clone(),
+                    format,
+                    #[cfg(feature = "chrono-tz")]
+                    time_zone,
+                })?)?;
+
+                value.clear();
             }
+
+            Ok(())
         }
     }
 }
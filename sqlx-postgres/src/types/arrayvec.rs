@@ -0,0 +1,61 @@
+use arrayvec::ArrayVec;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::array::{decode_elements, ArrayElements};
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+
+// `ArrayVec` is encoded and decoded like a `Vec`, but decoding fails if the array
+// has more elements than fit
+
+impl<T, const CAP: usize> Type<Postgres> for ArrayVec<T, CAP>
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+impl<'q, T, const CAP: usize> Encode<'q, Postgres> for ArrayVec<T, CAP>
+where
+    for<'a> &'a [T]: Encode<'q, Postgres>,
+    T: Encode<'q, Postgres>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.as_slice().encode_by_ref(buf)
+    }
+}
+
+impl<'r, T, const CAP: usize> Decode<'r, Postgres> for ArrayVec<T, CAP>
+where
+    T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let mut elements = ArrayVec::new();
+        decode_elements(value, &mut elements)?;
+        Ok(elements)
+    }
+}
+
+impl<T, const CAP: usize> ArrayElements<T> for ArrayVec<T, CAP> {
+    fn reserve_elements(&mut self, len: usize) -> Result<(), BoxDynError> {
+        if len > self.remaining_capacity() {
+            return Err(format!("array of {len} elements exceeds the capacity of {CAP}").into());
+        }
+
+        Ok(())
+    }
+
+    fn push_element(&mut self, element: T) -> Result<(), BoxDynError> {
+        self.try_push(element)
+            .map_err(|_| format!("array has more elements than the capacity of {CAP}").into())
+    }
+}
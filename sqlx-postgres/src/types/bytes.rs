@@ -8,6 +8,11 @@ impl PgHasArrayType for u8 {
     fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::BYTEA
     }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        // bit strings decode to their packed bytes
+        *ty == PgTypeInfo::BYTEA || *ty == PgTypeInfo::BIT || *ty == PgTypeInfo::VARBIT
+    }
 }
 
 impl PgHasArrayType for &'_ [u8] {
@@ -63,6 +68,7 @@ impl<const N: usize> Encode<'_, Postgres> for [u8; N] {
 impl<'r> Decode<'r, Postgres> for &'r [u8] {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         match value.format() {
+            PgValueFormat::Binary if is_bit_string(&value) => bit_string_binary_input(value),
             PgValueFormat::Binary => value.as_bytes(),
             PgValueFormat::Text => {
                 Err("unsupported decode to `&[u8]` of BYTEA in a simple query; use a prepared query or decode to `Vec<u8>`".into())
@@ -80,8 +86,59 @@ fn text_hex_decode_input(value: PgValueRef<'_>) -> Result<&[u8], BoxDynError> {
         .map_err(Into::into)
 }
 
+fn is_bit_string(value: &PgValueRef<'_>) -> bool {
+    value.type_info == PgTypeInfo::BIT || value.type_info == PgTypeInfo::VARBIT
+}
+
+// BIT and VARBIT are sent as the number of bits followed by the packed bits,
+// most significant bit first, with the final byte padded with zeroes
+fn bit_string_binary_input(value: PgValueRef<'_>) -> Result<&[u8], BoxDynError> {
+    let (len, bytes) = value
+        .as_bytes()?
+        .split_first_chunk::<4>()
+        .ok_or("VARBIT value is missing its length")?;
+
+    let len = i32::from_be_bytes(*len);
+    let len = usize::try_from(len).map_err(|_| format!("invalid VARBIT len: {len}"))?;
+
+    if bytes.len() != len.div_ceil(8) {
+        return Err("VARBIT length mismatch".into());
+    }
+
+    Ok(bytes)
+}
+
+fn bit_string_decode(value: PgValueRef<'_>) -> Result<Vec<u8>, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => Ok(bit_string_binary_input(value)?.to_owned()),
+        PgValueFormat::Text => {
+            // in text, each bit is a `0` or `1` character
+            let digits = value.as_bytes()?;
+            let mut bytes = vec![0; digits.len().div_ceil(8)];
+
+            for (i, digit) in digits.iter().enumerate() {
+                match digit {
+                    b'0' => {}
+                    b'1' => bytes[i / 8] |= 0x80 >> (i % 8),
+                    _ => {
+                        return Err(
+                            format!("invalid digit in VARBIT: {:?}", char::from(*digit)).into()
+                        )
+                    }
+                }
+            }
+
+            Ok(bytes)
+        }
+    }
+}
+
 impl Decode<'_, Postgres> for Box<[u8]> {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if is_bit_string(&value) {
+            return Ok(bit_string_decode(value)?.into_boxed_slice());
+        }
+
         Ok(match value.format() {
             PgValueFormat::Binary => Box::from(value.as_bytes()?),
             PgValueFormat::Text => Box::from(hex::decode(text_hex_decode_input(value)?)?),
@@ -91,6 +148,10 @@ impl Decode<'_, Postgres> for Box<[u8]> {
 
 impl Decode<'_, Postgres> for Vec<u8> {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if is_bit_string(&value) {
+            return bit_string_decode(value);
+        }
+
         Ok(match value.format() {
             PgValueFormat::Binary => value.as_bytes()?.to_owned(),
             PgValueFormat::Text => hex::decode(text_hex_decode_input(value)?)?,
@@ -100,6 +161,10 @@ impl Decode<'_, Postgres> for Vec<u8> {
 
 impl<const N: usize> Decode<'_, Postgres> for [u8; N] {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if is_bit_string(&value) {
+            return Ok(bit_string_decode(value)?.as_slice().try_into()?);
+        }
+
         let mut bytes = [0u8; N];
         match value.format() {
             PgValueFormat::Binary => {
@@ -124,6 +124,23 @@ impl<'r> Decode<'r, Postgres> for DateTime<FixedOffset> {
     }
 }
 
+/// Decodes `TIMESTAMPTZ` into the session `TimeZone` of the connection the value came from.
+#[cfg(feature = "chrono-tz")]
+impl<'r> Decode<'r, Postgres> for DateTime<chrono_tz::Tz> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let name = value
+            .time_zone()
+            .ok_or("the session TimeZone is not known for this value")?;
+
+        let tz: chrono_tz::Tz = name
+            .parse()
+            .map_err(|_| format!("session TimeZone {name:?} is not a named time zone"))?;
+
+        let fixed = <DateTime<FixedOffset> as Decode<Postgres>>::decode(value)?;
+        Ok(tz.from_utc_datetime(&fixed.naive_utc()))
+    }
+}
+
 #[inline]
 fn postgres_epoch_datetime() -> NaiveDateTime {
     NaiveDate::from_ymd_opt(2000, 1, 1)
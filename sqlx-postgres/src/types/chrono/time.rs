@@ -6,6 +6,12 @@ use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValue
 use chrono::{Duration, NaiveTime};
 use std::mem;
 
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+// `24:00:00` is a valid TIME, but `NaiveTime` ends at `23:59:59.999999`
+const END_OF_DAY_ERROR: &str =
+    "Postgres TIME 24:00:00 is out of range for chrono::NaiveTime, which ends at 23:59:59.999999";
+
 impl Type<Postgres> for NaiveTime {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::TIME
@@ -39,10 +45,27 @@ impl<'r> Decode<'r, Postgres> for NaiveTime {
             PgValueFormat::Binary => {
                 // TIME is encoded as the microseconds since midnight
                 let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                if us == MICROSECONDS_PER_DAY {
+                    return Err(END_OF_DAY_ERROR.into());
+                }
+
+                if !(0..MICROSECONDS_PER_DAY).contains(&us) {
+                    return Err(format!("Postgres TIME out of range for chrono: {us}us").into());
+                }
+
                 NaiveTime::default() + Duration::microseconds(us)
             }
 
-            PgValueFormat::Text => NaiveTime::parse_from_str(value.as_str()?, "%H:%M:%S%.f")?,
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                if s.starts_with("24:") {
+                    return Err(END_OF_DAY_ERROR.into());
+                }
+
+                NaiveTime::parse_from_str(s, "%H:%M:%S%.f")?
+            }
         })
     }
 }
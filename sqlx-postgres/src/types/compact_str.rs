@@ -0,0 +1,47 @@
+use compact_str::CompactString;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+
+// Stored as its text, like `str` and `String`; `Vec<CompactString>` and `&[CompactString]`
+// are `TEXT[]` through the generic array impls
+
+impl Type<Postgres> for CompactString {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for CompactString {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for CompactString {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<Postgres>>::encode(self.as_str(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Decode<'_, Postgres> for CompactString {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        // short strings are kept inline rather than allocated
+        Ok(CompactString::new(value.as_str()?))
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+
+/// A date and time stored as only its date, for `DATE`.
+///
+/// This is lossy: encoding drops the time of day, and decoding sets it to midnight. Use it when
+/// values that carry a time should be kept in a `DATE` column anyway, without calling
+/// `.date()` on each of them.
+///
+/// Implemented for `chrono::NaiveDateTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgDateOnly<T>(pub T);
+
+impl Type<Postgres> for PgDateOnly<NaiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE
+    }
+}
+
+impl PgHasArrayType for PgDateOnly<NaiveDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgDateOnly<NaiveDateTime> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <NaiveDate as Encode<Postgres>>::encode(self.0.date(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.date().size_hint()
+    }
+}
+
+impl Decode<'_, Postgres> for PgDateOnly<NaiveDateTime> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let date = <NaiveDate as Decode<Postgres>>::decode(value)?;
+
+        Ok(PgDateOnly(date.and_time(NaiveTime::MIN)))
+    }
+}
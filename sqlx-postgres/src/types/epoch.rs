@@ -0,0 +1,135 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use std::mem;
+
+// Postgres counts microseconds from 2000-01-01 00:00:00 UTC, 946684800 seconds after the Unix epoch
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800 * MICROS_PER_SECOND;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MILLI: i64 = 1_000;
+
+/// A point in time as whole seconds since the Unix epoch (`1970-01-01 00:00:00 UTC`),
+/// for `TIMESTAMPTZ`.
+///
+/// This needs no date and time crate, except to decode a value in text format, as returned by
+/// the simple query protocol, which requires the `chrono` or `time` feature.
+/// Decoding rounds down to the second, towards the past.
+///
+/// Postgres' `infinity` and `-infinity` cannot be decoded into this type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgEpochSeconds(pub i64);
+
+/// A point in time as whole milliseconds since the Unix epoch (`1970-01-01 00:00:00 UTC`),
+/// for `TIMESTAMPTZ`.
+///
+/// See [`PgEpochSeconds`]; this is the same, but rounds down to the millisecond instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgEpochMillis(pub i64);
+
+macro_rules! impl_epoch {
+    ($ty:ident, $micros_per_unit:expr) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::TIMESTAMPTZ
+            }
+        }
+
+        impl PgHasArrayType for $ty {
+            fn array_type_info() -> PgTypeInfo {
+                PgTypeInfo::TIMESTAMPTZ_ARRAY
+            }
+        }
+
+        impl Encode<'_, Postgres> for $ty {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                let micros = self
+                    .0
+                    .checked_mul($micros_per_unit)
+                    .and_then(|micros| micros.checked_sub(POSTGRES_EPOCH_MICROS))
+                    .ok_or_else(|| format!("{self:?} is out of range for TIMESTAMPTZ"))?;
+
+                Encode::<Postgres>::encode(micros, buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                mem::size_of::<i64>()
+            }
+        }
+
+        impl Decode<'_, Postgres> for $ty {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                Ok(Self(
+                    decode_unix_micros(value)?.div_euclid($micros_per_unit),
+                ))
+            }
+        }
+    };
+}
+
+impl_epoch!(PgEpochSeconds, MICROS_PER_SECOND);
+impl_epoch!(PgEpochMillis, MICROS_PER_MILLI);
+
+fn decode_unix_micros(value: PgValueRef<'_>) -> Result<i64, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let micros: i64 = Decode::<Postgres>::decode(value)?;
+
+            // `infinity` and `-infinity`
+            if micros == i64::MAX || micros == i64::MIN {
+                return Err("cannot decode an infinite timestamp as a Unix time".into());
+            }
+
+            micros
+                .checked_add(POSTGRES_EPOCH_MICROS)
+                .ok_or_else(|| "timestamp out of range for a Unix time".into())
+        }
+
+        PgValueFormat::Text => decode_text_unix_micros(value),
+    }
+}
+
+// The text format is parsed with whichever date and time crate is enabled
+
+#[cfg(feature = "chrono")]
+fn decode_text_unix_micros(value: PgValueRef<'_>) -> Result<i64, BoxDynError> {
+    let datetime: chrono::DateTime<chrono::Utc> = Decode::<Postgres>::decode(value)?;
+
+    Ok(datetime.timestamp_micros())
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+fn decode_text_unix_micros(value: PgValueRef<'_>) -> Result<i64, BoxDynError> {
+    let datetime: ::time::OffsetDateTime = Decode::<Postgres>::decode(value)?;
+
+    let micros = datetime.unix_timestamp_nanos().div_euclid(1_000);
+
+    Ok(i64::try_from(micros)?)
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+fn decode_text_unix_micros(_value: PgValueRef<'_>) -> Result<i64, BoxDynError> {
+    Err("decoding a timestamp in text format requires the `chrono` or `time` feature".into())
+}
+
+#[test]
+fn test_decode_rounds_towards_the_past() {
+    let micros = (-1_500_000 - POSTGRES_EPOCH_MICROS).to_be_bytes();
+
+    let value = PgValueRef {
+        value: Some(&micros),
+        row: None,
+        type_info: PgTypeInfo::TIMESTAMPTZ,
+        format: PgValueFormat::Binary,
+        #[cfg(feature = "chrono-tz")]
+        time_zone: None,
+    };
+
+    assert_eq!(
+        PgEpochSeconds::decode(value.clone()).unwrap(),
+        PgEpochSeconds(-2)
+    );
+    assert_eq!(PgEpochMillis::decode(value).unwrap(), PgEpochMillis(-1_500));
+}
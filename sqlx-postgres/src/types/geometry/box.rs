@@ -1,9 +1,10 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::types::Type;
+use crate::types::{PgPoint, Type};
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use sqlx_core::bytes::Buf;
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
 const ERROR: &str = "error decoding BOX";
@@ -104,7 +105,33 @@ impl FromStr for PgBox {
     }
 }
 
+impl Display for PgBox {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(({},{}),({},{}))",
+            self.upper_right_x, self.upper_right_y, self.lower_left_x, self.lower_left_y
+        )
+    }
+}
+
 impl PgBox {
+    /// The area of the box.
+    pub fn area(&self) -> f64 {
+        (self.upper_right_x - self.lower_left_x).abs()
+            * (self.upper_right_y - self.lower_left_y).abs()
+    }
+
+    /// Whether `point` lies inside the box or on its edges, like the `@>` operator.
+    ///
+    /// The corners do not need to be in order.
+    pub fn contains(&self, point: &PgPoint) -> bool {
+        let (min_x, max_x) = min_max(self.upper_right_x, self.lower_left_x);
+        let (min_y, max_y) = min_max(self.upper_right_y, self.lower_left_y);
+
+        (min_x..=max_x).contains(&point.x) && (min_y..=max_y).contains(&point.y)
+    }
+
     fn from_bytes(mut bytes: &[u8]) -> Result<PgBox, BoxDynError> {
         let upper_right_x = bytes.get_f64();
         let upper_right_y = bytes.get_f64();
@@ -141,12 +168,17 @@ impl PgBox {
     }
 }
 
+fn min_max(a: f64, b: f64) -> (f64, f64) {
+    (a.min(b), a.max(b))
+}
+
 #[cfg(test)]
 mod box_tests {
 
     use std::str::FromStr;
 
     use super::PgBox;
+    use crate::types::PgPoint;
 
     const BOX_BYTES: &[u8] = &[
         64, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0,
@@ -318,4 +350,64 @@ mod box_tests {
             }
         )
     }
+
+    #[test]
+    fn can_round_trip_box_type_str() {
+        let pg_box = PgBox {
+            upper_right_x: 2.5,
+            upper_right_y: 4.,
+            lower_left_x: -1.,
+            lower_left_y: 0.25,
+        };
+        assert_eq!(pg_box.to_string(), "((2.5,4),(-1,0.25))");
+        assert_eq!(PgBox::from_str(&pg_box.to_string()).unwrap(), pg_box);
+    }
+
+    #[test]
+    fn can_compute_box_area() {
+        let pg_box = PgBox {
+            upper_right_x: 3.,
+            upper_right_y: 4.,
+            lower_left_x: 1.,
+            lower_left_y: -1.,
+        };
+        assert_eq!(pg_box.area(), 10.);
+
+        // corners out of order
+        let pg_box = PgBox {
+            upper_right_x: 1.,
+            upper_right_y: -1.,
+            lower_left_x: 3.,
+            lower_left_y: 4.,
+        };
+        assert_eq!(pg_box.area(), 10.);
+    }
+
+    #[test]
+    fn box_contains_points_inside_and_on_edges() {
+        let pg_box = PgBox {
+            upper_right_x: 2.,
+            upper_right_y: 2.,
+            lower_left_x: -2.,
+            lower_left_y: -2.,
+        };
+
+        assert!(pg_box.contains(&PgPoint { x: 0., y: 0. }));
+        assert!(pg_box.contains(&PgPoint { x: 2., y: -2. }));
+        assert!(pg_box.contains(&PgPoint { x: -2., y: 1.5 }));
+
+        assert!(!pg_box.contains(&PgPoint { x: 2.1, y: 0. }));
+        assert!(!pg_box.contains(&PgPoint { x: 0., y: -3. }));
+
+        // corners out of order
+        let pg_box = PgBox {
+            upper_right_x: -2.,
+            upper_right_y: -2.,
+            lower_left_x: 2.,
+            lower_left_y: 2.,
+        };
+
+        assert!(pg_box.contains(&PgPoint { x: 1., y: 1. }));
+        assert!(!pg_box.contains(&PgPoint { x: 3., y: 1. }));
+    }
 }
@@ -0,0 +1,264 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::{PgPoint, Type};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use sqlx_core::bytes::Buf;
+use std::f64::consts::PI;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+const ERROR: &str = "error decoding CIRCLE";
+
+/// ## Postgres Geometric Circle type
+///
+/// Description: Circle
+/// Representation: `<(x, y), radius>` (center point and radius)
+///
+/// Circles are represented by a center point and radius. Values of type circle are specified using any of the following syntaxes:
+/// ```text
+/// < ( x , y ) , radius >
+/// ( ( x , y ) , radius )
+///   ( x , y ) , radius
+///     x , y   , radius
+/// ```
+/// where `(x,y)` is the center point and `radius` is the radius of the circle.
+///
+/// See https://www.postgresql.org/docs/16/datatype-geometric.html#DATATYPE-CIRCLE
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgCircle {
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+}
+
+impl Type<Postgres> for PgCircle {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("circle")
+    }
+}
+
+impl PgHasArrayType for PgCircle {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_circle")
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgCircle {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        match value.format() {
+            PgValueFormat::Text => Ok(PgCircle::from_str(value.as_str()?)?),
+            PgValueFormat::Binary => Ok(PgCircle::from_bytes(value.as_bytes()?)?),
+        }
+    }
+}
+
+impl<'q> Encode<'q, Postgres> for PgCircle {
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(PgTypeInfo::with_name("circle"))
+    }
+
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.serialize(buf)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromStr for PgCircle {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sanitised = s.replace(['<', '>', '(', ')', ' '], "");
+        let mut parts = sanitised.split(',');
+
+        let x = parts
+            .next()
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| format!("{}: could not get x from {}", ERROR, s))?;
+
+        let y = parts
+            .next()
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| format!("{}: could not get y from {}", ERROR, s))?;
+
+        let radius = parts
+            .next()
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| format!("{}: could not get radius from {}", ERROR, s))?;
+
+        if parts.next().is_some() {
+            return Err(format!("{}: too many numbers inputted in {}", ERROR, s).into());
+        }
+
+        if radius < 0. {
+            return Err(format!("{}: cannot have negative radius: {}", ERROR, s).into());
+        }
+
+        Ok(PgCircle { x, y, radius })
+    }
+}
+
+impl Display for PgCircle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "<({},{}),{}>", self.x, self.y, self.radius)
+    }
+}
+
+impl PgCircle {
+    /// The area of the circle.
+    pub fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    /// Whether `point` lies inside the circle or on its circumference,
+    /// like the `@>` operator.
+    pub fn contains(&self, point: &PgPoint) -> bool {
+        (point.x - self.x).hypot(point.y - self.y) <= self.radius
+    }
+
+    fn from_bytes(mut bytes: &[u8]) -> Result<PgCircle, BoxDynError> {
+        let x = bytes.get_f64();
+        let y = bytes.get_f64();
+        let radius = bytes.get_f64();
+
+        Ok(PgCircle { x, y, radius })
+    }
+
+    fn serialize(&self, buff: &mut PgArgumentBuffer) -> Result<(), String> {
+        if self.radius < 0. {
+            return Err(format!(
+                "error encoding CIRCLE: cannot have negative radius: {}",
+                self.radius
+            ));
+        }
+
+        buff.extend_from_slice(&self.x.to_be_bytes());
+        buff.extend_from_slice(&self.y.to_be_bytes());
+        buff.extend_from_slice(&self.radius.to_be_bytes());
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    fn serialize_to_vec(&self) -> Vec<u8> {
+        let mut buff = PgArgumentBuffer::default();
+        self.serialize(&mut buff).unwrap();
+        buff.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod circle_tests {
+
+    use std::str::FromStr;
+
+    use super::PgCircle;
+    use crate::types::PgPoint;
+
+    const CIRCLE_BYTES: &[u8] = &[
+        63, 241, 153, 153, 153, 153, 153, 154, 64, 1, 153, 153, 153, 153, 153, 154, 64, 10, 102,
+        102, 102, 102, 102, 102,
+    ];
+
+    #[test]
+    fn can_deserialise_circle_type_bytes() {
+        let circle = PgCircle::from_bytes(CIRCLE_BYTES).unwrap();
+        assert_eq!(
+            circle,
+            PgCircle {
+                x: 1.1,
+                y: 2.2,
+                radius: 3.3
+            }
+        )
+    }
+
+    #[test]
+    fn can_deserialise_circle_type_str() {
+        for input in ["<(1, 2), 3>", "((1, 2), 3)", "(1, 2), 3", "1, 2, 3"] {
+            assert_eq!(
+                PgCircle::from_str(input).unwrap(),
+                PgCircle {
+                    x: 1.,
+                    y: 2.,
+                    radius: 3.
+                },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn cannot_deserialise_negative_radius() {
+        let input_str = "<(1, 2), -3>";
+        let circle = PgCircle::from_str(input_str);
+        assert!(circle.is_err());
+        if let Err(err) = circle {
+            assert_eq!(
+                err.to_string(),
+                format!("error decoding CIRCLE: cannot have negative radius: {input_str}")
+            )
+        }
+    }
+
+    #[test]
+    fn cannot_deserialise_too_many_numbers() {
+        let input_str = "1, 2, 3, 4";
+        let circle = PgCircle::from_str(input_str);
+        assert!(circle.is_err());
+        if let Err(err) = circle {
+            assert_eq!(
+                err.to_string(),
+                format!("error decoding CIRCLE: too many numbers inputted in {input_str}")
+            )
+        }
+    }
+
+    #[test]
+    fn can_serialise_circle_type() {
+        let circle = PgCircle {
+            x: 1.1,
+            y: 2.2,
+            radius: 3.3,
+        };
+        assert_eq!(circle.serialize_to_vec(), CIRCLE_BYTES,)
+    }
+
+    #[test]
+    fn can_round_trip_circle_type_str() {
+        let circle = PgCircle {
+            x: 2.5,
+            y: -1.,
+            radius: 0.25,
+        };
+        assert_eq!(circle.to_string(), "<(2.5,-1),0.25>");
+        assert_eq!(PgCircle::from_str(&circle.to_string()).unwrap(), circle);
+    }
+
+    #[test]
+    fn can_compute_circle_area() {
+        let circle = PgCircle {
+            x: 5.,
+            y: 5.,
+            radius: 2.,
+        };
+        assert!((circle.area() - 4. * std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn circle_contains_points_inside_and_on_circumference() {
+        let circle = PgCircle {
+            x: 1.,
+            y: 1.,
+            radius: 2.,
+        };
+
+        assert!(circle.contains(&PgPoint { x: 1., y: 1. }));
+        assert!(circle.contains(&PgPoint { x: 2., y: 2. }));
+        assert!(circle.contains(&PgPoint { x: 3., y: 1. }));
+        assert!(circle.contains(&PgPoint { x: 1., y: -1. }));
+
+        assert!(!circle.contains(&PgPoint { x: 3., y: 3. }));
+        assert!(!circle.contains(&PgPoint { x: -1.5, y: 1. }));
+    }
+}
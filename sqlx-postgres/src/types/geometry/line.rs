@@ -4,6 +4,7 @@ use crate::error::BoxDynError;
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use sqlx_core::bytes::Buf;
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
 const ERROR: &str = "error decoding LINE";
@@ -86,6 +87,12 @@ impl FromStr for PgLine {
     }
 }
 
+impl Display for PgLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{},{},{}}}", self.a, self.b, self.c)
+    }
+}
+
 impl PgLine {
     fn from_bytes(mut bytes: &[u8]) -> Result<PgLine, BoxDynError> {
         let a = bytes.get_f64();
@@ -208,4 +215,15 @@ mod line_tests {
         };
         assert_eq!(line.serialize_to_vec(), LINE_BYTES,)
     }
+
+    #[test]
+    fn can_round_trip_line_type_str() {
+        let line = PgLine {
+            a: 1.5,
+            b: -2.,
+            c: 0.,
+        };
+        assert_eq!(line.to_string(), "{1.5,-2,0}");
+        assert_eq!(PgLine::from_str(&line.to_string()).unwrap(), line);
+    }
 }
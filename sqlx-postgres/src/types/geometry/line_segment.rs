@@ -4,6 +4,7 @@ use crate::error::BoxDynError;
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use sqlx_core::bytes::Buf;
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
 const ERROR: &str = "error decoding LSEG";
@@ -105,6 +106,16 @@ impl FromStr for PgLSeg {
     }
 }
 
+impl Display for PgLSeg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[({},{}),({},{})]",
+            self.start_x, self.start_y, self.end_x, self.end_y
+        )
+    }
+}
+
 impl PgLSeg {
     fn from_bytes(mut bytes: &[u8]) -> Result<PgLSeg, BoxDynError> {
         let start_x = bytes.get_f64();
@@ -280,4 +291,16 @@ mod lseg_tests {
         };
         assert_eq!(lseg.serialize_to_vec(), LINE_SEGMENT_BYTES,)
     }
+
+    #[test]
+    fn can_round_trip_lseg_type_str() {
+        let lseg = PgLSeg {
+            start_x: 1.,
+            start_y: 2.5,
+            end_x: -3.,
+            end_y: 4.,
+        };
+        assert_eq!(lseg.to_string(), "[(1,2.5),(-3,4)]");
+        assert_eq!(PgLSeg::from_str(&lseg.to_string()).unwrap(), lseg);
+    }
 }
@@ -1,4 +1,6 @@
 pub mod r#box;
+pub mod circle;
 pub mod line;
 pub mod line_segment;
 pub mod point;
+pub mod polygon;
@@ -5,6 +5,7 @@ use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use sqlx_core::bytes::Buf;
 use sqlx_core::Error;
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
 /// ## Postgres Geometric Point type
@@ -74,12 +75,18 @@ impl FromStr for PgPoint {
             .ok_or_else(|| format!("error decoding POINT: could not get x and y from {}", s))?;
 
         let x = parse_float_from_str(x_str, "error decoding POINT: could not get x")?;
-        let y = parse_float_from_str(y_str, "error decoding POINT: could not get x")?;
+        let y = parse_float_from_str(y_str, "error decoding POINT: could not get y")?;
 
         Ok(PgPoint { x, y })
     }
 }
 
+impl Display for PgPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
 impl PgPoint {
     fn from_bytes(mut bytes: &[u8]) -> Result<PgPoint, BoxDynError> {
         let x = bytes.get_f64();
@@ -135,4 +142,11 @@ mod point_tests {
         let point = PgPoint { x: 2.1, y: 5.2 };
         assert_eq!(point.serialize_to_vec(), POINT_BYTES,)
     }
+
+    #[test]
+    fn can_round_trip_point_type_str() {
+        let point = PgPoint { x: 2.5, y: -3. };
+        assert_eq!(point.to_string(), "(2.5,-3)");
+        assert_eq!(PgPoint::from_str(&point.to_string()).unwrap(), point);
+    }
 }
@@ -0,0 +1,336 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::{PgPoint, Type};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use sqlx_core::bytes::Buf;
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+use std::str::FromStr;
+
+const ERROR: &str = "error decoding POLYGON";
+
+/// ## Postgres Geometric Polygon type
+///
+/// Description: Polygon (similar to closed path)
+/// Representation: `((x1,y1),...)`
+///
+/// Polygons are represented by lists of points (the vertexes of the polygon). Values of type polygon are specified using any of the following syntaxes:
+///
+/// ```text
+/// ( ( x1 , y1 ) , ... , ( xn , yn ) )
+///   ( x1 , y1 ) , ... , ( xn , yn )
+///   ( x1 , y1   , ... ,   xn , yn )
+///     x1 , y1   , ... ,   xn , yn
+/// ```
+///
+/// where the points are the end points of the line segments comprising the boundary of the polygon.
+///
+/// See https://www.postgresql.org/docs/16/datatype-geometric.html#DATATYPE-POLYGON
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+impl Type<Postgres> for PgPolygon {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("polygon")
+    }
+}
+
+impl PgHasArrayType for PgPolygon {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_polygon")
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgPolygon {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        match value.format() {
+            PgValueFormat::Text => Ok(PgPolygon::from_str(value.as_str()?)?),
+            PgValueFormat::Binary => Ok(PgPolygon::from_bytes(value.as_bytes()?)?),
+        }
+    }
+}
+
+impl<'q> Encode<'q, Postgres> for PgPolygon {
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(PgTypeInfo::with_name("polygon"))
+    }
+
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.serialize(buf)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromStr for PgPolygon {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sanitised = s.replace(['(', ')', ' '], "");
+
+        let coordinates = sanitised
+            .split(',')
+            .map(|part| part.parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| format!("{}: could not parse coordinates in {}", ERROR, s))?;
+
+        if coordinates.len() % 2 != 0 {
+            return Err(format!("{}: odd number of coordinates in {}", ERROR, s).into());
+        }
+
+        let points = coordinates
+            .chunks_exact(2)
+            .map(|xy| PgPoint { x: xy[0], y: xy[1] })
+            .collect();
+
+        Ok(PgPolygon { points })
+    }
+}
+
+impl Display for PgPolygon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+
+        for (i, point) in self.points.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "{point}")?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl PgPolygon {
+    /// The area enclosed by the polygon, computed with the shoelace formula.
+    ///
+    /// The result is only meaningful for simple (non self-intersecting) polygons.
+    pub fn area(&self) -> f64 {
+        let twice_area: f64 = self
+            .edges()
+            .map(|(start, end)| start.x * end.y - end.x * start.y)
+            .sum();
+
+        twice_area.abs() / 2.
+    }
+
+    /// Whether `point` lies inside the polygon or on its boundary, like the `@>` operator.
+    ///
+    /// Points strictly inside are found by casting a ray from `point` and counting how many
+    /// edges it crosses.
+    pub fn contains(&self, point: &PgPoint) -> bool {
+        let mut inside = false;
+
+        for (start, end) in self.edges() {
+            if on_segment(point, start, end) {
+                return true;
+            }
+
+            // the edge straddles the horizontal line through `point`,
+            // and crosses it to the right of `point`
+            if (start.y > point.y) != (end.y > point.y)
+                && point.x < (end.x - start.x) * (point.y - start.y) / (end.y - start.y) + start.x
+            {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    // every pair of consecutive vertexes, including the one closing the polygon
+    fn edges(&self) -> impl Iterator<Item = (&PgPoint, &PgPoint)> {
+        self.points.iter().zip(self.points.iter().cycle().skip(1))
+    }
+
+    fn from_bytes(mut bytes: &[u8]) -> Result<PgPolygon, BoxDynError> {
+        if bytes.len() < mem::size_of::<i32>() {
+            return Err(format!("{}: missing the number of points", ERROR).into());
+        }
+
+        let npts = bytes.get_i32();
+        let npts = usize::try_from(npts)
+            .map_err(|_| format!("{}: invalid number of points: {}", ERROR, npts))?;
+
+        if bytes.len() != npts * 2 * mem::size_of::<f64>() {
+            return Err(format!(
+                "{}: expected {} points, got {} bytes",
+                ERROR,
+                npts,
+                bytes.len()
+            )
+            .into());
+        }
+
+        let points = (0..npts)
+            .map(|_| PgPoint {
+                x: bytes.get_f64(),
+                y: bytes.get_f64(),
+            })
+            .collect();
+
+        Ok(PgPolygon { points })
+    }
+
+    fn serialize(&self, buff: &mut PgArgumentBuffer) -> Result<(), String> {
+        let npts = i32::try_from(self.points.len()).map_err(|_| {
+            format!(
+                "error encoding POLYGON: too many points: {}",
+                self.points.len()
+            )
+        })?;
+
+        buff.extend_from_slice(&npts.to_be_bytes());
+
+        for point in &self.points {
+            buff.extend_from_slice(&point.x.to_be_bytes());
+            buff.extend_from_slice(&point.y.to_be_bytes());
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    fn serialize_to_vec(&self) -> Vec<u8> {
+        let mut buff = PgArgumentBuffer::default();
+        self.serialize(&mut buff).unwrap();
+        buff.to_vec()
+    }
+}
+
+// whether `point` lies on the segment between `start` and `end`
+fn on_segment(point: &PgPoint, start: &PgPoint, end: &PgPoint) -> bool {
+    let cross = (end.x - start.x) * (point.y - start.y) - (end.y - start.y) * (point.x - start.x);
+
+    cross == 0.
+        && point.x >= start.x.min(end.x)
+        && point.x <= start.x.max(end.x)
+        && point.y >= start.y.min(end.y)
+        && point.y <= start.y.max(end.y)
+}
+
+#[cfg(test)]
+mod polygon_tests {
+
+    use std::str::FromStr;
+
+    use super::PgPolygon;
+    use crate::types::PgPoint;
+
+    const POLYGON_BYTES: &[u8] = &[
+        0, 0, 0, 2, 63, 241, 153, 153, 153, 153, 153, 154, 64, 1, 153, 153, 153, 153, 153, 154, 64,
+        10, 102, 102, 102, 102, 102, 102, 64, 17, 153, 153, 153, 153, 153, 154,
+    ];
+
+    fn polygon(points: &[(f64, f64)]) -> PgPolygon {
+        PgPolygon {
+            points: points.iter().map(|&(x, y)| PgPoint { x, y }).collect(),
+        }
+    }
+
+    #[test]
+    fn can_deserialise_polygon_type_bytes() {
+        let pg_polygon = PgPolygon::from_bytes(POLYGON_BYTES).unwrap();
+        assert_eq!(pg_polygon, polygon(&[(1.1, 2.2), (3.3, 4.4)]))
+    }
+
+    #[test]
+    fn cannot_deserialise_truncated_polygon_bytes() {
+        assert!(PgPolygon::from_bytes(&POLYGON_BYTES[..20]).is_err());
+    }
+
+    #[test]
+    fn can_deserialise_polygon_type_str() {
+        for input in [
+            "((1, 2), (3, 4), (5, 6))",
+            "(1, 2), (3, 4), (5, 6)",
+            "(1, 2, 3, 4, 5, 6)",
+            "1, 2, 3, 4, 5, 6",
+        ] {
+            assert_eq!(
+                PgPolygon::from_str(input).unwrap(),
+                polygon(&[(1., 2.), (3., 4.), (5., 6.)]),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn cannot_deserialise_odd_number_of_coordinates() {
+        let input_str = "1, 2, 3";
+        let pg_polygon = PgPolygon::from_str(input_str);
+        assert!(pg_polygon.is_err());
+        if let Err(err) = pg_polygon {
+            assert_eq!(
+                err.to_string(),
+                format!("error decoding POLYGON: odd number of coordinates in {input_str}")
+            )
+        }
+    }
+
+    #[test]
+    fn can_serialise_polygon_type() {
+        let pg_polygon = polygon(&[(1.1, 2.2), (3.3, 4.4)]);
+        assert_eq!(pg_polygon.serialize_to_vec(), POLYGON_BYTES,)
+    }
+
+    #[test]
+    fn can_round_trip_polygon_type_str() {
+        let pg_polygon = polygon(&[(0., 0.), (2.5, 0.), (1., -1.5)]);
+        assert_eq!(pg_polygon.to_string(), "((0,0),(2.5,0),(1,-1.5))");
+        assert_eq!(
+            PgPolygon::from_str(&pg_polygon.to_string()).unwrap(),
+            pg_polygon
+        );
+    }
+
+    #[test]
+    fn can_compute_polygon_area() {
+        assert_eq!(polygon(&[(0., 0.), (4., 0.), (4., 3.)]).area(), 6.);
+
+        // the orientation of the vertexes does not matter
+        assert_eq!(
+            polygon(&[(0., 0.), (0., 2.), (2., 2.), (2., 0.)]).area(),
+            4.
+        );
+    }
+
+    #[test]
+    fn polygon_contains_points_inside_and_on_boundary() {
+        let triangle = polygon(&[(0., 0.), (4., 0.), (0., 4.)]);
+
+        assert!(triangle.contains(&PgPoint { x: 1., y: 1. }));
+        assert!(triangle.contains(&PgPoint { x: 0., y: 0. }));
+        assert!(triangle.contains(&PgPoint { x: 2., y: 0. }));
+        assert!(triangle.contains(&PgPoint { x: 2., y: 2. }));
+
+        assert!(!triangle.contains(&PgPoint { x: 3., y: 3. }));
+        assert!(!triangle.contains(&PgPoint { x: -1., y: 1. }));
+        assert!(!triangle.contains(&PgPoint { x: 5., y: 0. }));
+    }
+
+    #[test]
+    fn concave_polygon_does_not_contain_its_notch() {
+        // a "U" shape, open at the top between x = 1 and x = 3
+        let u_shape = polygon(&[
+            (0., 0.),
+            (4., 0.),
+            (4., 4.),
+            (3., 4.),
+            (3., 1.),
+            (1., 1.),
+            (1., 4.),
+            (0., 4.),
+        ]);
+
+        assert!(u_shape.contains(&PgPoint { x: 0.5, y: 3. }));
+        assert!(u_shape.contains(&PgPoint { x: 3.5, y: 3. }));
+        assert!(u_shape.contains(&PgPoint { x: 2., y: 0.5 }));
+
+        assert!(!u_shape.contains(&PgPoint { x: 2., y: 3. }));
+    }
+}
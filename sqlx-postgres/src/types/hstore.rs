@@ -13,6 +13,7 @@ use crate::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::{Map as JsonMap, Value as JsonValue};
 use sqlx_core::bytes::Buf;
 
 /// Key-value support (`hstore`) for Postgres.
@@ -91,6 +92,91 @@ use sqlx_core::bytes::Buf;
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 pub struct PgHstore(pub BTreeMap<String, Option<String>>);
 
+/// How [`PgHstore::try_from_json_map()`] handles JSON values that are neither strings nor
+/// `null`, which have no equivalent in `hstore`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PgHstoreJsonPolicy {
+    /// Fail on the first such value.
+    ///
+    /// This is the default.
+    #[default]
+    Reject,
+
+    /// Store the JSON text of the value instead, e.g. `1`, `true` or `[1,2]`.
+    Stringify,
+}
+
+impl PgHstore {
+    /// Convert to a JSON object, with each value as a JSON string and each `NULL` value as
+    /// `null`.
+    ///
+    /// ```
+    /// # use sqlx_postgres::types::PgHstore;
+    /// use serde_json::json;
+    ///
+    /// let hstore = PgHstore::from_iter([
+    ///     ("department".to_string(), Some("IT".to_string())),
+    ///     ("equipment_issued".to_string(), None),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     serde_json::Value::Object(hstore.to_json_map()),
+    ///     json!({ "department": "IT", "equipment_issued": null }),
+    /// );
+    /// ```
+    pub fn to_json_map(&self) -> JsonMap<String, JsonValue> {
+        self.0
+            .iter()
+            .map(|(key, value)| {
+                let value = value.clone().map_or(JsonValue::Null, JsonValue::String);
+
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Convert from a JSON object, with each `null` value as `NULL`.
+    ///
+    /// Values that are neither strings nor `null` are handled according to `policy`.
+    ///
+    /// ```
+    /// # use sqlx_postgres::types::{PgHstore, PgHstoreJsonPolicy};
+    /// use serde_json::json;
+    ///
+    /// let serde_json::Value::Object(map) = json!({ "department": "IT", "floor": 3 }) else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert!(PgHstore::try_from_json_map(map.clone(), PgHstoreJsonPolicy::Reject).is_err());
+    ///
+    /// let hstore = PgHstore::try_from_json_map(map, PgHstoreJsonPolicy::Stringify).unwrap();
+    /// assert_eq!(hstore["floor"], Some("3".to_string()));
+    /// ```
+    pub fn try_from_json_map(
+        map: JsonMap<String, JsonValue>,
+        policy: PgHstoreJsonPolicy,
+    ) -> Result<Self, BoxDynError> {
+        map.into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    JsonValue::Null => None,
+                    JsonValue::String(value) => Some(value),
+                    value => match policy {
+                        PgHstoreJsonPolicy::Reject => {
+                            return Err(BoxDynError::from(format!(
+                                "PgHstore: value for key {key:?} is not a string or null: {value}"
+                            )))
+                        }
+                        PgHstoreJsonPolicy::Stringify => Some(value.to_string()),
+                    },
+                };
+
+                Ok((key, value))
+            })
+            .collect()
+    }
+}
+
 impl Deref for PgHstore {
     type Target = BTreeMap<String, Option<String>>;
 
@@ -183,6 +269,16 @@ impl Encode<'_, Postgres> for PgHstore {
         ));
 
         for (i, (key, val)) in self.0.iter().enumerate() {
+            // keys and values are sent as-is after their length, so nothing needs escaping,
+            // but Postgres cannot store a NUL byte in text of any kind
+            if key.contains('\0') || val.as_deref().is_some_and(|val| val.contains('\0')) {
+                return Err(format!(
+                    "PgHstore: key or value for key {key:?} contains a NUL byte, \
+                     which Postgres does not allow in text"
+                )
+                .into());
+            }
+
             let key_bytes = key.as_bytes();
 
             let key_len = i32::try_from(key_bytes.len()).map_err(|_| {
@@ -272,6 +368,8 @@ mod test {
         let empty = PgValueRef {
             value: Some(empty.as_slice()),
             row: None,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: None,
             type_info: PgTypeInfo::with_name("hstore"),
             format: PgValueFormat::Binary,
         };
@@ -279,6 +377,8 @@ mod test {
         let name_surname = PgValueRef {
             value: Some(name_surname_age.as_slice()),
             row: None,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: None,
             type_info: PgTypeInfo::with_name("hstore"),
             format: PgValueFormat::Binary,
         };
@@ -298,6 +398,8 @@ mod test {
         let buf = PgValueRef {
             value: Some(&[255, 255, 255, 251]),
             row: None,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: None,
             type_info: PgTypeInfo::with_name("hstore"),
             format: PgValueFormat::Binary,
         };
@@ -326,4 +428,74 @@ mod test {
 
         assert_eq!(hex::encode(buff.as_slice()), NAME_SURNAME_AGE);
     }
+
+    #[test]
+    fn hstore_round_trip_unicode_and_separators() {
+        let hstore = PgHstore::from_iter([
+            ("🔑".to_string(), Some("🦀 crab".to_string())),
+            ("a=>b".to_string(), Some("c=>d, \"e\"=>NULL".to_string())),
+            ("clé".to_string(), Some("\\".to_string())),
+            ("".to_string(), Some("".to_string())),
+            ("ключ".to_string(), None),
+        ]);
+
+        let mut buff = PgArgumentBuffer::default();
+        let _ = hstore.encode_by_ref(&mut buff).unwrap();
+
+        let value = PgValueRef {
+            value: Some(buff.as_slice()),
+            row: None,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: None,
+            type_info: PgTypeInfo::with_name("hstore"),
+            format: PgValueFormat::Binary,
+        };
+
+        assert_eq!(PgHstore::decode(value).unwrap(), hstore);
+    }
+
+    #[test]
+    fn hstore_json_map_round_trip() {
+        let hstore = PgHstore::from_iter([
+            ("name".to_string(), Some("John".to_string())),
+            ("age".to_string(), None),
+        ]);
+
+        let map = hstore.to_json_map();
+        assert_eq!(map["name"], JsonValue::String("John".to_string()));
+        assert_eq!(map["age"], JsonValue::Null);
+
+        assert_eq!(
+            PgHstore::try_from_json_map(map, PgHstoreJsonPolicy::Reject).unwrap(),
+            hstore
+        );
+    }
+
+    #[test]
+    fn hstore_json_map_non_string() {
+        let map = JsonMap::from_iter([
+            ("age".to_string(), JsonValue::from(42)),
+            ("tags".to_string(), JsonValue::from(vec!["a", "b"])),
+        ]);
+
+        let error = PgHstore::try_from_json_map(map.clone(), PgHstoreJsonPolicy::Reject)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("\"age\""), "{error}");
+
+        let hstore = PgHstore::try_from_json_map(map, PgHstoreJsonPolicy::Stringify).unwrap();
+        assert_eq!(hstore["age"], Some("42".to_string()));
+        assert_eq!(hstore["tags"], Some("[\"a\",\"b\"]".to_string()));
+    }
+
+    #[test]
+    fn hstore_serialize_nul_error() {
+        let mut buff = PgArgumentBuffer::default();
+
+        let hstore = PgHstore::from_iter([("a\0b".to_string(), None)]);
+        assert!(hstore.encode_by_ref(&mut buff).is_err());
+
+        let hstore = PgHstore::from_iter([("a".to_string(), "b\0".to_string())]);
+        assert!(hstore.encode_by_ref(&mut buff).is_err());
+    }
 }
@@ -4,10 +4,15 @@ use std::num::{NonZeroI16, NonZeroI32, NonZeroI64};
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
+use crate::types::numeric::{PgNumeric, PgNumericSign};
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 
 fn int_decode(value: PgValueRef<'_>) -> Result<i64, BoxDynError> {
+    if value.type_info == PgTypeInfo::NUMERIC {
+        return Ok(numeric_int_decode(value)?.try_into()?);
+    }
+
     Ok(match value.format() {
         PgValueFormat::Text => value.as_str()?.parse()?,
         PgValueFormat::Binary => {
@@ -32,6 +37,38 @@ fn int_decode(value: PgValueRef<'_>) -> Result<i64, BoxDynError> {
     })
 }
 
+// `NUMERIC` is accepted by the wider integer types as long as the value is integral,
+// as that is what Postgres returns for aggregates such as `SUM(bigint)`.
+fn numeric_int_decode(value: PgValueRef<'_>) -> Result<i128, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_to_i128(),
+        PgValueFormat::Text => Ok(numeric_text_integral_part(value.as_str()?)?.parse()?),
+    }
+}
+
+fn numeric_uint_decode(value: PgValueRef<'_>) -> Result<u128, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_to_u128(),
+        PgValueFormat::Text => match numeric_text_integral_part(value.as_str()?)? {
+            "-0" => Ok(0),
+            int => Ok(int.parse()?),
+        },
+    }
+}
+
+fn numeric_text_integral_part(text: &str) -> Result<&str, BoxDynError> {
+    let (int, fract) = text.split_once('.').unwrap_or((text, ""));
+
+    if fract.bytes().any(|b| b != b'0') {
+        return Err(format!(
+            "NUMERIC value has a fractional part and cannot be decoded as an integer: {text}"
+        )
+        .into());
+    }
+
+    Ok(int)
+}
+
 impl Type<Postgres> for i8 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::CHAR
@@ -109,6 +146,10 @@ impl Type<Postgres> for i32 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INT4
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::INT4 || *ty == PgTypeInfo::NUMERIC
+    }
 }
 
 impl PgHasArrayType for i32 {
@@ -135,6 +176,10 @@ impl Type<Postgres> for i64 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INT8
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::INT8 || *ty == PgTypeInfo::NUMERIC
+    }
 }
 
 impl PgHasArrayType for i64 {
@@ -157,6 +202,157 @@ impl Decode<'_, Postgres> for i64 {
     }
 }
 
+// Postgres has no unsigned integer types, so `u16` and `u32` are stored in the signed type
+// of the same width. Only values that fit the signed type can be encoded, and decoding
+// a negative value is an error.
+
+impl Type<Postgres> for u16 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT2
+    }
+}
+
+impl PgHasArrayType for u16 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INT2_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for u16 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let value = i16::try_from(*self)
+            .map_err(|_| format!("value {self} is out of range for SMALLINT"))?;
+
+        buf.extend(&value.to_be_bytes());
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for u16 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let value = int_decode(value)?;
+
+        u16::try_from(value).map_err(|_| format!("value {value} is out of range for u16").into())
+    }
+}
+
+impl Type<Postgres> for u32 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4
+    }
+}
+
+impl PgHasArrayType for u32 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for u32 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let value = i32::try_from(*self)
+            .map_err(|_| format!("value {self} is out of range for INTEGER"))?;
+
+        buf.extend(&value.to_be_bytes());
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for u32 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let value = int_decode(value)?;
+
+        u32::try_from(value).map_err(|_| format!("value {value} is out of range for u32").into())
+    }
+}
+
+// Postgres has no 128-bit integer types either, so `i128` and `u128` are stored as `NUMERIC`.
+// Any integer type can be decoded, as can `NUMERIC` values that are integral and in range.
+
+impl Type<Postgres> for i128 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        [
+            PgTypeInfo::NUMERIC,
+            PgTypeInfo::INT2,
+            PgTypeInfo::INT4,
+            PgTypeInfo::INT8,
+        ]
+        .contains(ty)
+    }
+}
+
+impl PgHasArrayType for i128 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for i128 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let sign = if *self < 0 {
+            PgNumericSign::Negative
+        } else {
+            PgNumericSign::Positive
+        };
+
+        PgNumeric::from_integer(sign, self.unsigned_abs()).encode(buf)?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for i128 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info == PgTypeInfo::NUMERIC {
+            return numeric_int_decode(value);
+        }
+
+        Ok(int_decode(value)?.into())
+    }
+}
+
+impl Type<Postgres> for u128 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <i128 as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for u128 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for u128 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgNumeric::from_integer(PgNumericSign::Positive, *self).encode(buf)?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for u128 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info == PgTypeInfo::NUMERIC {
+            return numeric_uint_decode(value);
+        }
+
+        let value = int_decode(value)?;
+
+        u128::try_from(value).map_err(|_| format!("value {value} is out of range for u128").into())
+    }
+}
+
 impl PgHasArrayType for NonZeroI16 {
     fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::INT2_ARRAY
@@ -17,6 +17,86 @@ pub struct PgInterval {
     pub microseconds: i64,
 }
 
+// The lengths Postgres assumes when it has to compare or justify intervals
+const DAYS_PER_MONTH: i64 = 30;
+const MICROSECONDS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+
+impl PgInterval {
+    /// The months of this interval, which already include its years as 12 months each.
+    ///
+    /// This is exact, as Postgres stores years and months together as months.
+    pub fn total_months(&self) -> i32 {
+        self.months
+    }
+
+    /// The time of day part of this interval, in microseconds, without its days or months.
+    ///
+    /// This is exact.
+    pub fn microseconds(&self) -> i64 {
+        self.microseconds
+    }
+
+    /// The length of this interval in whole days, assuming 30 days per month and 24 hours
+    /// per day, with any remaining time truncated towards zero.
+    ///
+    /// These are the lengths Postgres itself assumes when comparing intervals or with
+    /// `justify_interval()`, but they are only an approximation: months vary in length,
+    /// and days do across daylight saving time changes.
+    pub fn total_days_approx(&self) -> i64 {
+        i64::from(self.months) * DAYS_PER_MONTH
+            + i64::from(self.days)
+            + self.microseconds / MICROSECONDS_PER_DAY
+    }
+
+    /// The length of this interval in microseconds, assuming 30 days per month and 24 hours
+    /// per day.
+    ///
+    /// This is an approximation, see [`total_days_approx()`](Self::total_days_approx).
+    /// Every interval fits in the result, which is larger than an `i64` for this reason.
+    pub fn total_microseconds_approx(&self) -> i128 {
+        let days = i64::from(self.months) * DAYS_PER_MONTH + i64::from(self.days);
+
+        i128::from(days) * i128::from(MICROSECONDS_PER_DAY) + i128::from(self.microseconds)
+    }
+
+    /// A short description of this interval for people to read, such as `1y 2mo 3d 4h 5m 6s`.
+    ///
+    /// Only the parts that are not zero are included, down to `ms` and `us`, and an empty
+    /// interval is `0s`. The months, days and time of an interval can have different signs,
+    /// so every negative part has its own minus sign, as in `1mo -2d` or `-1h -30m`.
+    ///
+    /// Nothing is converted between parts: 36 hours stay `36h` rather than `1d 12h`,
+    /// as a day is not always 24 hours long.
+    pub fn to_human(&self) -> String {
+        let months = i64::from(self.months);
+        let micros = self.microseconds;
+
+        let parts = [
+            (months / 12, "y"),
+            (months % 12, "mo"),
+            (i64::from(self.days), "d"),
+            (micros / 3_600_000_000, "h"),
+            (micros / 60_000_000 % 60, "m"),
+            (micros / 1_000_000 % 60, "s"),
+            (micros / 1_000 % 1_000, "ms"),
+            (micros % 1_000, "us"),
+        ];
+
+        let human = parts
+            .iter()
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, unit)| format!("{value}{unit}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if human.is_empty() {
+            return "0s".into();
+        }
+
+        human
+    }
+}
+
 impl Type<Postgres> for PgInterval {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INTERVAL
@@ -45,12 +125,111 @@ impl<'de> Decode<'de, Postgres> for PgInterval {
                 })
             }
 
-            // TODO: Implement parsing of text mode
-            PgValueFormat::Text => {
-                Err("not implemented: decode `INTERVAL` in text mode (unprepared queries)".into())
-            }
+            PgValueFormat::Text => parse_interval(value.as_str()?),
+        }
+    }
+}
+
+// Parses an interval in the `postgres` IntervalStyle, which is set for every connection:
+// `[N years] [N mons] [N days] [[+-]HH:MM:SS[.ffffff]]`, e.g. `1 year 2 mons -3 days +04:05:06.7`.
+// Any other unit word, singular or plural, from `microseconds` up to `millennia` is accepted too.
+fn parse_interval(s: &str) -> Result<PgInterval, BoxDynError> {
+    let overflow = || format!("INTERVAL out of range: {s:?}");
+
+    let mut interval = PgInterval::default();
+    let mut tokens = s.split_whitespace();
+
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            interval.microseconds = interval
+                .microseconds
+                .checked_add(
+                    parse_interval_time(token)
+                        .ok_or_else(|| format!("invalid time {token:?} in INTERVAL {s:?}"))?,
+                )
+                .ok_or_else(overflow)?;
+
+            continue;
         }
+
+        let quantity: i64 = token.parse()?;
+        let unit = tokens
+            .next()
+            .ok_or_else(|| format!("missing unit after {token:?} in INTERVAL {s:?}"))?;
+
+        // the months, days and microseconds in one of `unit`
+        let (months, days, microseconds) = match unit {
+            "millennium" | "millennia" => (12_000, 0, 0),
+            "century" | "centuries" => (1_200, 0, 0),
+            "decade" | "decades" => (120, 0, 0),
+            "year" | "years" => (12, 0, 0),
+            "mon" | "mons" | "month" | "months" => (1, 0, 0),
+            "week" | "weeks" => (0, 7, 0),
+            "day" | "days" => (0, 1, 0),
+            "hour" | "hours" => (0, 0, 3_600_000_000),
+            "min" | "mins" | "minute" | "minutes" => (0, 0, 60_000_000),
+            "sec" | "secs" | "second" | "seconds" => (0, 0, 1_000_000),
+            "msec" | "msecs" | "millisecond" | "milliseconds" => (0, 0, 1_000),
+            "usec" | "usecs" | "microsecond" | "microseconds" => (0, 0, 1),
+            _ => return Err(format!("unknown unit {unit:?} in INTERVAL {s:?}").into()),
+        };
+
+        let add = |total: i64, per_unit: i64| {
+            quantity
+                .checked_mul(per_unit)
+                .and_then(|quantity| total.checked_add(quantity))
+        };
+
+        interval.months = add(interval.months.into(), months)
+            .and_then(|months| i32::try_from(months).ok())
+            .ok_or_else(overflow)?;
+        interval.days = add(interval.days.into(), days)
+            .and_then(|days| i32::try_from(days).ok())
+            .ok_or_else(overflow)?;
+        interval.microseconds = add(interval.microseconds, microseconds).ok_or_else(overflow)?;
     }
+
+    Ok(interval)
+}
+
+// Parses `[+-]HH:MM:SS[.ffffff]` into microseconds; hours are not limited to 24
+fn parse_interval_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let mut parts = s.splitn(3, ':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let (seconds, fraction) = parts
+        .next()
+        .map(|seconds| seconds.split_once('.').unwrap_or((seconds, "")))?;
+
+    let seconds: i64 = seconds.parse().ok()?;
+
+    let fraction = if fraction.is_empty() {
+        0
+    } else if fraction.len() <= 6 && fraction.bytes().all(|b| b.is_ascii_digit()) {
+        // `.5` is half a second, not 5 microseconds
+        format!("{fraction:0<6}").parse::<i64>().ok()?
+    } else {
+        return None;
+    };
+
+    let microseconds = hours
+        .checked_mul(60)?
+        .checked_add(minutes)?
+        .checked_mul(60)?
+        .checked_add(seconds)?
+        .checked_mul(1_000_000)?
+        .checked_add(fraction)?;
+
+    Some(if negative {
+        -microseconds
+    } else {
+        microseconds
+    })
 }
 
 impl Encode<'_, Postgres> for PgInterval {
@@ -221,6 +400,202 @@ impl TryFrom<time::Duration> for PgInterval {
     }
 }
 
+// `iso8601::Duration` has separate year, month, day and time units like `INTERVAL`, so it maps
+// onto it field by field: years and months to `months`, days (or weeks) to `days`, and the
+// time of day to `microseconds`. Neither side carries one unit into another, so an hour count
+// of 24 or more stays as it is. It has no sign and only millisecond precision, so decoding an
+// `INTERVAL` with a negative field or a fraction of a millisecond is an error.
+
+#[cfg(feature = "iso8601")]
+impl Type<Postgres> for iso8601::Duration {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INTERVAL
+    }
+}
+
+#[cfg(feature = "iso8601")]
+impl PgHasArrayType for iso8601::Duration {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INTERVAL_ARRAY
+    }
+}
+
+#[cfg(feature = "iso8601")]
+impl Encode<'_, Postgres> for iso8601::Duration {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgInterval::try_from(*self)?.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<i64>()
+    }
+}
+
+#[cfg(feature = "iso8601")]
+impl<'r> Decode<'r, Postgres> for iso8601::Duration {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        iso8601::Duration::try_from(PgInterval::decode(value)?)
+    }
+}
+
+#[cfg(feature = "iso8601")]
+impl TryFrom<iso8601::Duration> for PgInterval {
+    type Error = BoxDynError;
+
+    /// Convert an `iso8601::Duration` to a `PgInterval`.
+    ///
+    /// This returns an error if any of the fields of `PgInterval` would overflow.
+    fn try_from(value: iso8601::Duration) -> Result<Self, BoxDynError> {
+        let overflow = || format!("PostgreSQL `INTERVAL` out of range: {value}");
+
+        match value {
+            iso8601::Duration::YMDHMS {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                millisecond,
+            } => {
+                let months = i32::try_from(year)
+                    .ok()
+                    .and_then(|year| year.checked_mul(12))
+                    .zip(i32::try_from(month).ok())
+                    .and_then(|(year, month)| year.checked_add(month))
+                    .ok_or_else(overflow)?;
+
+                let days = i32::try_from(day).map_err(|_| overflow())?;
+
+                let microseconds = [
+                    (hour, 3_600_000_000),
+                    (minute, 60_000_000),
+                    (second, 1_000_000),
+                    (millisecond, 1_000),
+                ]
+                .into_iter()
+                .try_fold(0_i64, |total, (count, unit)| {
+                    i64::from(count)
+                        .checked_mul(unit)
+                        .and_then(|micros| total.checked_add(micros))
+                })
+                .ok_or_else(overflow)?;
+
+                Ok(Self {
+                    months,
+                    days,
+                    microseconds,
+                })
+            }
+
+            iso8601::Duration::Weeks(weeks) => Ok(Self {
+                months: 0,
+                days: i32::try_from(weeks)
+                    .ok()
+                    .and_then(|weeks| weeks.checked_mul(7))
+                    .ok_or_else(overflow)?,
+                microseconds: 0,
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "iso8601")]
+impl TryFrom<PgInterval> for iso8601::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to an `iso8601::Duration`.
+    ///
+    /// This returns an error if any field is negative, or if `microseconds` is not a whole
+    /// number of milliseconds.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        let PgInterval {
+            months,
+            days,
+            microseconds,
+        } = value;
+
+        let negative = || format!("`iso8601::Duration` cannot be negative: {value:?}");
+
+        let months = u32::try_from(months).map_err(|_| negative())?;
+        let day = u32::try_from(days).map_err(|_| negative())?;
+        let microseconds = u64::try_from(microseconds).map_err(|_| negative())?;
+
+        if microseconds % 1_000 != 0 {
+            return Err(format!(
+                "`iso8601::Duration` does not support sub-millisecond precision: {value:?}"
+            )
+            .into());
+        }
+
+        let milliseconds = microseconds / 1_000;
+
+        Ok(iso8601::Duration::YMDHMS {
+            year: months / 12,
+            month: months % 12,
+            day,
+            hour: u32::try_from(milliseconds / 3_600_000)?,
+            // the rest are less than 60, or 1000 for milliseconds, so they always fit
+            minute: u32::try_from(milliseconds / 60_000 % 60)?,
+            second: u32::try_from(milliseconds / 1_000 % 60)?,
+            millisecond: u32::try_from(milliseconds % 1_000)?,
+        })
+    }
+}
+
+#[test]
+fn test_parse_interval() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(parse_interval("00:00:00").unwrap(), interval(0, 0, 0));
+    assert_eq!(parse_interval("1 day").unwrap(), interval(0, 1, 0));
+    assert_eq!(
+        parse_interval("02:00:00").unwrap(),
+        interval(0, 0, 7_200_000_000)
+    );
+    assert_eq!(
+        parse_interval("1 year 2 mons 3 days 04:05:06.789").unwrap(),
+        interval(14, 3, 14_706_789_000)
+    );
+    assert_eq!(
+        parse_interval("-1 years -2 mons +3 days -04:05:06.000001").unwrap(),
+        interval(-14, 3, -14_706_000_001)
+    );
+    assert_eq!(
+        parse_interval("-00:00:00.5").unwrap(),
+        interval(0, 0, -500_000)
+    );
+    assert_eq!(
+        parse_interval("2562047788:00:54.775807").unwrap(),
+        interval(0, 0, i64::MAX)
+    );
+
+    assert_eq!(
+        parse_interval("2 years 3 mons").unwrap(),
+        interval(27, 0, 0)
+    );
+    assert_eq!(parse_interval("1 week 2 days").unwrap(), interval(0, 9, 0));
+    assert_eq!(
+        parse_interval("1 millennium 2 centuries -1 decade 1 month").unwrap(),
+        interval(14_281, 0, 0)
+    );
+    assert_eq!(
+        parse_interval("1 hour 2 minutes 3 seconds 4 milliseconds 5 microseconds").unwrap(),
+        interval(0, 0, 3_723_004_005)
+    );
+
+    assert!(parse_interval("1").is_err());
+    assert!(parse_interval("1 fortnight").is_err());
+    assert!(parse_interval("01:02").is_err());
+    assert!(parse_interval("00:00:00.1234567").is_err());
+    assert!(parse_interval("178956971 years").is_err());
+    assert!(parse_interval("1789570 centuries").is_err());
+}
+
 #[test]
 fn test_encode_interval() {
     let mut buf = PgArgumentBuffer::default();
@@ -310,6 +685,45 @@ fn test_encode_interval() {
     );
 }
 
+#[test]
+fn test_pginterval_totals() {
+    let interval = PgInterval {
+        months: 14,
+        days: 3,
+        microseconds: 2 * MICROSECONDS_PER_DAY + 5,
+    };
+
+    assert_eq!(interval.total_months(), 14);
+    assert_eq!(interval.microseconds(), 2 * MICROSECONDS_PER_DAY + 5);
+    assert_eq!(interval.total_days_approx(), 14 * 30 + 3 + 2);
+    assert_eq!(
+        interval.total_microseconds_approx(),
+        i128::from(14 * 30 + 3 + 2) * i128::from(MICROSECONDS_PER_DAY) + 5
+    );
+
+    // time short of a whole day is truncated towards zero
+    let interval = PgInterval {
+        months: 0,
+        days: -1,
+        microseconds: -MICROSECONDS_PER_DAY + 1,
+    };
+
+    assert_eq!(interval.total_days_approx(), -1);
+    assert_eq!(
+        interval.total_microseconds_approx(),
+        i128::from(-2 * MICROSECONDS_PER_DAY + 1)
+    );
+
+    // the largest interval does not overflow
+    let interval = PgInterval {
+        months: i32::MAX,
+        days: i32::MAX,
+        microseconds: i64::MAX,
+    };
+
+    assert!(interval.total_microseconds_approx() > i128::from(i64::MAX));
+}
+
 #[test]
 fn test_pginterval_std() {
     // Case for positive duration
@@ -397,3 +811,86 @@ fn test_pginterval_time() {
     assert!(PgInterval::try_from(time::Duration::seconds(10_000_000_000_000)).is_err());
     assert!(PgInterval::try_from(time::Duration::seconds(-10_000_000_000_000)).is_err());
 }
+
+#[test]
+#[cfg(feature = "iso8601")]
+fn test_pginterval_iso8601() {
+    let duration = iso8601::Duration::YMDHMS {
+        year: 1,
+        month: 2,
+        day: 3,
+        hour: 28,
+        minute: 5,
+        second: 6,
+        millisecond: 7,
+    };
+    let interval = PgInterval {
+        months: 14,
+        days: 3,
+        microseconds: 101_106_007_000,
+    };
+    assert_eq!(PgInterval::try_from(duration).unwrap(), interval);
+    assert_eq!(iso8601::Duration::try_from(interval).unwrap(), duration);
+
+    // Weeks are converted to days
+    assert_eq!(
+        PgInterval::try_from(iso8601::Duration::Weeks(2)).unwrap(),
+        PgInterval {
+            months: 0,
+            days: 14,
+            microseconds: 0,
+        }
+    );
+
+    // Case when an `INTERVAL` field overflows
+    assert!(PgInterval::try_from(iso8601::Duration::Weeks(u32::MAX)).is_err());
+    assert!(PgInterval::try_from(iso8601::Duration::YMDHMS {
+        year: u32::MAX,
+        month: 0,
+        day: 0,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+    })
+    .is_err());
+
+    // Case for negative intervals
+    assert!(iso8601::Duration::try_from(PgInterval {
+        months: 0,
+        days: -1,
+        microseconds: 0,
+    })
+    .is_err());
+
+    // Case when precision loss occurs
+    assert!(iso8601::Duration::try_from(PgInterval {
+        months: 0,
+        days: 0,
+        microseconds: 1_500,
+    })
+    .is_err());
+}
+
+#[test]
+fn test_pginterval_to_human() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(interval(0, 1, 7_380_000_000).to_human(), "1d 2h 3m");
+    assert_eq!(
+        interval(14, 3, 14_706_007_008).to_human(),
+        "1y 2mo 3d 4h 5m 6s 7ms 8us"
+    );
+    assert_eq!(interval(0, 0, 129_600_000_000).to_human(), "36h");
+    assert_eq!(interval(0, 0, 1_500).to_human(), "1ms 500us");
+
+    assert_eq!(interval(0, 0, 0).to_human(), "0s");
+
+    assert_eq!(interval(0, 0, -5_400_000_000).to_human(), "-1h -30m");
+    assert_eq!(interval(-13, 0, 0).to_human(), "-1y -1mo");
+    assert_eq!(interval(1, -2, 0).to_human(), "1mo -2d");
+}
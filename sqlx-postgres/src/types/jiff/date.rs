@@ -0,0 +1,58 @@
+use std::mem;
+
+use jiff::civil::{date, Date};
+use jiff::SignedDuration;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+const POSTGRES_EPOCH_DATE: Date = date(2000, 1, 1);
+
+impl Type<Postgres> for Date {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE
+    }
+}
+
+impl PgHasArrayType for Date {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for Date {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // DATE is encoded as the days since epoch
+        let days: i32 = (self.duration_since(POSTGRES_EPOCH_DATE).as_secs() / 86_400)
+            .try_into()
+            .map_err(|_| {
+                format!("value {self:?} would overflow binary encoding for Postgres DATE")
+            })?;
+
+        Encode::<Postgres>::encode(days, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i32>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Date {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(match value.format() {
+            PgValueFormat::Binary => {
+                // DATE is encoded as the days since epoch
+                let days: i32 = Decode::<Postgres>::decode(value)?;
+
+                POSTGRES_EPOCH_DATE
+                    .checked_add(SignedDuration::from_hours(i64::from(days) * 24))
+                    .map_err(|e| format!("Postgres DATE out of range for jiff: {e}"))?
+            }
+
+            PgValueFormat::Text => value.as_str()?.parse()?,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use std::mem;
+
+use jiff::civil::{datetime, DateTime};
+use jiff::SignedDuration;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+const POSTGRES_EPOCH_DATETIME: DateTime = datetime(2000, 1, 1, 0, 0, 0, 0);
+
+impl Type<Postgres> for DateTime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMP
+    }
+}
+
+impl PgHasArrayType for DateTime {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMP_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for DateTime {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // TIMESTAMP is encoded as the microseconds since the epoch
+        let micros = i64::try_from(self.duration_since(POSTGRES_EPOCH_DATETIME).as_micros())
+            .map_err(|_| format!("DateTime out of range for Postgres: {self}"))?;
+
+        Encode::<Postgres>::encode(micros, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DateTime {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(match value.format() {
+            PgValueFormat::Binary => {
+                // TIMESTAMP is encoded as the microseconds since the epoch
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                POSTGRES_EPOCH_DATETIME
+                    .checked_add(SignedDuration::from_micros(us))
+                    .map_err(|e| format!("Postgres TIMESTAMP out of range for jiff: {e}"))?
+            }
+
+            // jiff accepts the space separator Postgres uses between date and time
+            PgValueFormat::Text => value.as_str()?.parse()?,
+        })
+    }
+}
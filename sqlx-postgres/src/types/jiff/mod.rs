@@ -0,0 +1,4 @@
+mod date;
+mod datetime;
+mod time;
+mod timestamp;
@@ -0,0 +1,59 @@
+use std::mem;
+
+use jiff::{SignedDuration, Timestamp};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+// 2000-01-01T00:00:00Z
+const POSTGRES_EPOCH: Timestamp = Timestamp::constant(946_684_800, 0);
+
+impl Type<Postgres> for Timestamp {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMPTZ
+    }
+}
+
+impl PgHasArrayType for Timestamp {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMPTZ_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for Timestamp {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // TIMESTAMPTZ is encoded as the microseconds since the epoch, in UTC
+        let micros = i64::try_from(self.duration_since(POSTGRES_EPOCH).as_micros())
+            .map_err(|_| format!("Timestamp out of range for Postgres: {self}"))?;
+
+        Encode::<Postgres>::encode(micros, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Timestamp {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(match value.format() {
+            PgValueFormat::Binary => {
+                // TIMESTAMPTZ is encoded as the microseconds since the epoch, in UTC
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                POSTGRES_EPOCH
+                    .checked_add(SignedDuration::from_micros(us))
+                    .map_err(|e| format!("Postgres TIMESTAMPTZ out of range for jiff: {e}"))?
+            }
+
+            PgValueFormat::Text => {
+                // Postgres renders the offset as `+HH`, `+HH:MM` or `+HH:MM:SS`,
+                // all of which jiff accepts.
+                value.as_str()?.parse()?
+            }
+        })
+    }
+}
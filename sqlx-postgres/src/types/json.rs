@@ -65,6 +65,8 @@ where
             if *ty == PgTypeInfo::JSON || *ty == PgTypeInfo::JSON_ARRAY {
                 buf[0] = b' ';
             }
+
+            Ok(())
         });
 
         // JSONB version (as of 2020-03-20)
@@ -85,13 +87,18 @@ where
         let mut buf = value.as_bytes()?;
 
         if value.format() == PgValueFormat::Binary && value.type_info == PgTypeInfo::JSONB {
-            assert_eq!(
-                buf[0], 1,
-                "unsupported JSONB format version {}; please open an issue",
-                buf[0]
-            );
-
-            buf = &buf[1..];
+            // the JSON text follows a version byte; borrowing the rest of the buffer
+            // lets `&RawValue` and other borrowed types decode without copying
+            buf = match buf.split_first() {
+                Some((1, rest)) => rest,
+                Some((version, _)) => {
+                    return Err(format!(
+                        "unsupported JSONB format version {version}; please open an issue"
+                    )
+                    .into())
+                }
+                None => return Err("JSONB value is missing its format version".into()),
+            };
         }
 
         serde_json::from_slice(buf).map(Json).map_err(Into::into)
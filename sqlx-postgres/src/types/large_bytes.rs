@@ -0,0 +1,52 @@
+use sqlx_core::bytes::Bytes;
+
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgTypeInfo, Postgres};
+
+/// A large `BYTEA` parameter that is written to the connection a chunk at a time, straight from
+/// the [`Bytes`] holding it.
+///
+/// Any other value is copied into the arguments of its query, and the arguments are copied into
+/// the `Bind` message sent to the server, so binding a `Vec<u8>` of hundreds of megabytes needs
+/// several times that much memory. A `PgLargeBytes` is left where it is until the query is
+/// executed, then sent in chunks of 64 KiB, so no more than that is copied at once.
+///
+/// This only works for a parameter by itself, not inside an array, range or record, and only
+/// when executing or fetching a query. Binding it in a batch, a portal or a `COPY` is an error.
+/// With [`prefer_simple_protocol`][crate::PgConnectOptions::prefer_simple_protocol] it is
+/// inlined into the query like any other value, which copies it.
+///
+/// The `Bind` message is at most 1 GiB, which is also the maximum size of a `BYTEA`.
+/// If the query is cancelled while the value is being sent, the connection is left in the middle
+/// of a message. Any later query or ping on it then fails, so a pool closes it instead of
+/// reusing it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgLargeBytes(pub Bytes);
+
+impl PgLargeBytes {
+    /// Wrap anything that can be turned into [`Bytes`] without copying, such as a `Vec<u8>`.
+    pub fn new(bytes: impl Into<Bytes>) -> Self {
+        PgLargeBytes(bytes.into())
+    }
+}
+
+impl Type<Postgres> for PgLargeBytes {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BYTEA
+    }
+}
+
+impl Encode<'_, Postgres> for PgLargeBytes {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // only a reference to the bytes is kept
+        buf.push_external(self.0.clone());
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
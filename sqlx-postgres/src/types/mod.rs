@@ -6,16 +6,22 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bool`                                | BOOL                                                 |
 //! | `i8`                                  | "CHAR"                                               |
+//! | `char`                                | "CHAR", CHAR(1)<sup>5</sup>                          |
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
-//! | `i32`                                 | INT, SERIAL, INT4                                    |
-//! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `i32`                                 | INT, SERIAL, INT4, NUMERIC<sup>2</sup>               |
+//! | `i64`                                 | BIGINT, BIGSERIAL, INT8, NUMERIC<sup>2</sup>         |
+//! | `u16`                                 | SMALLINT, SMALLSERIAL, INT2<sup>4</sup>              |
+//! | `u32`                                 | INT, SERIAL, INT4<sup>4</sup>                        |
+//! | `i128`, `u128`                        | NUMERIC<sup>6</sup>                                  |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
-//! | `&str`, [`String`]                    | VARCHAR, CHAR(N), TEXT, NAME, CITEXT                 |
-//! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
+//! | `&str`, [`String`], `Arc<str>`        | VARCHAR, CHAR(N), TEXT, NAME, CITEXT                 |
+//! | `&[u8]`, `Vec<u8>`                    | BYTEA, BIT<sup>3</sup>, VARBIT<sup>3</sup>           |
+//! | [`PgLargeBytes`]                      | BYTEA                                                |
 //! | `()`                                  | VOID                                                 |
 //! | [`PgInterval`]                        | INTERVAL                                             |
 //! | [`PgRange<T>`](PgRange)               | INT8RANGE, INT4RANGE, TSRANGE, TSTZRANGE, DATERANGE, NUMRANGE |
+//! | [`PgMultiRange<T>`](PgMultiRange)     | INT8MULTIRANGE, INT4MULTIRANGE, TSMULTIRANGE, TSTZMULTIRANGE, DATEMULTIRANGE, NUMMULTIRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
 //! | [`PgLTree`]                           | LTREE                                                |
 //! | [`PgLQuery`]                          | LQUERY                                               |
@@ -25,12 +31,35 @@
 //! | [`PgLine`]                            | LINE                                                 |
 //! | [`PgLSeg`]                            | LSEG                                                 |
 //! | [`PgBox`]                             | BOX                                                  |
+//! | [`PgPolygon`]                         | POLYGON                                              |
+//! | [`PgCircle`]                          | CIRCLE                                               |
 //! | [`PgHstore`]                          | HSTORE                                               |
+//! | [`PgRefCursor`]                       | REFCURSOR                                            |
+//! | [`PgEpochSeconds`], [`PgEpochMillis`] | TIMESTAMPTZ                                          |
 //!
 //! <sup>1</sup> SQLx generally considers `CITEXT` to be compatible with `String`, `&str`, etc.,
 //! but this wrapper type is available for edge cases, such as `CITEXT[]` which Postgres
 //! does not consider to be compatible with `TEXT[]`.
 //!
+//! <sup>2</sup> Only for decoding, and only if the value is integral and fits the target type,
+//! e.g. the result of `SUM(bigint)`. A fractional or out-of-range value is an error.
+//!
+//! <sup>3</sup> Only for decoding. The bit string is returned in its packed form: the first bit
+//! is the most significant bit of the first byte, and if the length is not a multiple of 8 the
+//! unused low bits of the final byte are zero. The bit length itself is not returned; use
+//! `bit_vec::BitVec` if it is needed.
+//!
+//! <sup>4</sup> Postgres has no unsigned integer types, so these are stored in the signed type
+//! of the same width. Encoding a value above `i16::MAX` or `i32::MAX` respectively is an error,
+//! as is decoding a negative value. Use `i32` or `i64` to store the full unsigned range.
+//!
+//! <sup>5</sup> Only for decoding. A `"CHAR"` holds a single byte, so only ASCII characters
+//! can be decoded from it.
+//!
+//! <sup>6</sup> Postgres has no 128-bit integer types, so these are stored as `NUMERIC`.
+//! Decoding a fractional or out-of-range value is an error. They can also be decoded from
+//! `SMALLINT`, `INT` and `BIGINT`.
+//!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //! Requires the `bigdecimal` Cargo feature flag.
 //!
@@ -57,10 +86,23 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `chrono::DateTime<Utc>`               | TIMESTAMPTZ                                          |
 //! | `chrono::DateTime<Local>`             | TIMESTAMPTZ                                          |
+//! | `chrono::DateTime<chrono_tz::Tz>`     | TIMESTAMPTZ                                          |
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
+//! | [`PgDateOnly<NaiveDateTime>`]         | DATE                                                 |
+//!
+//! [`PgDateOnly`] drops the time of day when encoding a `NaiveDateTime`, and decodes a `DATE`
+//! at midnight.
+//!
+//! `TIME` goes up to and including `24:00:00`, which `chrono::NaiveTime` cannot represent,
+//! so decoding it is an error. Select such a value as `INTERVAL` to decode it as a
+//! [`PgInterval`] instead.
+//!
+//! `DateTime<chrono_tz::Tz>` requires the `chrono-tz` Cargo feature flag. It is decoded into
+//! the session `TimeZone` of the connection, which must be a named time zone such as
+//! `Europe/Berlin`.
 //!
 //! ### [`time`](https://crates.io/crates/time)
 //!
@@ -73,6 +115,23 @@
 //! | `time::Date`                          | DATE                                                 |
 //! | `time::Time`                          | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
+//! | [`PgOffsetDateTime`]                  | TIMESTAMPTZ                                          |
+//!
+//! `TIMESTAMPTZ` only has microsecond precision, so encoding a `time::OffsetDateTime` truncates
+//! any nanoseconds. Use [`PgOffsetDateTime`] to round them or reject the value instead.
+//!
+//! As with `chrono`, decoding a `TIME` of `24:00:00` into a `time::Time` is an error.
+//!
+//! ### [`jiff`](https://crates.io/crates/jiff)
+//!
+//! Requires the `jiff` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `jiff::Timestamp`                     | TIMESTAMPTZ                                          |
+//! | `jiff::civil::DateTime`               | TIMESTAMP                                            |
+//! | `jiff::civil::Date`                   | DATE                                                 |
+//! | `jiff::civil::Time`                   | TIME                                                 |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -81,6 +140,17 @@
 //! | Rust type                             | Postgres type(s)                                     |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `uuid::Uuid`                          | UUID                                                 |
+//! | [`PgTextUuid`]                        | TEXT, VARCHAR                                        |
+//!
+//! ### [`url`](https://crates.io/crates/url)
+//!
+//! Requires the `url` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `url::Url`                            | VARCHAR, CHAR(N), TEXT, NAME, CITEXT                 |
+//!
+//! Decoding fails if the text is not a valid absolute URL.
 //!
 //! ### [`ipnetwork`](https://crates.io/crates/ipnetwork)
 //!
@@ -113,6 +183,97 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bit_vec::BitVec`                     | BIT, VARBIT                                          |
 //!
+//! ### [`ordered-float`](https://crates.io/crates/ordered-float)
+//!
+//! Requires the `ordered-float` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `ordered_float::OrderedFloat<f32>`    | REAL, FLOAT4                                         |
+//! | `ordered_float::OrderedFloat<f64>`    | DOUBLE PRECISION, FLOAT8                             |
+//!
+//! ### [`arrayvec`](https://crates.io/crates/arrayvec)
+//!
+//! Requires the `arrayvec` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `arrayvec::ArrayVec<T, CAP>`          | T[], like `Vec<T>`                                   |
+//!
+//! Decoding fails if the array has more than `CAP` elements.
+//!
+//! ### [`iso8601`](https://crates.io/crates/iso8601)
+//!
+//! Requires the `iso8601` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `iso8601::Duration`                   | INTERVAL                                             |
+//!
+//! Years and months are stored as the months of the interval, days (or weeks, as 7 days)
+//! as its days, and hours, minutes, seconds and milliseconds as its time. No unit is carried
+//! into another, so `PT36H` stays 36 hours rather than becoming 1 day and 12 hours.
+//! Decoding fails if the interval is negative or has a fraction of a millisecond.
+//!
+//! ### [`camino`](https://crates.io/crates/camino)
+//!
+//! Requires the `camino` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `&camino::Utf8Path`, `camino::Utf8PathBuf` | VARCHAR, CHAR(N), TEXT, NAME, CITEXT            |
+//!
+//! ### [`compact_str`](https://crates.io/crates/compact_str)
+//!
+//! Requires the `compact_str` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `compact_str::CompactString`          | VARCHAR, CHAR(N), TEXT, NAME, CITEXT                 |
+//!
+//! `Vec<CompactString>` and `&[CompactString]` are `TEXT[]`, like `Vec<String>`. Short
+//! strings are decoded inline, without allocating.
+//!
+//! ### [`nalgebra`](https://crates.io/crates/nalgebra)
+//!
+//! Requires the `nalgebra` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `nalgebra::Point2<f64>`               | POINT                                                |
+//!
+//! [`PgPoint`] converts to and from `Point2<f64>`, and [`PgBox`] to and from a pair of them,
+//! upper right corner first.
+//!
+//! ### [`num-bigint`](https://crates.io/crates/num-bigint)
+//!
+//! Requires the `num-bigint` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `num_bigint::BigInt`                  | NUMERIC                                              |
+//! | `num_bigint::BigUint`                 | NUMERIC                                              |
+//!
+//! For exact integers of any size, without the `bigdecimal` feature. Decoding fails if the
+//! value is `NaN` or has a nonzero fractional part, or, for `BigUint`, is negative.
+//!
+//! ### [`secrecy`](https://crates.io/crates/secrecy)
+//!
+//! Requires the `secrecy` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `secrecy::SecretString`               | VARCHAR, CHAR(N), TEXT, NAME, CITEXT                 |
+//!
+//! The secret is only exposed while it is encoded into the arguments of a query, and a decoded
+//! value is wrapped as soon as it is read. Its `Debug` output is redacted, and so is the argument
+//! it was encoded into, both in the `Debug` output of `PgArguments` and in the parameters logged
+//! with [`PgConnectOptions::log_parameters`]. Note that with
+//! [`PgConnectOptions::prefer_simple_protocol`], the value is sent inlined into the query text.
+//!
+//! [`PgConnectOptions::log_parameters`]: crate::PgConnectOptions::log_parameters
+//! [`PgConnectOptions::prefer_simple_protocol`]: crate::PgConnectOptions::prefer_simple_protocol
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -150,8 +311,12 @@
 //! }
 //! ```
 //!
-//! Anonymous composite types are represented as tuples. Note that anonymous composites may only
-//! be returned and not sent to Postgres (this is a limitation of postgres).
+//! Anonymous composite types are represented as tuples. Postgres cannot receive an anonymous
+//! composite, but a tuple can be sent where the composite type it stands for can be inferred
+//! from the query, such as the argument of a function taking a composite type or a parameter
+//! cast with `$1::inventory_item`. Its fields must have the types of the composite's fields.
+//!
+//! Either kind can also be decoded as a [`PgRecord`], whose fields are accessed by name.
 //!
 //! # Arrays
 //!
@@ -203,6 +368,7 @@ mod array;
 mod bool;
 mod bytes;
 mod citext;
+mod epoch;
 mod float;
 mod hstore;
 mod int;
@@ -211,10 +377,13 @@ mod lquery;
 mod ltree;
 // Not behind a Cargo feature because we require JSON in the driver implementation.
 mod json;
+mod large_bytes;
 mod money;
+mod multirange;
 mod oid;
 mod range;
 mod record;
+mod refcursor;
 mod str;
 mod text;
 mod tuple;
@@ -230,8 +399,12 @@ mod cube;
 
 mod geometry;
 
-#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
-mod numeric;
+// Integer types can decode integral `NUMERIC` values, but only the decimal integrations encode.
+#[cfg_attr(
+    not(any(feature = "bigdecimal", feature = "rust_decimal")),
+    allow(dead_code)
+)]
+pub(crate) mod numeric;
 
 #[cfg(feature = "rust_decimal")]
 mod rust_decimal;
@@ -239,12 +412,21 @@ mod rust_decimal;
 #[cfg(feature = "chrono")]
 mod chrono;
 
+#[cfg(feature = "chrono")]
+mod date_only;
+
 #[cfg(feature = "time")]
 mod time;
 
+#[cfg(feature = "jiff")]
+mod jiff;
+
 #[cfg(feature = "uuid")]
 mod uuid;
 
+#[cfg(feature = "url")]
+mod url;
+
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
@@ -257,15 +439,40 @@ mod mac_address;
 #[cfg(feature = "bit-vec")]
 mod bit_vec;
 
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+
+#[cfg(feature = "camino")]
+mod camino;
+
+#[cfg(feature = "compact_str")]
+mod compact_str;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+
+#[cfg(feature = "secrecy")]
+mod secrecy;
+
 pub use array::PgHasArrayType;
 pub use citext::PgCiText;
 pub use cube::PgCube;
+pub use epoch::{PgEpochMillis, PgEpochSeconds};
+pub use geometry::circle::PgCircle;
 pub use geometry::line::PgLine;
 pub use geometry::line_segment::PgLSeg;
 pub use geometry::point::PgPoint;
+pub use geometry::polygon::PgPolygon;
 pub use geometry::r#box::PgBox;
-pub use hstore::PgHstore;
+pub use hstore::{PgHstore, PgHstoreJsonPolicy};
 pub use interval::PgInterval;
+pub use large_bytes::PgLargeBytes;
 pub use lquery::PgLQuery;
 pub use lquery::PgLQueryLevel;
 pub use lquery::PgLQueryVariant;
@@ -274,12 +481,25 @@ pub use ltree::PgLTree;
 pub use ltree::PgLTreeLabel;
 pub use ltree::PgLTreeParseError;
 pub use money::PgMoney;
+pub use multirange::PgMultiRange;
 pub use oid::Oid;
 pub use range::PgRange;
+pub use refcursor::PgRefCursor;
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 pub use time_tz::PgTimeTz;
 
+#[cfg(feature = "chrono")]
+pub use date_only::PgDateOnly;
+
+#[cfg(feature = "time")]
+pub use self::time::{PgOffsetDateTime, PgSubMicros};
+
+#[cfg(feature = "uuid")]
+pub use self::uuid::PgTextUuid;
+
+pub use record::PgRecord;
+
 // used in derive(Type) for `struct`
 // but the interface is not considered part of the public API
 #[doc(hidden)]
@@ -0,0 +1,376 @@
+use std::fmt::{self, Display, Formatter};
+
+use sqlx_core::bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::type_info::PgTypeKind;
+use crate::types::{PgRange, Type};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+/// A multirange: an ordered set of non-overlapping, non-adjacent ranges.
+///
+/// Multirange types were added in Postgres 14. Each range type has a corresponding multirange
+/// type, e.g. `INT4MULTIRANGE` for `INT4RANGE`; see [`PgRange`] for the range types supported.
+///
+/// The server normalizes multiranges on input, so the ranges decoded from a multirange are
+/// sorted, merged where they overlap or touch, and never empty.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PgMultiRange<T>(pub Vec<PgRange<T>>);
+
+impl<T> Default for PgMultiRange<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> From<Vec<PgRange<T>>> for PgMultiRange<T> {
+    fn from(ranges: Vec<PgRange<T>>) -> Self {
+        Self(ranges)
+    }
+}
+
+impl<T> FromIterator<PgRange<T>> for PgMultiRange<T> {
+    fn from_iter<I: IntoIterator<Item = PgRange<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Type<Postgres> for PgMultiRange<i32> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<i32>(ty)
+    }
+}
+
+impl Type<Postgres> for PgMultiRange<i64> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<i64>(ty)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Type<Postgres> for PgMultiRange<bigdecimal::BigDecimal> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<bigdecimal::BigDecimal>(ty)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Type<Postgres> for PgMultiRange<rust_decimal::Decimal> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<rust_decimal::Decimal>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for PgMultiRange<chrono::NaiveDate> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::NaiveDate>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for PgMultiRange<chrono::NaiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::NaiveDateTime>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Type<Postgres> for PgMultiRange<chrono::DateTime<Tz>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::DateTime<Tz>>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultiRange<time::Date> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::Date>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultiRange<time::PrimitiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::PrimitiveDateTime>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultiRange<time::OffsetDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::OffsetDateTime>(ty)
+    }
+}
+
+impl PgHasArrayType for PgMultiRange<i32> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE_ARRAY
+    }
+}
+
+impl PgHasArrayType for PgMultiRange<i64> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl PgHasArrayType for PgMultiRange<bigdecimal::BigDecimal> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl PgHasArrayType for PgMultiRange<rust_decimal::Decimal> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PgHasArrayType for PgMultiRange<chrono::NaiveDate> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PgHasArrayType for PgMultiRange<chrono::NaiveDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> PgHasArrayType for PgMultiRange<chrono::DateTime<Tz>> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgHasArrayType for PgMultiRange<time::Date> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgHasArrayType for PgMultiRange<time::PrimitiveDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgHasArrayType for PgMultiRange<time::OffsetDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+impl<'q, T> Encode<'q, Postgres> for PgMultiRange<T>
+where
+    T: Encode<'q, Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // https://github.com/postgres/postgres/blob/REL_14_0/src/backend/utils/adt/multirangetypes.c#L341
+
+        let count = i32::try_from(self.0.len())
+            .map_err(|_| format!("too many ranges for a multirange: {}", self.0.len()))?;
+
+        buf.extend(&count.to_be_bytes());
+
+        for range in &self.0 {
+            buf.encode(range)?;
+        }
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgMultiRange<T>
+where
+    T: Type<Postgres> + for<'a> Decode<'a, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let range_ty = value
+            .type_info
+            .0
+            .try_multirange_element()
+            .ok_or_else(|| format!("unexpected non-multirange type {}", value.type_info))?;
+
+        match value.format {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+
+                if buf.len() < 4 {
+                    return Err("multirange is missing its range count".into());
+                }
+
+                let count = buf.get_i32();
+                let mut ranges = Vec::with_capacity(usize::try_from(count).unwrap_or(0));
+
+                for _ in 0..count {
+                    let range = value.get_nested(&mut buf, range_ty.clone())?;
+
+                    ranges.push(PgRange::decode(range)?);
+                }
+
+                Ok(PgMultiRange(ranges))
+            }
+
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                let inner = s
+                    .strip_prefix('{')
+                    .and_then(|s| s.strip_suffix('}'))
+                    .ok_or_else(|| format!("expected a multirange literal, got {s:?}"))?;
+
+                split_ranges(inner)?
+                    .into_iter()
+                    .map(|range| {
+                        PgRange::decode(PgValueRef {
+                            type_info: range_ty.clone(),
+                            format: PgValueFormat::Text,
+                            value: Some(range.as_bytes()),
+                            row: None,
+                            #[cfg(feature = "chrono-tz")]
+                            time_zone: value.time_zone,
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+// Split the inside of a multirange literal, e.g. `[1,3),[5,7)`, into its ranges.
+// Commas separate ranges as well as the bounds within a range, so only those outside
+// of a range's brackets (and of any quoted bound) end a range.
+fn split_ranges(s: &str) -> Result<Vec<&str>, BoxDynError> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    let mut in_quotes = false;
+    let mut in_escape = false;
+
+    for (i, ch) in s.char_indices() {
+        match ch {
+            _ if in_escape => in_escape = false,
+            '\\' => in_escape = true,
+            '"' => in_quotes = !in_quotes,
+            _ if in_quotes => {}
+
+            '[' | '(' if start.is_none() => start = Some(i),
+
+            ']' | ')' => {
+                if let Some(begin) = start.take() {
+                    ranges.push(&s[begin..=i]);
+                }
+            }
+
+            ',' if start.is_none() => {}
+
+            // a bound (or its separator) inside of a range
+            _ if start.is_some() => {}
+
+            _ => return Err(format!("unexpected {ch:?} in multirange literal").into()),
+        }
+    }
+
+    if start.is_some() || in_quotes {
+        return Err("unterminated range in multirange literal".into());
+    }
+
+    Ok(ranges)
+}
+
+impl<T> Display for PgMultiRange<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (i, range) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "{range}")?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+fn multirange_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
+    // we require the declared type to be a _multirange_ of ranges
+    // with an element type that is acceptable
+    match ty.0.try_multirange_element() {
+        Some(range) => match range.kind() {
+            PgTypeKind::Range(element) => E::compatible(element),
+            _ => false,
+        },
+        None => false,
+    }
+}
+
+#[test]
+fn test_split_ranges() {
+    assert_eq!(split_ranges("").unwrap(), Vec::<&str>::new());
+    assert_eq!(split_ranges("[1,3),[5,7)").unwrap(), vec!["[1,3)", "[5,7)"]);
+    assert_eq!(
+        split_ranges(r#"["2020-01-01 00:00:00+00","2020-01-02 00:00:00+00"),(,)"#).unwrap(),
+        vec![
+            r#"["2020-01-01 00:00:00+00","2020-01-02 00:00:00+00")"#,
+            "(,)"
+        ]
+    );
+    assert!(split_ranges("[1,3").is_err());
+}
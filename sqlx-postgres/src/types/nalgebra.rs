@@ -0,0 +1,97 @@
+use nalgebra::Point2;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::{PgBox, PgPoint, Type};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+
+impl From<Point2<f64>> for PgPoint {
+    fn from(point: Point2<f64>) -> Self {
+        PgPoint {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<PgPoint> for Point2<f64> {
+    fn from(point: PgPoint) -> Self {
+        Point2::new(point.x, point.y)
+    }
+}
+
+// Any two opposite corners are accepted, like Postgres does on input
+impl From<(Point2<f64>, Point2<f64>)> for PgBox {
+    fn from((a, b): (Point2<f64>, Point2<f64>)) -> Self {
+        PgBox {
+            upper_right_x: a.x.max(b.x),
+            upper_right_y: a.y.max(b.y),
+            lower_left_x: a.x.min(b.x),
+            lower_left_y: a.y.min(b.y),
+        }
+    }
+}
+
+// The upper right corner comes first, as in the text representation of `box`
+impl From<PgBox> for (Point2<f64>, Point2<f64>) {
+    fn from(value: PgBox) -> Self {
+        (
+            Point2::new(value.upper_right_x, value.upper_right_y),
+            Point2::new(value.lower_left_x, value.lower_left_y),
+        )
+    }
+}
+
+impl Type<Postgres> for Point2<f64> {
+    fn type_info() -> PgTypeInfo {
+        <PgPoint as Type<Postgres>>::type_info()
+    }
+}
+
+impl PgHasArrayType for Point2<f64> {
+    fn array_type_info() -> PgTypeInfo {
+        <PgPoint as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Point2<f64> {
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(<PgPoint as Type<Postgres>>::type_info())
+    }
+
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <PgPoint as Encode<Postgres>>::encode(PgPoint::from(*self), buf)
+    }
+}
+
+impl Decode<'_, Postgres> for Point2<f64> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(<PgPoint as Decode<Postgres>>::decode(value)?.into())
+    }
+}
+
+#[test]
+fn test_point_conversions() {
+    let point = PgPoint::from(Point2::new(1.5, -2.5));
+    assert_eq!(point, PgPoint { x: 1.5, y: -2.5 });
+    assert_eq!(Point2::from(point), Point2::new(1.5, -2.5));
+}
+
+#[test]
+fn test_box_conversions() {
+    let pg_box = PgBox::from((Point2::new(1., 4.), Point2::new(3., 2.)));
+    assert_eq!(
+        pg_box,
+        PgBox {
+            upper_right_x: 3.,
+            upper_right_y: 4.,
+            lower_left_x: 1.,
+            lower_left_y: 2.,
+        }
+    );
+
+    let (upper_right, lower_left) = <(Point2<f64>, Point2<f64>)>::from(pg_box);
+    assert_eq!(upper_right, Point2::new(3., 4.));
+    assert_eq!(lower_left, Point2::new(1., 2.));
+}
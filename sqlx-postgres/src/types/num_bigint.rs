@@ -0,0 +1,218 @@
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::numeric::{PgNumeric, PgNumericSign};
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+impl Type<Postgres> for BigInt {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+}
+
+impl PgHasArrayType for BigInt {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC_ARRAY
+    }
+}
+
+impl Type<Postgres> for BigUint {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+}
+
+impl PgHasArrayType for BigUint {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC_ARRAY
+    }
+}
+
+impl TryFrom<&'_ PgNumeric> for BigInt {
+    type Error = BoxDynError;
+
+    fn try_from(numeric: &'_ PgNumeric) -> Result<Self, BoxDynError> {
+        let PgNumeric::Number {
+            sign,
+            ref digits,
+            weight,
+            ..
+        } = *numeric
+        else {
+            return Err("cannot convert NUMERIC NaN to an integer".into());
+        };
+
+        // digits at indices `0..=weight` are before the decimal point
+        let int_digits = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+
+        if digits.iter().skip(int_digits).any(|&digit| digit != 0) {
+            return Err(
+                "NUMERIC value has a fractional part and cannot be decoded as an integer".into(),
+            );
+        }
+
+        // no optimized algorithm for base-10000 so use base-100, as for `BigDecimal`;
+        // digits stripped from the end by Postgres are zeroes
+        let mut cents = Vec::with_capacity(int_digits * 2);
+
+        for i in 0..int_digits {
+            let digit = digits.get(i).copied().unwrap_or(0);
+
+            if !PgNumeric::is_valid_digit(digit) {
+                return Err(
+                    format!("PgNumeric to BigInt: {i}th digit is out of range {digit}").into(),
+                );
+            }
+
+            // both halves are less than 100, so they fit
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            cents.extend([(digit / 100) as u8, (digit % 100) as u8]);
+        }
+
+        let sign = match sign {
+            PgNumericSign::Positive => Sign::Plus,
+            PgNumericSign::Negative => Sign::Minus,
+        };
+
+        // an empty slice is zero
+        BigInt::from_radix_be(sign, &cents, 100)
+            .ok_or_else(|| "PgNumeric contained an out-of-range digit".into())
+    }
+}
+
+impl TryFrom<&'_ BigInt> for PgNumeric {
+    type Error = BoxDynError;
+
+    fn try_from(int: &'_ BigInt) -> Result<Self, BoxDynError> {
+        let (sign, mut cents) = int.to_radix_le(100);
+
+        // pair up base-100 digits into base-10000 ones, least significant first
+        if cents.len() % 2 != 0 {
+            cents.push(0);
+        }
+
+        let mut digits: Vec<i16> = cents
+            .chunks(2)
+            .map(|pair| i16::from(pair[1]) * 100 + i16::from(pair[0]))
+            .collect();
+
+        while let Some(&0) = digits.last() {
+            digits.pop();
+        }
+
+        let weight = i16::try_from(digits.len().saturating_sub(1)).map_err(|_| {
+            format!(
+                "BigInt is out of range for NUMERIC: {} digits",
+                cents.len() * 2
+            )
+        })?;
+
+        // Postgres strips trailing zero digits, which `weight` already accounts for
+        let trailing_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..trailing_zeros);
+        digits.reverse();
+
+        Ok(PgNumeric::Number {
+            sign: match sign {
+                Sign::Minus => PgNumericSign::Negative,
+                Sign::NoSign | Sign::Plus => PgNumericSign::Positive,
+            },
+            digits,
+            weight,
+            scale: 0,
+        })
+    }
+}
+
+impl Encode<'_, Postgres> for BigInt {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgNumeric::try_from(self)?.encode(buf)?;
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        PgNumeric::size_hint(self.bits() * 3 / 10 + 1)
+    }
+}
+
+impl Encode<'_, Postgres> for BigUint {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgNumeric::try_from(&BigInt::from(self.clone()))?.encode(buf)?;
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        PgNumeric::size_hint(self.bits() * 3 / 10 + 1)
+    }
+}
+
+impl Decode<'_, Postgres> for BigInt {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => BigInt::try_from(&PgNumeric::decode(value.as_bytes()?)?),
+            PgValueFormat::Text => {
+                let text = value.as_str()?;
+                let (int, fract) = text.split_once('.').unwrap_or((text, ""));
+
+                if fract.bytes().any(|b| b != b'0') {
+                    return Err(format!(
+                        "NUMERIC value {text} has a fractional part and cannot be decoded as an integer"
+                    )
+                    .into());
+                }
+
+                Ok(int.parse()?)
+            }
+        }
+    }
+}
+
+impl Decode<'_, Postgres> for BigUint {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let int = BigInt::decode(value)?;
+
+        int.to_biguint()
+            .ok_or_else(|| format!("NUMERIC value out of range for BigUint: {int}").into())
+    }
+}
+
+#[test]
+fn test_bigint_to_pgnumeric_and_back() {
+    let cases = [
+        "0",
+        "1",
+        "-1",
+        "9999",
+        "10000",
+        "-10000",
+        "123456789",
+        "100000000000000000000",
+        "-340282366920938463463374607431768211457",
+        "12345678901234567890123456789012345678901234567890",
+    ];
+
+    for case in cases {
+        let int: BigInt = case.parse().unwrap();
+        let numeric = PgNumeric::try_from(&int).unwrap();
+
+        assert_eq!(BigInt::try_from(&numeric).unwrap(), int, "{case}");
+        // `PgNumeric`'s `Display` formats the value the way Postgres does
+        assert_eq!(numeric.to_string(), case);
+    }
+
+    // 10^8 is a single base-10000 digit with trailing zero digits stripped
+    assert_eq!(
+        PgNumeric::try_from(&BigInt::from(100_000_000)).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![1],
+            weight: 2,
+            scale: 0,
+        }
+    );
+}
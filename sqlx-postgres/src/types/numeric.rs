@@ -1,4 +1,5 @@
 use sqlx_core::bytes::Buf;
+use std::fmt::{self, Write};
 use std::num::Saturating;
 
 use crate::error::BoxDynError;
@@ -126,6 +127,103 @@ impl PgNumeric {
         }
     }
 
+    /// Build the `NUMERIC` value of an integer, given as its sign and magnitude.
+    pub(crate) fn from_integer(sign: PgNumericSign, mut magnitude: u128) -> Self {
+        let mut digits = Vec::new();
+
+        // Convert to base-10000, least significant digit first.
+        while magnitude > 0 {
+            // Always less than 10000, so it fits.
+            #[allow(clippy::cast_possible_truncation)]
+            digits.push((magnitude % 10_000) as i16);
+            magnitude /= 10_000;
+        }
+
+        // At most 10 digits for a `u128`, so this fits too.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let weight = digits.len().saturating_sub(1) as i16;
+
+        // Postgres strips trailing zero digits, which `weight` already accounts for.
+        let trailing_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..trailing_zeros);
+        digits.reverse();
+
+        PgNumeric::Number {
+            sign,
+            digits,
+            weight,
+            scale: 0,
+        }
+    }
+
+    /// Convert an integral `NUMERIC` value (e.g. the result of `SUM(bigint)`) to `i128`.
+    ///
+    /// ### Errors
+    ///
+    /// * If the value is `NaN`
+    /// * If the value has a nonzero fractional part
+    /// * If the value does not fit in `i128`
+    pub(crate) fn try_to_i128(&self) -> Result<i128, BoxDynError> {
+        let (sign, magnitude) = self.integral_magnitude()?;
+
+        match sign {
+            PgNumericSign::Positive => i128::try_from(magnitude).map_err(Into::into),
+            PgNumericSign::Negative => 0_i128
+                .checked_sub_unsigned(magnitude)
+                .ok_or_else(|| format!("NUMERIC value out of range for i128: -{magnitude}").into()),
+        }
+    }
+
+    /// Convert an integral, non-negative `NUMERIC` value to `u128`.
+    ///
+    /// ### Errors
+    ///
+    /// * If the value is `NaN`
+    /// * If the value has a nonzero fractional part
+    /// * If the value is negative or does not fit in `u128`
+    pub(crate) fn try_to_u128(&self) -> Result<u128, BoxDynError> {
+        match self.integral_magnitude()? {
+            (PgNumericSign::Negative, magnitude) if magnitude != 0 => {
+                Err(format!("NUMERIC value out of range for u128: -{magnitude}").into())
+            }
+            (_, magnitude) => Ok(magnitude),
+        }
+    }
+
+    fn integral_magnitude(&self) -> Result<(PgNumericSign, u128), BoxDynError> {
+        let PgNumeric::Number {
+            sign,
+            ref digits,
+            weight,
+            ..
+        } = *self
+        else {
+            return Err("cannot convert NUMERIC NaN to an integer".into());
+        };
+
+        // digits at indices `0..=weight` are before the decimal point
+        let int_digits = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+
+        if digits.iter().skip(int_digits).any(|&digit| digit != 0) {
+            return Err(
+                "NUMERIC value has a fractional part and cannot be decoded as an integer".into(),
+            );
+        }
+
+        let mut magnitude = 0_u128;
+
+        for i in 0..int_digits {
+            let digit = u128::try_from(digits.get(i).copied().unwrap_or(0))?;
+
+            magnitude = magnitude
+                .checked_mul(10_000)
+                .and_then(|magnitude| magnitude.checked_add(digit))
+                .ok_or("NUMERIC value out of range for a 128-bit integer")?;
+        }
+
+        Ok((sign, magnitude))
+    }
+
     /// ### Errors
     ///
     /// * If `digits.len()` overflows `i16`
@@ -170,3 +268,60 @@ impl PgNumeric {
         Ok(())
     }
 }
+
+/// Formats the value the way Postgres does in text mode, e.g. `-12.3400` or `NaN`.
+impl fmt::Display for PgNumeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let PgNumeric::Number {
+            sign,
+            ref digits,
+            weight,
+            scale,
+        } = *self
+        else {
+            return f.write_str("NaN");
+        };
+
+        // digits outside of `digits` are implied zeroes
+        let digit = |i: i32| {
+            usize::try_from(i)
+                .ok()
+                .and_then(|i| digits.get(i))
+                .copied()
+                .unwrap_or(0)
+        };
+
+        if sign == PgNumericSign::Negative {
+            f.write_char('-')?;
+        }
+
+        // digits at indices `0..=weight` are before the decimal point
+        if weight < 0 {
+            f.write_char('0')?;
+        } else {
+            write!(f, "{}", digit(0))?;
+
+            for i in 1..=i32::from(weight) {
+                write!(f, "{:04}", digit(i))?;
+            }
+        }
+
+        let scale = usize::try_from(scale).unwrap_or(0);
+
+        if scale > 0 {
+            let mut fraction = String::with_capacity(scale + 4);
+            let mut i = i32::from(weight) + 1;
+
+            while fraction.len() < scale {
+                write!(fraction, "{:04}", digit(i))?;
+                i += 1;
+            }
+
+            fraction.truncate(scale);
+
+            write!(f, ".{fraction}")?;
+        }
+
+        Ok(())
+    }
+}
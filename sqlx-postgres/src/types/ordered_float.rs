@@ -0,0 +1,44 @@
+use ordered_float::OrderedFloat;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+
+// `OrderedFloat` is encoded and decoded exactly like the float it wraps
+
+macro_rules! impl_ordered_float {
+    ($float:ty) => {
+        impl Type<Postgres> for OrderedFloat<$float> {
+            fn type_info() -> PgTypeInfo {
+                <$float as Type<Postgres>>::type_info()
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                <$float as Type<Postgres>>::compatible(ty)
+            }
+        }
+
+        impl PgHasArrayType for OrderedFloat<$float> {
+            fn array_type_info() -> PgTypeInfo {
+                <$float as PgHasArrayType>::array_type_info()
+            }
+        }
+
+        impl Encode<'_, Postgres> for OrderedFloat<$float> {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                <$float as Encode<Postgres>>::encode_by_ref(&self.0, buf)
+            }
+        }
+
+        impl Decode<'_, Postgres> for OrderedFloat<$float> {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                <$float as Decode<Postgres>>::decode(value).map(OrderedFloat)
+            }
+        }
+    };
+}
+
+impl_ordered_float!(f32);
+impl_ordered_float!(f64);
@@ -8,6 +8,8 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::type_info::PgTypeKind;
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::types::PgInterval;
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 
@@ -112,6 +114,168 @@ impl<T> RangeBounds<T> for PgRange<T> {
     }
 }
 
+impl<T> PgRange<T> {
+    // Rewrites both bounds into the `[)` form Postgres stores discrete ranges in.
+    //
+    // A bound whose neighbouring value would overflow is left as-is; Postgres rejects
+    // such a range with an "out of range" error instead.
+    fn normalize_discrete(self, next: impl Fn(&T) -> Option<T>) -> Self {
+        let start = match self.start {
+            Bound::Excluded(v) => next(&v).map_or(Bound::Excluded(v), Bound::Included),
+            bound => bound,
+        };
+
+        let end = match self.end {
+            Bound::Included(v) => next(&v).map_or(Bound::Included(v), Bound::Excluded),
+            bound => bound,
+        };
+
+        PgRange { start, end }
+    }
+}
+
+impl PgRange<i32> {
+    /// Converts this range into the canonical form of `int4range`: an inclusive lower bound
+    /// and an exclusive upper bound.
+    ///
+    /// This is the form the server returns an `int4range` in, so a normalized range compares
+    /// equal to the same range read back from the database.
+    ///
+    /// ```rust
+    /// # use sqlx::postgres::types::PgRange;
+    /// use std::ops::Bound;
+    ///
+    /// let range = PgRange::from((Bound::Excluded(1_i32), Bound::Included(5)));
+    ///
+    /// assert_eq!(range.normalize(), PgRange::from(2..6));
+    /// ```
+    pub fn normalize(self) -> Self {
+        self.normalize_discrete(|v| v.checked_add(1))
+    }
+}
+
+impl PgRange<i64> {
+    /// Converts this range into the canonical form of `int8range`: an inclusive lower bound
+    /// and an exclusive upper bound.
+    ///
+    /// See the `int4range` version of `normalize()` for details.
+    pub fn normalize(self) -> Self {
+        self.normalize_discrete(|v| v.checked_add(1))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PgRange<chrono::NaiveDate> {
+    /// Converts this range into the canonical form of `daterange`: an inclusive lower bound
+    /// and an exclusive upper bound.
+    ///
+    /// See the `int4range` version of `normalize()` for details.
+    pub fn normalize(self) -> Self {
+        self.normalize_discrete(chrono::NaiveDate::succ_opt)
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgRange<time::Date> {
+    /// Converts this range into the canonical form of `daterange`: an inclusive lower bound
+    /// and an exclusive upper bound.
+    ///
+    /// See the `int4range` version of `normalize()` for details.
+    pub fn normalize(self) -> Self {
+        self.normalize_discrete(|v| v.next_day())
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<T> PgRange<T> {
+    // The width of a range of timestamps, given the microseconds from one bound to the other.
+    //
+    // Like subtracting timestamps in Postgres, whole days are carried into `days`.
+    fn timestamp_width(
+        &self,
+        micros_between: impl Fn(&T, &T) -> Option<i64>,
+    ) -> Option<PgInterval> {
+        let (Bound::Included(start) | Bound::Excluded(start)) = &self.start else {
+            return None;
+        };
+
+        let (Bound::Included(end) | Bound::Excluded(end)) = &self.end else {
+            return None;
+        };
+
+        let microseconds = micros_between(start, end)?;
+
+        Some(PgInterval {
+            months: 0,
+            days: i32::try_from(microseconds / MICROS_PER_DAY).ok()?,
+            microseconds: microseconds % MICROS_PER_DAY,
+        })
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+const MICROS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+
+#[cfg(feature = "chrono")]
+impl PgRange<chrono::NaiveDateTime> {
+    /// The width of this `tsrange`, from its lower to its upper bound, the same as
+    /// `upper(range) - lower(range)` in Postgres.
+    ///
+    /// Returns `None` if either bound is unbounded.
+    ///
+    /// ```rust
+    /// # use sqlx::postgres::types::{PgInterval, PgRange};
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(6, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     PgRange::from(start..end).width(),
+    ///     Some(PgInterval { months: 0, days: 1, microseconds: 6 * 60 * 60 * 1_000_000 })
+    /// );
+    /// assert_eq!(PgRange::from(start..).width(), None);
+    /// ```
+    pub fn width(&self) -> Option<PgInterval> {
+        self.timestamp_width(|start, end| end.signed_duration_since(*start).num_microseconds())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> PgRange<chrono::DateTime<Tz>> {
+    /// The width of this `tstzrange`, from its lower to its upper bound, the same as
+    /// `upper(range) - lower(range)` in Postgres.
+    ///
+    /// Returns `None` if either bound is unbounded.
+    pub fn width(&self) -> Option<PgInterval> {
+        self.timestamp_width(|start, end| {
+            end.clone().signed_duration_since(start).num_microseconds()
+        })
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgRange<time::PrimitiveDateTime> {
+    /// The width of this `tsrange`, from its lower to its upper bound, the same as
+    /// `upper(range) - lower(range)` in Postgres.
+    ///
+    /// Returns `None` if either bound is unbounded.
+    pub fn width(&self) -> Option<PgInterval> {
+        self.timestamp_width(|start, end| i64::try_from((*end - *start).whole_microseconds()).ok())
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgRange<time::OffsetDateTime> {
+    /// The width of this `tstzrange`, from its lower to its upper bound, the same as
+    /// `upper(range) - lower(range)` in Postgres.
+    ///
+    /// Returns `None` if either bound is unbounded.
+    pub fn width(&self) -> Option<PgInterval> {
+        self.timestamp_width(|start, end| i64::try_from((*end - *start).whole_microseconds()).ok())
+    }
+}
+
 impl Type<Postgres> for PgRange<i32> {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INT4_RANGE
@@ -349,8 +513,7 @@ where
                 }
 
                 if !flags.contains(RangeFlags::LB_INF) {
-                    let value =
-                        T::decode(PgValueRef::get(&mut buf, value.format, element_ty.clone())?)?;
+                    let value = T::decode(value.get_nested(&mut buf, element_ty.clone())?)?;
 
                     start = if flags.contains(RangeFlags::LB_INC) {
                         Bound::Included(value)
@@ -360,8 +523,7 @@ where
                 }
 
                 if !flags.contains(RangeFlags::UB_INF) {
-                    let value =
-                        T::decode(PgValueRef::get(&mut buf, value.format, element_ty.clone())?)?;
+                    let value = T::decode(value.get_nested(&mut buf, element_ty.clone())?)?;
 
                     end = if flags.contains(RangeFlags::UB_INC) {
                         Bound::Included(value)
@@ -393,15 +555,18 @@ where
 
                 let mut element = String::new();
                 let mut done = false;
-                let mut quoted = false;
                 let mut in_quotes = false;
                 let mut in_escape = false;
-                let mut prev_ch = '\0';
                 let mut count = 0;
 
                 while !done {
                     element.clear();
 
+                    // both bounds may be quoted, e.g. `["2020-01-01 00:00:00+00","2021-01-01 00:00:00+00")`,
+                    // so the state of the previous bound must not leak into this one
+                    let mut quoted = false;
+                    let mut prev_ch = '\0';
+
                     loop {
                         match chars.next() {
                             Some(ch) => {
@@ -451,6 +616,8 @@ where
                             format: PgValueFormat::Text,
                             value: Some(element.as_bytes()),
                             row: None,
+                            #[cfg(feature = "chrono-tz")]
+                            time_zone: value.time_zone,
                         })?);
 
                         if count == 1 {
@@ -520,3 +687,75 @@ fn range_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_discrete() {
+        let range = PgRange::from((Bound::Excluded(1_i32), Bound::Included(5)));
+        assert_eq!(range.normalize(), PgRange::from(2..6));
+
+        let range = PgRange::from((Bound::Excluded(1_i64), Bound::Unbounded));
+        assert_eq!(range.normalize(), PgRange::from(2_i64..));
+
+        let range = PgRange::from((Bound::Unbounded, Bound::Included(i32::MAX)));
+        assert_eq!(range.normalize(), PgRange::from(..=i32::MAX));
+
+        let range = PgRange::from(2_i32..6);
+        assert_eq!(range.normalize(), range);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamp_width() {
+        use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+        fn ts(day: u32, hour: u32, min: u32) -> NaiveDateTime {
+            NaiveDate::from_ymd_opt(2024, 2, day)
+                .unwrap()
+                .and_hms_opt(hour, min, 0)
+                .unwrap()
+        }
+
+        let range = PgRange::from(ts(27, 12, 0)..ts(29, 13, 30));
+        assert_eq!(
+            range.width(),
+            Some(PgInterval {
+                months: 0,
+                days: 2,
+                microseconds: 90 * 60 * 1_000_000,
+            })
+        );
+
+        // the inclusivity of the bounds does not matter
+        let range = PgRange::from(ts(1, 0, 0)..=ts(1, 0, 1));
+        assert_eq!(
+            range.width(),
+            Some(PgInterval {
+                months: 0,
+                days: 0,
+                microseconds: 60 * 1_000_000,
+            })
+        );
+
+        assert_eq!(PgRange::from(ts(1, 0, 0)..).width(), None);
+        assert_eq!(PgRange::from(..ts(1, 0, 0)).width(), None);
+
+        let range =
+            PgRange::from(Utc.from_utc_datetime(&ts(1, 0, 0))..Utc.from_utc_datetime(&ts(8, 0, 0)));
+        assert_eq!(
+            range.width(),
+            Some(PgInterval {
+                months: 0,
+                days: 7,
+                microseconds: 0,
+            })
+        );
+        assert_eq!(
+            PgRange::<chrono::DateTime<Utc>>::from((Bound::Unbounded, Bound::Unbounded)).width(),
+            None
+        );
+    }
+}
@@ -1,13 +1,18 @@
-use sqlx_core::bytes::Buf;
+use std::fmt::{self, Debug, Formatter};
+#[cfg(feature = "chrono-tz")]
+use std::sync::Arc;
+
+use sqlx_core::bytes::{Buf, Bytes};
 
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::error::{mismatched_types, BoxDynError};
+use crate::error::{mismatched_types, BoxDynError, Error};
 use crate::type_info::TypeInfo;
 use crate::type_info::{PgType, PgTypeKind};
 use crate::types::Oid;
 use crate::types::Type;
-use crate::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::value::{Value, ValueRef};
+use crate::{PgArgumentBuffer, PgTypeInfo, PgValue, PgValueFormat, PgValueRef, Postgres};
 
 #[doc(hidden)]
 pub struct PgRecordEncoder<'a> {
@@ -63,6 +68,8 @@ pub struct PgRecordDecoder<'r> {
     typ: PgTypeInfo,
     fmt: PgValueFormat,
     ind: usize,
+    #[cfg(feature = "chrono-tz")]
+    time_zone: Option<&'r Arc<str>>,
 }
 
 impl<'r> PgRecordDecoder<'r> {
@@ -70,6 +77,8 @@ impl<'r> PgRecordDecoder<'r> {
     pub fn new(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let fmt = value.format();
         let mut buf = value.as_bytes()?;
+        #[cfg(feature = "chrono-tz")]
+        let time_zone = value.time_zone;
         let typ = value.type_info;
 
         match fmt {
@@ -88,6 +97,8 @@ impl<'r> PgRecordDecoder<'r> {
             fmt,
             typ,
             ind: 0,
+            #[cfg(feature = "chrono-tz")]
+            time_zone,
         })
     }
 
@@ -137,56 +148,20 @@ impl<'r> PgRecordDecoder<'r> {
 
                 self.ind += 1;
 
-                T::decode(PgValueRef::get(&mut self.buf, self.fmt, element_type)?)
-            }
-
-            PgValueFormat::Text => {
-                let mut element = String::new();
-                let mut quoted = false;
-                let mut in_quotes = false;
-                let mut in_escape = false;
-                let mut prev_ch = '\0';
-
-                while !self.buf.is_empty() {
-                    let ch = self.buf.get_u8() as char;
-                    match ch {
-                        _ if in_escape => {
-                            element.push(ch);
-                            in_escape = false;
-                        }
-
-                        '"' if in_quotes => {
-                            in_quotes = false;
-                        }
-
-                        '"' => {
-                            in_quotes = true;
-                            quoted = true;
-
-                            if prev_ch == '"' {
-                                element.push('"')
-                            }
-                        }
-
-                        '\\' if !in_escape => {
-                            in_escape = true;
-                        }
-
-                        ',' if !in_quotes => break,
+                #[allow(unused_mut)]
+                let mut element = PgValueRef::get(&mut self.buf, self.fmt, element_type)?;
 
-                        _ => {
-                            element.push(ch);
-                        }
-                    }
-                    prev_ch = ch;
+                #[cfg(feature = "chrono-tz")]
+                {
+                    element.time_zone = self.time_zone;
                 }
 
-                let buf = if element.is_empty() && !quoted {
-                    // completely empty input means NULL
-                    None
-                } else {
-                    Some(element.as_bytes())
-                };
+                T::decode(element)
+            }
+
+            PgValueFormat::Text => {
+                let element = next_text_field(&mut self.buf);
+                let buf = element.as_deref().map(str::as_bytes);
 
                 // NOTE: we do not call [`accepts`] or give a chance to from a user as
                 //       TEXT sequences are not strongly typed
@@ -198,8 +173,252 @@ impl<'r> PgRecordDecoder<'r> {
                     format: self.fmt,
                     value: buf,
                     row: None,
+                    #[cfg(feature = "chrono-tz")]
+                    time_zone: self.time_zone,
                 })
             }
         }
     }
 }
+
+// Read the next field of a record in the text format, or `None` if it is NULL.
+fn next_text_field(buf: &mut &[u8]) -> Option<String> {
+    let mut element = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut in_escape = false;
+    let mut prev_ch = '\0';
+
+    while !buf.is_empty() {
+        let ch = buf.get_u8() as char;
+        match ch {
+            _ if in_escape => {
+                element.push(ch);
+                in_escape = false;
+            }
+
+            '"' if in_quotes => {
+                in_quotes = false;
+            }
+
+            '"' => {
+                in_quotes = true;
+                quoted = true;
+
+                if prev_ch == '"' {
+                    element.push('"')
+                }
+            }
+
+            '\\' if !in_escape => {
+                in_escape = true;
+            }
+
+            ',' if !in_quotes => break,
+
+            _ => {
+                element.push(ch);
+            }
+        }
+        prev_ch = ch;
+    }
+
+    if element.is_empty() && !quoted {
+        // completely empty input means NULL
+        None
+    } else {
+        Some(element)
+    }
+}
+
+/// A composite value (or anonymous record) whose fields are accessed by name.
+///
+/// Unlike decoding into a tuple, which reads fields by position, the field names are resolved
+/// from the type information of the composite type, which is friendlier for wide types.
+/// The fields of an anonymous record, such as `ROW(1, 'foo')`, are named `f1`, `f2` and so on,
+/// as they are by Postgres.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use sqlx::Connection;
+/// # use sqlx::postgres::{PgConnection, types::PgRecord};
+/// #
+/// # sqlx::__rt::test_block_on(async move {
+/// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+/// // CREATE TYPE inventory_item AS (name TEXT, supplier_id INT, price BIGINT);
+/// let item: PgRecord =
+///     sqlx::query_scalar("SELECT ROW('fuzzy dice', 42, 199)::inventory_item")
+///         .fetch_one(&mut conn)
+///         .await?;
+///
+/// let name: String = item.get("name");
+/// let price: Option<i64> = item.try_get("price")?;
+/// # Result::<(), sqlx::Error>::Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct PgRecord {
+    fields: Vec<(String, PgValue)>,
+}
+
+impl PgRecord {
+    /// The number of fields in the record.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Whether the record has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// The names of the fields, in order.
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(name, _)| &**name)
+    }
+
+    /// The value of the field `name`, without decoding it.
+    pub fn try_get_raw(&self, name: &str) -> Result<PgValueRef<'_>, Error> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_ref())
+            .ok_or_else(|| Error::ColumnNotFound(name.into()))
+    }
+
+    /// Decode the field `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field does not exist or cannot be decoded as `T`.
+    /// See [`try_get`](Self::try_get) for a non-panicking version.
+    pub fn get<'r, T>(&'r self, name: &str) -> T
+    where
+        T: Decode<'r, Postgres> + Type<Postgres>,
+    {
+        self.try_get(name).unwrap()
+    }
+
+    /// Decode the field `name`.
+    ///
+    /// # Errors
+    ///
+    ///  * [`ColumnNotFound`](Error::ColumnNotFound) if the record has no such field.
+    ///  * [`ColumnDecode`](Error::ColumnDecode) if the value could not be decoded as `T`.
+    pub fn try_get<'r, T>(&'r self, name: &str) -> Result<T, Error>
+    where
+        T: Decode<'r, Postgres> + Type<Postgres>,
+    {
+        let value = self.try_get_raw(name)?;
+
+        // NOTE: fields in the text format are not strongly typed, see `PgRecordDecoder`
+        if !value.is_null() && value.format == PgValueFormat::Binary {
+            let ty = value.type_info.clone();
+
+            if !T::compatible(&ty) {
+                return Err(Error::ColumnDecode {
+                    index: format!("{name:?}"),
+                    source: mismatched_types::<Postgres, T>(&ty),
+                });
+            }
+        }
+
+        T::decode(value).map_err(|source| Error::ColumnDecode {
+            index: format!("{name:?}"),
+            source,
+        })
+    }
+}
+
+impl Debug for PgRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.fields
+                    .iter()
+                    .map(|(name, value)| (name, value.type_info.name())),
+            )
+            .finish()
+    }
+}
+
+impl Type<Postgres> for PgRecord {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::RECORD
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        ty.0 == PgType::Record || matches!(ty.kind(), PgTypeKind::Composite(_))
+    }
+}
+
+impl Decode<'_, Postgres> for PgRecord {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let format = value.format();
+        let declared = match value.type_info.kind() {
+            PgTypeKind::Composite(fields) => Some(fields.clone()),
+            _ => None,
+        };
+
+        let field = |ind: usize, oid: Option<Oid>| match &declared {
+            Some(fields) => fields
+                .get(ind)
+                .cloned()
+                .ok_or_else(|| BoxDynError::from(format!("unexpected field {ind} in record"))),
+
+            None => Ok((
+                format!("f{}", ind + 1),
+                oid.map_or(PgTypeInfo::UNKNOWN, |oid| {
+                    PgTypeInfo::try_from_oid(oid).unwrap_or_else(|| PgTypeInfo::with_oid(oid))
+                }),
+            )),
+        };
+
+        let mut buf = value.as_bytes()?;
+        let mut fields = Vec::new();
+
+        match format {
+            PgValueFormat::Binary => {
+                let num = buf.get_u32();
+
+                for ind in 0..num as usize {
+                    let oid = Oid(buf.get_u32());
+                    let (name, ty) = field(ind, Some(oid))?;
+
+                    if ty.0.oid() != oid {
+                        return Err("unexpected mismatch of composite type information".into());
+                    }
+
+                    let mut element = value.get_nested(&mut buf, ty)?;
+                    // share the row's buffer instead of copying the field out of it
+                    element.row = value.row;
+
+                    fields.push((name, ValueRef::to_owned(&element)));
+                }
+            }
+
+            PgValueFormat::Text => {
+                // remove the enclosing `(` .. `)`
+                buf = &buf[1..(buf.len() - 1)];
+
+                while !buf.is_empty() || fields.len() < declared.as_ref().map_or(0, |f| f.len()) {
+                    let (name, type_info) = field(fields.len(), None)?;
+
+                    fields.push((
+                        name,
+                        PgValue {
+                            value: next_text_field(&mut buf).map(Bytes::from),
+                            type_info,
+                            format,
+                            #[cfg(feature = "chrono-tz")]
+                            time_zone: value.time_zone.cloned(),
+                        },
+                    ));
+                }
+            }
+        }
+
+        Ok(PgRecord { fields })
+    }
+}
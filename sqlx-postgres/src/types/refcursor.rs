@@ -0,0 +1,111 @@
+use std::fmt::{self, Display, Formatter};
+
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::{BoxDynError, Error};
+use crate::executor::Executor;
+use crate::ident::ident;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgRow, PgTypeInfo, PgValueRef, Postgres};
+
+/// A cursor returned by a function declared `RETURNS refcursor` (or taking one as an argument).
+///
+/// The value is the name of a cursor opened by the function. The cursor only lives until the
+/// end of the transaction that opened it, so the function must be called, and the cursor
+/// fetched from, inside one transaction.
+///
+/// See [the Postgres manual, Section 41.7.3.5][PG.41.7.3.5] for details on returning cursors.
+///
+/// [PG.41.7.3.5]: https://www.postgresql.org/docs/current/plpgsql-cursors.html#PLPGSQL-CURSOR-RETURNING
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use futures_util::TryStreamExt;
+/// # use sqlx::{Connection, Row};
+/// # use sqlx::postgres::{PgConnection, types::PgRefCursor};
+/// #
+/// # sqlx::__rt::test_block_on(async move {
+/// # let mut conn = PgConnection::connect("postgres:// ...").await?;
+/// let mut tx = conn.begin().await?;
+///
+/// let cursor: PgRefCursor = sqlx::query_scalar("SELECT open_orders_cursor()")
+///     .fetch_one(&mut *tx)
+///     .await?;
+///
+/// let mut rows = cursor.fetch_all(&mut *tx);
+///
+/// while let Some(row) = rows.try_next().await? {
+///     let id: i64 = row.try_get("id")?;
+/// }
+/// # drop(rows);
+/// # tx.commit().await?;
+/// # Result::<(), sqlx::Error>::Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PgRefCursor(pub String);
+
+impl PgRefCursor {
+    /// The name of the cursor.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// Stream every remaining row of the cursor, using `FETCH ALL FROM <cursor>`.
+    ///
+    /// `executor` must be the transaction, or the connection inside it, that opened the cursor.
+    pub fn fetch_all<'c, E>(&self, executor: E) -> BoxStream<'c, Result<PgRow, Error>>
+    where
+        E: Executor<'c, Database = Postgres> + 'c,
+    {
+        let sql = ident(&self.0).map(|name| format!(r#"FETCH ALL FROM "{name}""#));
+
+        Box::pin(try_stream! {
+            let sql = sql?;
+            let mut rows = executor.fetch(&*sql);
+
+            while let Some(row) = rows.try_next().await? {
+                r#yield!(row);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl Display for PgRefCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgRefCursor {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::REFCURSOR
+    }
+}
+
+impl PgHasArrayType for PgRefCursor {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::REFCURSOR_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgRefCursor {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // the binary format is the name as text
+        buf.extend(self.0.as_bytes());
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for PgRefCursor {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Self(value.as_str()?.to_owned()))
+    }
+}
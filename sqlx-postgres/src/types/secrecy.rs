@@ -0,0 +1,38 @@
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+
+// Stored as its text, like `str` and `String`; the secret is only exposed to encode it,
+// and the argument is marked so that it is redacted when arguments are logged or printed
+
+impl Type<Postgres> for SecretString {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for SecretString {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.mark_secret();
+
+        <&str as Encode<Postgres>>::encode(self.expose_secret(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.expose_secret().len()
+    }
+}
+
+impl Decode<'_, Postgres> for SecretString {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(SecretString::from(value.as_str()?))
+    }
+}
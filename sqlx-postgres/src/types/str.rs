@@ -5,6 +5,7 @@ use crate::types::array_compatible;
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
 use std::borrow::Cow;
+use std::sync::Arc;
 
 impl Type<Postgres> for str {
     fn type_info() -> PgTypeInfo {
@@ -44,6 +45,16 @@ impl Type<Postgres> for Box<str> {
     }
 }
 
+impl Type<Postgres> for Arc<str> {
+    fn type_info() -> PgTypeInfo {
+        <&str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
 impl Type<Postgres> for String {
     fn type_info() -> PgTypeInfo {
         <&str as Type<Postgres>>::type_info()
@@ -84,6 +95,16 @@ impl PgHasArrayType for Box<str> {
     }
 }
 
+impl PgHasArrayType for Arc<str> {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
 impl PgHasArrayType for String {
     fn array_type_info() -> PgTypeInfo {
         <&str as PgHasArrayType>::array_type_info()
@@ -117,6 +138,12 @@ impl Encode<'_, Postgres> for Box<str> {
     }
 }
 
+impl Encode<'_, Postgres> for Arc<str> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<Postgres>>::encode(&**self, buf)
+    }
+}
+
 impl Encode<'_, Postgres> for String {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
         <&str as Encode<Postgres>>::encode(&**self, buf)
@@ -141,8 +168,59 @@ impl<'r> Decode<'r, Postgres> for Box<str> {
     }
 }
 
+// a single allocation that can then be shared between tasks without copying the text again
+impl Decode<'_, Postgres> for Arc<str> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Arc::from(value.as_str()?))
+    }
+}
+
 impl Decode<'_, Postgres> for String {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(value.as_str()?.to_owned())
     }
 }
+
+// `char` is decoded from either the single-byte `"char"` type or a `CHAR(1)` (`BPCHAR`)
+impl Type<Postgres> for char {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::CHAR
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        [PgTypeInfo::CHAR, PgTypeInfo::BPCHAR].contains(ty)
+    }
+}
+
+impl PgHasArrayType for char {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::CHAR_ARRAY
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<char>(ty)
+    }
+}
+
+impl Decode<'_, Postgres> for char {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info == PgTypeInfo::CHAR {
+            let [byte] = i8::decode(value)?.to_be_bytes();
+
+            // a `"char"` holds a single byte, which is only a whole character if it is ASCII
+            return if byte.is_ascii() {
+                Ok(char::from(byte))
+            } else {
+                Err(format!("\"char\" value {byte:#04x} is not an ASCII character").into())
+            };
+        }
+
+        let text = value.as_str()?;
+        let mut chars = text.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Ok(ch),
+            _ => Err(format!("expected a single character, got {text:?}").into()),
+        }
+    }
+}
@@ -90,7 +90,7 @@ impl<'r> Decode<'r, Postgres> for PrimitiveDateTime {
 
 impl Encode<'_, Postgres> for OffsetDateTime {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
-        let utc = self.to_offset(offset!(UTC));
+        let utc = PgSubMicros::Truncate.apply(*self)?.to_offset(offset!(UTC));
         let primitive = PrimitiveDateTime::new(utc.date(), utc.time());
 
         Encode::<Postgres>::encode(primitive, buf)
@@ -106,3 +106,106 @@ impl<'r> Decode<'r, Postgres> for OffsetDateTime {
         Ok(<PrimitiveDateTime as Decode<Postgres>>::decode(value)?.assume_utc())
     }
 }
+
+/// What to do with the nanoseconds of a timestamp that Postgres cannot store.
+///
+/// `TIMESTAMPTZ` has microsecond precision, while [`OffsetDateTime`] has nanosecond precision.
+/// Encoding an `OffsetDateTime` directly always truncates; wrap it in a [`PgOffsetDateTime`]
+/// to choose another policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PgSubMicros {
+    /// Drop the sub-microsecond part, e.g. `12:00:00.0000017` is sent as `12:00:00.000001`.
+    #[default]
+    Truncate,
+
+    /// Round to the nearest microsecond, rounding half up,
+    /// e.g. `12:00:00.0000017` is sent as `12:00:00.000002`.
+    Round,
+
+    /// Fail to encode a value that has a sub-microsecond part.
+    Error,
+}
+
+impl PgSubMicros {
+    fn apply(self, value: OffsetDateTime) -> Result<OffsetDateTime, BoxDynError> {
+        let nanos = value.nanosecond() % 1_000;
+
+        if nanos == 0 {
+            return Ok(value);
+        }
+
+        let nanos = Duration::nanoseconds(nanos.into());
+
+        let rounded = match self {
+            PgSubMicros::Truncate => value.checked_sub(nanos),
+
+            PgSubMicros::Round if nanos >= Duration::nanoseconds(500) => {
+                value.checked_add(Duration::MICROSECOND - nanos)
+            }
+
+            PgSubMicros::Round => value.checked_sub(nanos),
+
+            PgSubMicros::Error => {
+                return Err(format!(
+                    "value {value} has sub-microsecond precision that Postgres cannot store"
+                )
+                .into());
+            }
+        };
+
+        rounded.ok_or_else(|| format!("value {value} is out of range when rounded").into())
+    }
+}
+
+/// An [`OffsetDateTime`] that is encoded as `TIMESTAMPTZ` according to a [`PgSubMicros`] policy.
+///
+/// ```rust
+/// # use sqlx::postgres::types::{PgOffsetDateTime, PgSubMicros};
+/// use sqlx::types::time::OffsetDateTime;
+///
+/// // refuse to silently lose the nanoseconds
+/// let value = PgOffsetDateTime::new(OffsetDateTime::now_utc(), PgSubMicros::Error);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgOffsetDateTime {
+    pub value: OffsetDateTime,
+    pub policy: PgSubMicros,
+}
+
+impl PgOffsetDateTime {
+    pub fn new(value: OffsetDateTime, policy: PgSubMicros) -> Self {
+        Self { value, policy }
+    }
+}
+
+impl Type<Postgres> for PgOffsetDateTime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMPTZ
+    }
+}
+
+impl PgHasArrayType for PgOffsetDateTime {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMPTZ_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgOffsetDateTime {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        Encode::<Postgres>::encode(self.policy.apply(self.value)?, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgOffsetDateTime {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        // a decoded value never has a sub-microsecond part, so the policy has nothing to do
+        Ok(PgOffsetDateTime::new(
+            Decode::<Postgres>::decode(value)?,
+            PgSubMicros::default(),
+        ))
+    }
+}
@@ -7,6 +7,12 @@ use std::mem;
 use time::macros::format_description;
 use time::{Duration, Time};
 
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+// `24:00:00` is a valid TIME, but `time::Time` ends at `23:59:59.999999999`
+const END_OF_DAY_ERROR: &str =
+    "Postgres TIME 24:00:00 is out of range for time::Time, which ends at 23:59:59.999999999";
+
 impl Type<Postgres> for Time {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::TIME
@@ -39,15 +45,32 @@ impl<'r> Decode<'r, Postgres> for Time {
         Ok(match value.format() {
             PgValueFormat::Binary => {
                 // TIME is encoded as the microseconds since midnight
-                let us = Decode::<Postgres>::decode(value)?;
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                if us == MICROSECONDS_PER_DAY {
+                    return Err(END_OF_DAY_ERROR.into());
+                }
+
+                if !(0..MICROSECONDS_PER_DAY).contains(&us) {
+                    return Err(format!("Postgres TIME out of range for time: {us}us").into());
+                }
+
                 Time::MIDNIGHT + Duration::microseconds(us)
             }
 
-            PgValueFormat::Text => Time::parse(
-                value.as_str()?,
-                // Postgres will not include the subsecond part if it's zero.
-                &format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]"),
-            )?,
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                if s.starts_with("24:") {
+                    return Err(END_OF_DAY_ERROR.into());
+                }
+
+                Time::parse(
+                    s,
+                    // Postgres will not include the subsecond part if it's zero.
+                    &format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]"),
+                )?
+            }
         })
     }
 }
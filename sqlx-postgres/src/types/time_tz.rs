@@ -124,6 +124,7 @@ mod chrono {
 #[cfg(feature = "time")]
 mod time {
     use super::*;
+    use ::time::macros::format_description;
     use ::time::{Duration, Time, UtcOffset};
 
     impl Type<Postgres> for PgTimeTz<Time, UtcOffset> {
@@ -165,12 +166,47 @@ mod time {
                     })
                 }
 
-                PgValueFormat::Text => {
-                    // the `time` crate has a limited ability to parse and can't parse the
-                    // timezone format
-                    Err("reading a `TIMETZ` value in text format is not supported.".into())
-                }
+                PgValueFormat::Text => try_parse_timetz(value.as_str()?),
             }
         }
     }
+
+    fn try_parse_timetz(s: &str) -> Result<PgTimeTz<Time, UtcOffset>, BoxDynError> {
+        // `TIMETZ` is rendered as `HH:MM:SS[.ffffff]±HH[:MM[:SS]]`, and the
+        // offset is the only part of it that can contain a sign
+        let sign_at = s
+            .rfind(['+', '-'])
+            .ok_or_else(|| format!("`TIMETZ` value is missing its offset: {s:?}"))?;
+
+        let (time, offset) = s.split_at(sign_at);
+
+        let time = Time::parse(
+            time,
+            // Postgres will not include the subsecond part if it's zero.
+            &format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]"),
+        )?;
+
+        let mut seconds = 0;
+        let mut parts = offset[1..].split(':');
+
+        // hours are always present, minutes and seconds only if they are non-zero
+        for unit in [3600, 60, 1] {
+            if let Some(part) = parts.next() {
+                seconds += unit * part.parse::<i32>()?;
+            }
+        }
+
+        if parts.next().is_some() {
+            return Err(format!("`TIMETZ` value has an invalid offset: {s:?}").into());
+        }
+
+        if offset.starts_with('-') {
+            seconds = -seconds;
+        }
+
+        Ok(PgTimeTz {
+            time,
+            offset: UtcOffset::from_whole_seconds(seconds)?,
+        })
+    }
 }
@@ -1,8 +1,9 @@
 use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::types::PgRecordDecoder;
 use crate::types::Type;
-use crate::{PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::{PgRecordDecoder, PgRecordEncoder};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
 
 macro_rules! impl_type_for_tuple {
     ($( $idx:ident : $T:ident ),*) => {
@@ -41,6 +42,38 @@ macro_rules! impl_type_for_tuple {
                 Ok(($($idx,)*))
             }
         }
+
+        impl<$($T,)*> Encode<'_, Postgres> for ($($T,)*)
+        where
+            $($T: for<'e> Encode<'e, Postgres>,)*
+            $($T: Type<Postgres>,)*
+        {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                let ($($idx,)*) = self;
+
+                let mut encoder = PgRecordEncoder::new(buf);
+
+                $(encoder.encode($idx)?;)*
+
+                encoder.finish();
+
+                Ok(IsNull::No)
+            }
+
+            fn produces(&self) -> Option<PgTypeInfo> {
+                // Postgres cannot receive an anonymous `record`, so the parameter type is left
+                // for it to infer from the query, e.g. from the composite type of a function
+                // argument or a cast
+                Some(PgTypeInfo::UNSPECIFIED)
+            }
+
+            fn size_hint(&self) -> usize {
+                let ($($idx,)*) = self;
+
+                // the field count, then the OID and length of each field
+                4 $(+ 8 + $idx.size_hint())*
+            }
+        }
     };
 }
 
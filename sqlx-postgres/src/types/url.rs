@@ -0,0 +1,47 @@
+use url::Url;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+
+// URLs are stored as their text, like `str` and `String`
+
+impl Type<Postgres> for Url {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for Url {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Url {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<Postgres>>::encode(self.as_str(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+impl Decode<'_, Postgres> for Url {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let text = value.as_str()?;
+
+        Url::parse(text).map_err(|e| format!("invalid URL {text:?}: {e}").into())
+    }
+}
@@ -35,3 +35,63 @@ impl Decode<'_, Postgres> for Uuid {
         .map_err(Into::into)
     }
 }
+
+/// A [`Uuid`] stored in a `TEXT` or `VARCHAR` column, as a string such as
+/// `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+///
+/// `Uuid` itself is only compatible with the `UUID` type. This decodes any string that
+/// [`Uuid::parse_str()`] accepts, and encodes in the lowercase hyphenated form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgTextUuid(pub Uuid);
+
+impl From<Uuid> for PgTextUuid {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<PgTextUuid> for Uuid {
+    fn from(uuid: PgTextUuid) -> Self {
+        uuid.0
+    }
+}
+
+impl Type<Postgres> for PgTextUuid {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TEXT
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for PgTextUuid {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TEXT_ARRAY
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgTextUuid {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let mut encoded = Uuid::encode_buffer();
+        buf.extend_from_slice(self.0.hyphenated().encode_lower(&mut encoded).as_bytes());
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        uuid::fmt::Hyphenated::LENGTH
+    }
+}
+
+impl Decode<'_, Postgres> for PgTextUuid {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        // text is sent as-is in both formats
+        Ok(Self(value.as_str()?.parse()?))
+    }
+}
@@ -1,9 +1,15 @@
+use crate::decode::Decode;
 use crate::error::{BoxDynError, UnexpectedNullError};
+use crate::type_info::PgTypeKind;
+use crate::types::numeric::PgNumeric;
 use crate::{PgTypeInfo, Postgres};
+use serde_json::{Number as JsonNumber, Value as JsonValue};
 use sqlx_core::bytes::{Buf, Bytes};
 pub(crate) use sqlx_core::value::{Value, ValueRef};
 use std::borrow::Cow;
 use std::str::from_utf8;
+#[cfg(feature = "chrono-tz")]
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[repr(u8)]
@@ -19,6 +25,8 @@ pub struct PgValueRef<'r> {
     pub(crate) row: Option<&'r Bytes>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: PgValueFormat,
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) time_zone: Option<&'r Arc<str>>,
 }
 
 /// Implementation of [`Value`] for PostgreSQL.
@@ -27,6 +35,8 @@ pub struct PgValue {
     pub(crate) value: Option<Bytes>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: PgValueFormat,
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) time_zone: Option<Arc<str>>,
 }
 
 impl<'r> PgValueRef<'r> {
@@ -54,13 +64,56 @@ impl<'r> PgValueRef<'r> {
             row: None,
             type_info: ty,
             format,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: None,
         })
     }
 
+    // Reads an element nested in this value, e.g. of an array or range, from `buf`.
+    pub(crate) fn get_nested(&self, buf: &mut &'r [u8], ty: PgTypeInfo) -> Result<Self, String> {
+        #[allow(unused_mut)]
+        let mut element = Self::get(buf, self.format, ty)?;
+
+        #[cfg(feature = "chrono-tz")]
+        {
+            element.time_zone = self.time_zone;
+        }
+
+        Ok(element)
+    }
+
     pub fn format(&self) -> PgValueFormat {
         self.format
     }
 
+    /// The session `TimeZone` reported by the server when this value was received, if known.
+    #[cfg(feature = "chrono-tz")]
+    pub fn time_zone(&self) -> Option<&'r str> {
+        self.time_zone.map(|tz| &**tz)
+    }
+
+    /// Returns `true` if the value is `NULL`.
+    ///
+    /// This is the same as [`ValueRef::is_null()`], without having to import the trait.
+    pub fn is_null(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Decode this value as `T`, or return `default` if it is `NULL`.
+    ///
+    /// This is convenient in a [`Decode`] implementation for a type that should have a value
+    /// even when the column is `NULL`.
+    pub fn decode_or<T>(self, default: T) -> Result<T, BoxDynError>
+    where
+        T: Decode<'r, Postgres>,
+    {
+        if self.is_null() {
+            return Ok(default);
+        }
+
+        T::decode(self)
+    }
+
     pub fn as_bytes(&self) -> Result<&'r [u8], BoxDynError> {
         match &self.value {
             Some(v) => Ok(v),
@@ -71,6 +124,97 @@ impl<'r> PgValueRef<'r> {
     pub fn as_str(&self) -> Result<&'r str, BoxDynError> {
         Ok(from_utf8(self.as_bytes()?)?)
     }
+
+    /// Decode this value as JSON, falling back to its text representation for types
+    /// that JSON has no counterpart for.
+    ///
+    /// `JSON` and `JSONB` values are decoded as they are, `BOOL` as a boolean and the
+    /// integer and floating-point types as numbers. Any other scalar, such as a `JSONPATH`
+    /// or a `NUMERIC`, becomes a [`JsonValue::String`] of the text Postgres would show
+    /// for it, so that tools handling arbitrary queries can pass along values of types
+    /// they know nothing about.
+    ///
+    /// Values sent in the text format, as with the simple query protocol, always have a
+    /// text representation. Values sent in the binary format only have one for `NUMERIC`
+    /// and for types whose binary format is their text: the character types, `JSONPATH`,
+    /// `CITEXT` and enums. Any other binary value is an error, as is an array.
+    pub fn to_json_lenient(&self) -> Result<JsonValue, BoxDynError> {
+        if self.value.is_none() {
+            return Ok(JsonValue::Null);
+        }
+
+        let ty = &self.type_info;
+
+        if *ty == PgTypeInfo::JSON || *ty == PgTypeInfo::JSONB {
+            return <JsonValue as Decode<Postgres>>::decode(self.clone());
+        }
+
+        if *ty == PgTypeInfo::BOOL {
+            return Ok(JsonValue::Bool(<bool as Decode<Postgres>>::decode(
+                self.clone(),
+            )?));
+        }
+
+        if *ty == PgTypeInfo::INT2 || *ty == PgTypeInfo::INT4 || *ty == PgTypeInfo::INT8 {
+            return Ok(JsonValue::from(<i64 as Decode<Postgres>>::decode(
+                self.clone(),
+            )?));
+        }
+
+        if *ty == PgTypeInfo::FLOAT4 || *ty == PgTypeInfo::FLOAT8 {
+            let float = <f64 as Decode<Postgres>>::decode(self.clone())?;
+
+            // `NaN` and the infinities are not JSON numbers
+            return Ok(JsonNumber::from_f64(float)
+                .map_or_else(|| JsonValue::String(float.to_string()), JsonValue::Number));
+        }
+
+        if matches!(ty.kind(), PgTypeKind::Array(_)) {
+            return Err(format!("cannot decode {} as a JSON scalar", ty.name()).into());
+        }
+
+        let text = match self.format {
+            PgValueFormat::Text => self.as_str()?.to_owned(),
+
+            PgValueFormat::Binary if *ty == PgTypeInfo::NUMERIC => {
+                PgNumeric::decode(self.as_bytes()?)?.to_string()
+            }
+
+            PgValueFormat::Binary if *ty == PgTypeInfo::JSONPATH => {
+                // the binary format of `JSONPATH` is a version byte followed by the text
+                match self.as_bytes()? {
+                    [1, text @ ..] => from_utf8(text)?.to_owned(),
+                    _ => return Err("unsupported JSONPATH format version".into()),
+                }
+            }
+
+            PgValueFormat::Binary if is_text_in_binary(ty) => self.as_str()?.to_owned(),
+
+            PgValueFormat::Binary => {
+                return Err(format!(
+                    "no text representation of binary {} values to decode as JSON",
+                    ty.name()
+                )
+                .into());
+            }
+        };
+
+        Ok(JsonValue::String(text))
+    }
+}
+
+// Types whose binary format is the same as their text format.
+fn is_text_in_binary(ty: &PgTypeInfo) -> bool {
+    matches!(ty.kind(), PgTypeKind::Enum(_))
+        || [
+            PgTypeInfo::TEXT,
+            PgTypeInfo::NAME,
+            PgTypeInfo::BPCHAR,
+            PgTypeInfo::VARCHAR,
+            PgTypeInfo::UNKNOWN,
+            PgTypeInfo::with_name("citext"),
+        ]
+        .contains(ty)
 }
 
 impl Value for PgValue {
@@ -83,6 +227,8 @@ impl Value for PgValue {
             row: None,
             type_info: self.type_info.clone(),
             format: self.format,
+            #[cfg(feature = "chrono-tz")]
+            time_zone: self.time_zone.as_ref(),
         }
     }
 
@@ -111,6 +257,8 @@ impl<'r> ValueRef<'r> for PgValueRef<'r> {
             value,
             format: self.format,
             type_info: self.type_info.clone(),
+            #[cfg(feature = "chrono-tz")]
+            time_zone: self.time_zone.cloned(),
         }
     }
 
@@ -204,6 +204,48 @@ async fn test_pool_callbacks() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn pool_reports_connection_metrics() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(2)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // hold two connections at once so that both are opened
+    let mut conns = vec![pool.acquire().await?, pool.acquire().await?];
+    for conn in &mut conns {
+        sqlx::query("SELECT 1").execute(&mut **conn).await?;
+    }
+    for mut conn in conns {
+        conn.return_to_pool().await;
+    }
+
+    let mut conn = pool.acquire().await?;
+    sqlx::query("SELECT 1").execute(&mut *conn).await?;
+    conn.return_to_pool().await;
+
+    let mut metrics = pool.connection_metrics();
+    assert_eq!(metrics.len(), 2);
+
+    metrics.sort_by_key(|metrics| metrics.times_acquired);
+    assert_eq!(metrics[0].times_acquired, 1);
+    assert_eq!(metrics[1].times_acquired, 2);
+
+    for metrics in &metrics {
+        assert!(metrics.age > Duration::ZERO);
+        assert!(metrics.age >= metrics.idle_for);
+    }
+
+    // the connections are still idle after being inspected
+    assert_eq!(pool.num_idle(), 2);
+
+    pool.close().await;
+
+    Ok(())
+}
+
 #[ignore]
 #[sqlx_macros::test]
 async fn test_connection_maintenance() -> anyhow::Result<()> {
@@ -1,4 +1,5 @@
-use sqlx::{error::ErrorKind, postgres::Postgres, Connection};
+use sqlx::postgres::{sqlstate, PgErrorExt, Postgres};
+use sqlx::{error::ErrorKind, Connection};
 use sqlx_test::new;
 
 #[sqlx_macros::test]
@@ -74,3 +75,34 @@ async fn it_fails_with_check_violation() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_reports_sqlstate() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO tweet(id, text, owner_id) VALUES (1, 'Foo', 1);")
+        .execute(&mut *tx)
+        .await?;
+
+    let err = sqlx::query("INSERT INTO tweet VALUES (1, NOW(), 'Foo', 1);")
+        .execute(&mut *tx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.sqlstate(), Some(sqlstate::UNIQUE_VIOLATION));
+
+    tx.rollback().await?;
+
+    let err = sqlx::query("SELECT * FROM this_table_does_not_exist")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.sqlstate(), Some(sqlstate::UNDEFINED_TABLE));
+
+    // not an error returned by the database
+    assert_eq!(sqlx::Error::RowNotFound.sqlstate(), None);
+
+    Ok(())
+}
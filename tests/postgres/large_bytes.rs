@@ -0,0 +1,94 @@
+use sqlx::postgres::types::PgLargeBytes;
+use sqlx::postgres::Postgres;
+use sqlx::Connection;
+use sqlx_test::new;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::cmp;
+use std::time::Duration;
+
+// Records the largest allocation made on each thread, so a test can check that a value is not
+// copied while it is sent; each test runs on its own single-threaded runtime. These tests have
+// a target of their own, so the rest of the suite keeps the normal allocator.
+struct TrackingAllocator;
+
+thread_local! {
+    static LARGEST_ALLOCATION: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record_allocation(size: usize) {
+    let _ = LARGEST_ALLOCATION.try_with(|largest| largest.set(cmp::max(largest.get(), size)));
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+#[sqlx_macros::test]
+async fn it_binds_large_bytes() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let len = 500_000_000;
+    let bytes = PgLargeBytes::new(vec![7_u8; len]);
+
+    LARGEST_ALLOCATION.with(|largest| largest.set(0));
+
+    // the parameter after the large one ends up in the right place
+    let sql = "SELECT length($1), get_byte($1, length($1) - 1), $2";
+
+    let (length, last, next): (i32, i32, i32) = sqlx::query_as(sql)
+        .bind(&bytes)
+        .bind(42_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(length as usize, len);
+    assert_eq!(last, 7);
+    assert_eq!(next, 42);
+
+    // nothing close to the size of the value was allocated to send it
+    let largest = LARGEST_ALLOCATION.with(Cell::get);
+    assert!(largest < 16 * 1024 * 1024, "allocated {largest} bytes");
+
+    // the whole message was sent, so the connection is still usable
+    let value: i32 = sqlx::query_scalar("SELECT 1 + 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    // cancelled in the middle of sending the value (the statement is already prepared),
+    // the connection cannot be reused
+    let res = sqlx_core::rt::timeout(
+        Duration::from_millis(20),
+        sqlx::query(sql)
+            .bind(&bytes)
+            .bind(42_i32)
+            .execute(&mut conn),
+    )
+    .await;
+    assert!(res.is_err(), "expected the query to time out");
+
+    assert!(conn.ping().await.is_err());
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
-use sqlx::migrate::Migrator;
+use sqlx::migrate::{Migrate, Migrator};
 use sqlx::pool::PoolConnection;
-use sqlx::postgres::{PgConnection, Postgres};
+use sqlx::postgres::{PgConnection, PgPool, Postgres};
 use sqlx::Executor;
 use sqlx::Row;
 use std::path::Path;
@@ -85,6 +85,81 @@ async fn no_tx(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = false)]
+async fn dry_run(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/postgres/migrations_simple")).await?;
+
+    // everything is pending, and nothing is applied
+    let pending: Vec<i64> = migrator
+        .dry_run(&mut conn)
+        .await?
+        .iter()
+        .map(|m| m.version)
+        .collect();
+    assert_eq!(
+        pending,
+        migrator.iter().map(|m| m.version).collect::<Vec<_>>()
+    );
+
+    let applied: i64 = conn
+        .fetch_one("SELECT COUNT(*) FROM _sqlx_migrations")
+        .await?
+        .get(0);
+    assert_eq!(applied, 0);
+
+    migrator.run(&mut conn).await?;
+
+    assert!(migrator.dry_run(&mut conn).await?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn concurrent_runs(pool: PgPool) -> anyhow::Result<()> {
+    clean_up(&mut *pool.acquire().await?).await?;
+
+    let migrator = Migrator::new(Path::new("tests/postgres/migrations_simple")).await?;
+
+    // hold the migration lock so that both runs have to wait for it
+    let mut holder = pool.acquire().await?;
+    holder.lock().await?;
+
+    let runs = tokio::spawn({
+        let pool = pool.clone();
+
+        async move {
+            let migrator = Migrator::new(Path::new("tests/postgres/migrations_simple")).await?;
+
+            futures::try_join!(migrator.run(&pool), migrator.run(&pool))?;
+
+            anyhow::Ok(())
+        }
+    });
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert!(!runs.is_finished());
+
+    holder.unlock().await?;
+    runs.await??;
+
+    // only one of the runs applied the migrations; the other found nothing left to do
+    let applied: i64 = pool
+        .fetch_one("SELECT COUNT(*) FROM _sqlx_migrations")
+        .await?
+        .get(0);
+    assert_eq!(applied, migrator.iter().count() as i64);
+
+    let res: String = pool
+        .fetch_one("SELECT some_payload FROM migrations_simple_test")
+        .await?
+        .get(0);
+    assert_eq!(res, "110_suffix");
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut PgConnection) -> anyhow::Result<()> {
     conn.execute("DROP DATABASE IF EXISTS test_db").await.ok();
@@ -2,16 +2,18 @@ use futures::{Stream, StreamExt, TryStreamExt};
 
 use sqlx::postgres::types::Oid;
 use sqlx::postgres::{
-    PgAdvisoryLock, PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgListener,
-    PgPoolOptions, PgRow, PgSeverity, Postgres, PG_COPY_MAX_DATA_LEN,
+    PgAdvisoryLock, PgApplicationNameSuffix, PgArguments, PgChannelBinding, PgConnectOptions,
+    PgConnection, PgDatabaseError, PgErrorPosition, PgListener, PgPoolOptions, PgProtocolDirection,
+    PgRow, PgSeverity, PgSslMode, PgTypeInfo, Postgres, TlsVersion, PG_COPY_MAX_DATA_LEN,
 };
-use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
+use sqlx::query_builder::Separated;
+use sqlx::{Arguments, Column, Connection, Either, Executor, Row, Statement, TypeInfo};
 use sqlx_core::{bytes::Bytes, error::BoxDynError};
 use sqlx_test::{new, pool, setup_if_needed};
 use std::env;
 use std::pin::{pin, Pin};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -595,6 +597,148 @@ async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn pool_uses_new_connect_options_for_new_connections() -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let original: String = sqlx::query_scalar("SELECT current_database()::text")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    let target = if original == "postgres" {
+        "template1"
+    } else {
+        "postgres"
+    };
+
+    pool.set_connect_options(pool.connect_options().as_ref().clone().database(target));
+
+    // the connection that is already open is left as it was
+    let current: String = sqlx::query_scalar("SELECT current_database()::text")
+        .fetch_one(&mut *conn)
+        .await?;
+    assert_eq!(current, original);
+
+    // once it is closed, the next connection is opened with the new options
+    conn.close().await?;
+
+    let current: String = sqlx::query_scalar("SELECT current_database()::text")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(current, target);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn pool_follows_the_primary_across_a_failover() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // a role whose sessions are read-only by default stands in for a hot standby,
+    // and resetting that setting stands in for promoting it
+    conn.execute(
+        r#"
+DO $$
+BEGIN
+    CREATE ROLE sqlx_failover_test LOGIN PASSWORD 'failover';
+EXCEPTION WHEN duplicate_object THEN NULL;
+END
+$$;
+
+ALTER ROLE sqlx_failover_test SET default_transaction_read_only = on;
+        "#,
+    )
+    .await?;
+
+    // the old primary is down, so nothing listens on its port
+    let old_primary = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+
+    let base: PgConnectOptions = dotenvy::var("DATABASE_URL")?.parse()?;
+    let options = base
+        .clone()
+        .username("sqlx_failover_test")
+        .password("failover")
+        .require_primary([
+            ("127.0.0.1".to_owned(), old_primary.port()),
+            (base.get_host().to_owned(), base.get_port()),
+        ]);
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_secs(1))
+        .connect_lazy_with(options);
+
+    // until the standby is promoted, there is no primary to connect to
+    assert!(pool.acquire().await.is_err());
+
+    conn.execute("ALTER ROLE sqlx_failover_test RESET default_transaction_read_only")
+        .await?;
+
+    let read_only: String = sqlx::query_scalar("SHOW transaction_read_only")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(read_only, "off");
+
+    pool.close().await;
+
+    conn.execute("DROP ROLE sqlx_failover_test").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_connects_to_the_next_host_in_a_url() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    // nothing listens on the port of the first host
+    let down = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+
+    let mut url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    let host = url.host_str().unwrap_or("localhost").to_owned();
+    let port = url.port().unwrap_or(5432);
+
+    url.set_host(Some("127.0.0.1"))?;
+    url.set_port(Some(down.port())).unwrap();
+
+    let url = url
+        .as_str()
+        .replacen(&down.to_string(), &format!("{down},{host}:{port}"), 1);
+
+    let options: PgConnectOptions = url.parse()?;
+    assert_eq!(options.get_host(), "127.0.0.1");
+
+    let mut conn = PgConnection::connect(&url).await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT 1 + 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_refuses_to_connect_without_required_channel_binding() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    // whichever way the server authenticates, it is not with channel binding
+    let res = PgConnection::connect_with(&options.channel_binding(PgChannelBinding::Require)).await;
+
+    let err = res.err().expect("expected the connection to be refused");
+    assert!(err.to_string().contains("channel binding is required"));
+
+    Ok(())
+}
+
 // run with `cargo test --features postgres -- --ignored --nocapture pool_smoke_test`
 #[ignore]
 #[sqlx_macros::test]
@@ -698,6 +842,39 @@ async fn test_empty_query() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// A query containing only comments is empty as well, whether it is prepared or not.
+#[sqlx_macros::test]
+async fn test_comment_only_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for query in ["", "-- nothing to see here", "/* or here */"] {
+        // unprepared
+        let results: Vec<_> = conn.fetch_many(query).try_collect().await?;
+        assert!(
+            matches!(results[..], [Either::Left(ref done)] if done.rows_affected() == 0),
+            "{query:?}: {results:?}"
+        );
+
+        // prepared
+        let results: Vec<_> = conn.fetch_many(sqlx::query(query)).try_collect().await?;
+        assert!(
+            matches!(results[..], [Either::Left(ref done)] if done.rows_affected() == 0),
+            "{query:?}: {results:?}"
+        );
+
+        assert!(sqlx::query(query)
+            .fetch_optional(&mut conn)
+            .await?
+            .is_none());
+    }
+
+    // the connection is still usable
+    let value: i32 = conn.fetch_one("SELECT 1").await?.try_get(0)?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
 /// Test a simple select expression. This should return the row.
 #[sqlx_macros::test]
 async fn test_select_expression() -> anyhow::Result<()> {
@@ -785,6 +962,57 @@ async fn it_caches_statements() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_lists_prepared_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    assert_eq!(conn.prepared_statements().count(), 0);
+
+    let queries = ["SELECT $1::int4", "SELECT $1::text", "SELECT 1 WHERE $1"];
+
+    sqlx::query(queries[0])
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await?;
+    sqlx::query(queries[1]).bind("a").execute(&mut conn).await?;
+    sqlx::query(queries[2])
+        .bind(true)
+        .execute(&mut conn)
+        .await?;
+
+    // not cached
+    sqlx::query("SELECT $1::int8")
+        .bind(1_i64)
+        .persistent(false)
+        .execute(&mut conn)
+        .await?;
+
+    let statements: Vec<(String, String)> = conn
+        .prepared_statements()
+        .map(|(name, sql)| (name, sql.to_owned()))
+        .collect();
+
+    // least recently used first
+    let sql: Vec<&str> = statements.iter().map(|(_, sql)| &**sql).collect();
+    assert_eq!(sql, queries);
+
+    // the server also keeps the statements of queries that were not cached
+    let on_server: Vec<(String, String)> =
+        sqlx::query_as("SELECT name, statement FROM pg_prepared_statements")
+            .persistent(false)
+            .fetch_all(&mut conn)
+            .await?;
+
+    for statement in &statements {
+        assert!(
+            on_server.contains(statement),
+            "{statement:?} not in {on_server:?}"
+        );
+    }
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
@@ -813,872 +1041,3234 @@ async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
 }
 
 #[sqlx_macros::test]
-async fn it_sets_application_name() -> anyhow::Result<()> {
-    sqlx_test::setup_if_needed();
-
-    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
-    options = options.application_name("some-name");
+async fn it_can_discard_all() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    let mut conn = PgConnection::connect_with(&options).await?;
+    conn.execute("CREATE TEMPORARY TABLE discard_all (id INT); SET application_name = 'discarded'")
+        .await?;
 
-    let row = sqlx::query("select current_setting('application_name') as app_name")
+    let value: i32 = sqlx::query_scalar("SELECT $1")
+        .bind(1_i32)
         .fetch_one(&mut conn)
         .await?;
+    assert_eq!(value, 1);
+    assert_eq!(conn.cached_statements_size(), 1);
 
-    let val: String = row.get("app_name");
+    conn.discard_all().await?;
 
-    assert_eq!("some-name", &val);
+    assert_eq!(conn.cached_statements_size(), 0);
 
-    Ok(())
-}
+    // the simple query protocol, so this query is not prepared itself
+    let prepared: i64 = sqlx::raw_sql("SELECT count(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?
+        .try_get(0)?;
+    assert_eq!(prepared, 0);
+
+    let temporary: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM pg_class WHERE relname = 'discard_all' AND relpersistence = 't'",
+    )
+    .persistent(false)
+    .fetch_one(&mut conn)
+    .await?;
+    assert_eq!(temporary, 0);
+
+    let application_name: String = sqlx::query_scalar("SHOW application_name")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_ne!(application_name, "discarded");
+
+    // statements are prepared again as they are needed
+    let value: i32 = sqlx::query_scalar("SELECT $1")
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    // not allowed inside a transaction
+    let mut tx = conn.begin().await?;
+    assert!(tx.discard_all().await.is_err());
+    tx.rollback().await?;
 
-#[sqlx_macros::test]
-async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()> {
-    new::<Postgres>().await?.execute("SET NAMES 'UTF8'").await?;
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
+async fn it_can_set_search_path() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
-    let mut tx = conn.begin().await?;
-
-    let tweet_id: i64 =
-        sqlx::query_scalar("INSERT INTO tweet ( text ) VALUES ( 'Hello, World' ) RETURNING id")
-            .fetch_one(&mut *tx)
-            .await?;
 
-    let statement = tx.prepare("SELECT * FROM tweet WHERE id = $1").await?;
-
-    assert_eq!(statement.column(0).name(), "id");
-    assert_eq!(statement.column(1).name(), "created_at");
-    assert_eq!(statement.column(2).name(), "text");
-    assert_eq!(statement.column(3).name(), "owner_id");
+    // everything is rolled back at the end
+    let mut tx = conn.begin().await?;
 
-    assert_eq!(statement.column(0).type_info().name(), "INT8");
-    assert_eq!(statement.column(1).type_info().name(), "TIMESTAMPTZ");
-    assert_eq!(statement.column(2).type_info().name(), "TEXT");
-    assert_eq!(statement.column(3).type_info().name(), "INT8");
+    tx.execute(
+        r#"
+        CREATE SCHEMA "Tenant ""A""";
+        CREATE TABLE "Tenant ""A""".accounts (name TEXT NOT NULL);
+        INSERT INTO "Tenant ""A""".accounts VALUES ('alice');
 
-    let row = statement.query().bind(tweet_id).fetch_one(&mut *tx).await?;
-    let tweet_text: &str = row.try_get("text")?;
+        CREATE SCHEMA tenant_b;
+        CREATE TABLE tenant_b.accounts (name TEXT NOT NULL);
+        INSERT INTO tenant_b.accounts VALUES ('bob');
+        "#,
+    )
+    .await?;
 
-    assert_eq!(tweet_text, "Hello, World");
+    tx.set_search_path(&["Tenant \"A\"", "public"]).await?;
 
-    Ok(())
-}
+    let name: String = sqlx::query_scalar("SELECT name FROM accounts")
+        .fetch_one(&mut *tx)
+        .await?;
+    assert_eq!(name, "alice");
 
-// repro is more reliable with the basic scheduler used by `#[tokio::test]`
-#[cfg(feature = "_rt-tokio")]
-#[tokio::test]
-async fn test_issue_622() -> anyhow::Result<()> {
-    use std::time::Instant;
+    tx.set_search_path(&["tenant_b"]).await?;
 
-    setup_if_needed();
+    let name: String = sqlx::query_scalar("SELECT name FROM accounts")
+        .fetch_one(&mut *tx)
+        .await?;
+    assert_eq!(name, "bob");
 
-    let pool = PgPoolOptions::new()
-        .max_connections(1) // also fails with higher counts, e.g. 5
-        .connect(&std::env::var("DATABASE_URL").unwrap())
+    let search_path: String = sqlx::query_scalar("SHOW search_path")
+        .fetch_one(&mut *tx)
         .await?;
+    assert_eq!(search_path, "tenant_b");
+
+    // a name cannot be sent with a NUL byte, nor be cut short at it
+    assert!(matches!(
+        tx.set_search_path(&["tenant_b\0public"]).await,
+        Err(sqlx::Error::Encode(_))
+    ));
+
+    // with no schemas, unqualified names cannot be resolved
+    tx.set_search_path(&[]).await?;
+    assert!(sqlx::query("SELECT name FROM accounts")
+        .persistent(false)
+        .fetch_one(&mut *tx)
+        .await
+        .is_err());
 
-    println!("pool state: {pool:?}");
+    tx.rollback().await?;
 
-    let mut handles = vec![];
+    Ok(())
+}
 
-    // given repro spawned 100 tasks but I found it reliably reproduced with 3
-    for i in 0..3 {
-        let pool = pool.clone();
+#[sqlx_macros::test]
+async fn it_traces_protocol_messages() -> anyhow::Result<()> {
+    use std::sync::Mutex;
 
-        handles.push(sqlx_core::rt::spawn(async move {
-            {
-                let mut conn = pool.acquire().await.unwrap();
+    let mut conn = new::<Postgres>().await?;
 
-                let _ = sqlx::query("SELECT 1").fetch_one(&mut *conn).await.unwrap();
+    let messages = Arc::new(Mutex::new(Vec::new()));
 
-                // conn gets dropped here and should be returned to the pool
-            }
+    conn.on_protocol_message({
+        let messages = Arc::clone(&messages);
+        move |message| messages.lock().unwrap().push(*message)
+    });
 
-            // (do some other work here without holding on to a connection)
-            // this actually fixes the issue, depending on the timeout used
-            // sqlx_core::rt::sleep(Duration::from_millis(500)).await;
+    // `fetch_one()` would limit the portal to one row and see it suspended instead of completed
+    let values: Vec<i32> = sqlx::query_scalar("SELECT $1::int4")
+        .bind(1_i32)
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(values, [1]);
 
-            {
-                let start = Instant::now();
-                match pool.acquire().await {
-                    Ok(conn) => {
-                        println!("{} acquire took {:?}", i, start.elapsed());
-                        drop(conn);
-                    }
-                    Err(e) => panic!("{i} acquire returned error: {e} pool state: {pool:?}"),
-                }
-            }
+    let messages = messages.lock().unwrap();
 
-            Result::<(), anyhow::Error>::Ok(())
-        }));
-    }
+    let names = |direction| {
+        messages
+            .iter()
+            .filter(|message| message.direction() == direction)
+            .map(|message| message.name())
+            .collect::<Vec<_>>()
+    };
 
-    futures::future::try_join_all(handles).await?;
+    assert_eq!(
+        names(PgProtocolDirection::Outbound),
+        ["Parse", "Describe", "Sync", "Bind", "Execute", "Close", "Sync"]
+    );
+    assert_eq!(
+        names(PgProtocolDirection::Inbound),
+        [
+            "ParseComplete",
+            "ParameterDescription",
+            "RowDescription",
+            "ReadyForQuery",
+            "BindComplete",
+            "DataRow",
+            "CommandComplete",
+            "CloseComplete",
+            "ReadyForQuery",
+        ]
+    );
+
+    // `Sync` has no body and `ReadyForQuery` only the transaction status
+    assert!(messages
+        .iter()
+        .filter(|message| message.name() == "Sync")
+        .all(|message| message.length() == 4));
+    assert!(messages
+        .iter()
+        .filter(|message| message.name() == "ReadyForQuery")
+        .all(|message| message.length() == 5));
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn test_describe_outer_join_nullable() -> anyhow::Result<()> {
+async fn it_can_scope_session_authorization() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
 
-    // test nullability inference for various joins
+    conn.execute(
+        r#"
+DO $$
+BEGIN
+    CREATE ROLE "sqlx session ""auth"" test";
+EXCEPTION WHEN duplicate_object THEN NULL;
+END
+$$;
+        "#,
+    )
+    .await?;
 
-    // inner join, nullability should not be overridden
-    // language=PostgreSQL
-    let describe = conn
-        .describe(
-            "select tweet.id
-    from tweet
-    inner join products on products.name = tweet.text",
-        )
+    let (original,): (String,) = sqlx::query_as("SELECT session_user::text")
+        .fetch_one(&mut conn)
         .await?;
 
-    assert_eq!(describe.nullable(0), Some(false));
-
-    // language=PostgreSQL
-    let describe = conn
-        .describe(
-            "select tweet.id
-from (values (null)) vals(val)
-         left join tweet on false",
-        )
+    let (session_user, current_user): (String, String) = conn
+        .with_session_authorization(r#"sqlx session "auth" test"#, |conn| {
+            Box::pin(async move {
+                sqlx::query_as("SELECT session_user::text, current_user::text")
+                    .fetch_one(conn)
+                    .await
+            })
+        })
         .await?;
 
-    // tweet.id is marked NOT NULL but it's brought in from a left-join here
-    // which should make it nullable
-    assert_eq!(describe.nullable(0), Some(true));
+    assert_eq!(session_user, r#"sqlx session "auth" test"#);
+    assert_eq!(current_user, r#"sqlx session "auth" test"#);
 
-    // make sure we don't mis-infer for the outer half of the join
-    // language=PostgreSQL
-    let describe = conn
-        .describe(
-            "select tweet1.id, tweet2.id
-    from tweet tweet1
-    left join tweet tweet2 on false",
-        )
+    let (session_user,): (String,) = sqlx::query_as("SELECT session_user::text")
+        .fetch_one(&mut conn)
         .await?;
+    assert_eq!(session_user, original);
 
-    assert_eq!(describe.nullable(0), Some(false));
-    assert_eq!(describe.nullable(1), Some(true));
+    // the authorization is also restored if the callback fails
+    let res = conn
+        .with_session_authorization(r#"sqlx session "auth" test"#, |conn| {
+            Box::pin(async move { conn.execute("SELECT 1 / 0").await })
+        })
+        .await;
+    assert!(res.is_err());
 
-    // right join, nullability should be inverted
-    // language=PostgreSQL
-    let describe = conn
-        .describe(
-            "select tweet1.id, tweet2.id
-    from tweet tweet1
-    right join tweet tweet2 on false",
-        )
+    let (session_user,): (String,) = sqlx::query_as("SELECT session_user::text")
+        .fetch_one(&mut conn)
         .await?;
+    assert_eq!(session_user, original);
 
-    assert_eq!(describe.nullable(0), Some(true));
-    assert_eq!(describe.nullable(1), Some(false));
-
-    // full outer join, both tables are nullable
-    // language=PostgreSQL
-    let describe = conn
-        .describe(
-            "select tweet1.id, tweet2.id
-    from tweet tweet1
-    full join tweet tweet2 on false",
-        )
+    conn.execute(r#"DROP ROLE "sqlx session ""auth"" test""#)
         .await?;
 
-    assert_eq!(describe.nullable(0), Some(true));
-    assert_eq!(describe.nullable(1), Some(true));
-
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn test_listener_cleanup() -> anyhow::Result<()> {
-    use sqlx_core::rt::timeout;
+async fn it_cancels_queries_past_the_deadline() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    use sqlx::pool::PoolOptions;
-    use sqlx::postgres::PgListener;
+    let started = Instant::now();
+    let deadline = started + Duration::from_secs(1);
+
+    let (completed, slow, after) = conn
+        .with_deadline(deadline, |conn| {
+            Box::pin(async move {
+                let mut completed = 0;
+
+                for i in 0..3_i32 {
+                    let value: i32 = sqlx::query_scalar("SELECT $1")
+                        .bind(i)
+                        .fetch_one(&mut *conn)
+                        .await?;
+                    assert_eq!(value, i);
+                    completed += 1;
+                }
 
-    // Create a connection on which to send notifications
-    let mut notify_conn = new::<Postgres>().await?;
+                let slow = conn.execute("SELECT pg_sleep(10)").await;
 
-    // Create a pool with exactly one connection so we can
-    // deterministically test the cleanup.
-    let pool = PoolOptions::<Postgres>::new()
-        .min_connections(1)
-        .max_connections(1)
-        .test_before_acquire(true)
-        .connect(&env::var("DATABASE_URL")?)
+                // the deadline has passed, so this is not even sent
+                let after = conn.execute("SELECT 1").await;
+
+                Ok::<_, sqlx::Error>((completed, slow, after))
+            })
+        })
         .await?;
 
-    let mut listener = PgListener::connect_with(&pool).await?;
-    listener.listen("test_channel").await?;
+    assert_eq!(completed, 3);
+    assert!(started.elapsed() < Duration::from_secs(5));
 
-    // Checks for a notification on the test channel
-    async fn try_recv(listener: &mut PgListener) -> anyhow::Result<bool> {
-        match timeout(Duration::from_millis(100), listener.recv()).await {
-            Ok(res) => {
-                res?;
-                Ok(true)
-            }
-            Err(_) => Ok(false),
-        }
+    let err = slow.unwrap_err();
+    assert_eq!(
+        err.as_database_error().and_then(|e| e.code()).as_deref(),
+        Some("57014"),
+        "{err:?}"
+    );
+
+    match after.unwrap_err() {
+        sqlx::Error::Io(e) => assert_eq!(e.kind(), std::io::ErrorKind::TimedOut),
+        e => panic!("unexpected error: {e:?}"),
     }
 
-    // Check no notification is received before one is sent
-    assert!(!try_recv(&mut listener).await?, "Notification not sent");
+    // the deadline only applies within the callback
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
 
-    // Check notification is sent and received
-    notify_conn.execute("NOTIFY test_channel").await?;
-    assert!(
-        try_recv(&mut listener).await?,
-        "Notification sent and received"
-    );
-    assert!(
-        !try_recv(&mut listener).await?,
-        "Notification is not duplicated"
-    );
+    Ok(())
+}
 
-    // Test that cleanup stops listening on the channel
-    drop(listener);
-    let mut listener = PgListener::connect_with(&pool).await?;
+#[sqlx_macros::test]
+async fn it_restores_the_deadline_when_the_callback_is_dropped() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    // Check notification is sent but not received
-    notify_conn.execute("NOTIFY test_channel").await?;
-    assert!(
-        !try_recv(&mut listener).await?,
-        "Notification is not received on fresh listener"
-    );
+    let deadline = Instant::now() + Duration::from_millis(100);
+
+    let res = sqlx_core::rt::timeout(
+        Duration::from_millis(10),
+        conn.with_deadline(deadline, |_| {
+            Box::pin(async move {
+                sqlx_core::rt::sleep(Duration::from_secs(1)).await;
+                Ok::<_, sqlx::Error>(())
+            })
+        }),
+    )
+    .await;
+    assert!(res.is_err(), "expected `with_deadline()` to time out");
+
+    sqlx_core::rt::sleep(Duration::from_millis(200)).await;
+
+    // the deadline has passed, but no longer applies to the connection
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn test_listener_try_recv_buffered() -> anyhow::Result<()> {
-    use sqlx_core::rt::timeout;
+async fn it_can_bind_the_same_arguments_by_reference() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    use sqlx::pool::PoolOptions;
-    use sqlx::postgres::PgListener;
+    let mut arguments = PgArguments::default();
+    arguments.add(3_i32).map_err(sqlx::Error::Encode)?;
+    arguments.add("foo").map_err(sqlx::Error::Encode)?;
 
-    // Create a connection on which to send notifications
-    let mut notify_conn = new::<Postgres>().await?;
+    let (sum, name): (i32, String) = sqlx::query_as_with("SELECT $1 + 1, $2 || 'bar'", &arguments)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!((sum, name.as_str()), (4, "foobar"));
 
-    let pool = PoolOptions::<Postgres>::new()
-        .min_connections(1)
-        .max_connections(1)
-        .test_before_acquire(true)
-        .connect(&env::var("DATABASE_URL")?)
+    let (product, len): (i32, i32) =
+        sqlx::query_as_with("SELECT $1 * 2, length($2)::int4", &arguments)
+            .fetch_one(&mut conn)
+            .await?;
+    assert_eq!((product, len), (6, 3));
+
+    // the borrowed arguments are left as they were
+    assert_eq!(arguments.len(), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_execute_prepared_unchecked() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE unchecked_params (ty TEXT NOT NULL)")
         .await?;
 
-    let mut listener = PgListener::connect_with(&pool).await?;
-    listener.listen("test_channel2").await?;
+    let cached = conn.cached_statements_size();
 
-    // Checks for a notification on the test channel
-    async fn try_recv(listener: &mut PgListener) -> anyhow::Result<bool> {
-        match timeout(Duration::from_millis(100), listener.recv()).await {
-            Ok(res) => {
-                res?;
-                Ok(true)
+    let describes = Arc::new(AtomicUsize::new(0));
+
+    conn.on_protocol_message({
+        let describes = Arc::clone(&describes);
+        move |message| {
+            if message.direction() == PgProtocolDirection::Outbound && message.name() == "Describe"
+            {
+                describes.fetch_add(1, Ordering::SeqCst);
             }
-            Err(_) => Ok(false),
         }
-    }
+    });
 
-    // Check no notification is buffered, since we haven't sent one.
-    assert!(listener.next_buffered().is_none());
+    // the parameter is given no type by the query itself, so without a `Describe`
+    // the server can only have learned it from the explicit type
+    let mut arguments = PgArguments::default();
+    arguments.add(5_i64).map_err(sqlx::Error::Encode)?;
 
-    // Send five notifications transactionally, so they all arrive at once.
-    {
-        let mut txn = notify_conn.begin().await?;
-        for i in 0..5 {
-            txn.execute(format!("NOTIFY test_channel2, 'payload {i}'").as_str())
-                .await?;
-        }
-        txn.commit().await?;
-    }
+    let result = conn
+        .execute_prepared_unchecked(
+            "INSERT INTO unchecked_params (ty) SELECT pg_typeof($1)::text",
+            &[<i64 as sqlx::Type<Postgres>>::type_info()],
+            arguments,
+        )
+        .await?;
 
-    // Still no notifications buffered, since we haven't awaited the listener yet.
-    assert!(listener.next_buffered().is_none());
+    assert_eq!(result.rows_affected(), 1);
+    assert_eq!(conn.cached_statements_size(), cached);
+    assert_eq!(describes.load(Ordering::SeqCst), 0);
 
-    // Activate connection.
-    sqlx::query!("SELECT 1 AS one")
-        .fetch_all(&mut listener)
+    let ty: String = sqlx::query_scalar("SELECT ty FROM unchecked_params")
+        .fetch_one(&mut conn)
         .await?;
+    assert_eq!(ty, "bigint");
 
-    // The next five notifications should now be buffered.
-    for i in 0..5 {
-        assert!(
-            listener.next_buffered().is_some(),
-            "Notification {i} was not buffered"
-        );
-    }
+    // a type count that does not match the arguments is rejected before anything is sent
+    let mut arguments = PgArguments::default();
+    arguments.add(5_i64).map_err(sqlx::Error::Encode)?;
 
-    // Should be no more.
-    assert!(listener.next_buffered().is_none());
+    let res = conn
+        .execute_prepared_unchecked("SELECT $1", &[], arguments)
+        .await;
+    assert!(matches!(res, Err(sqlx::Error::Encode(_))));
 
-    // Even if we wait.
-    assert!(!try_recv(&mut listener).await?, "Notification received");
+    // the connection is still usable
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn test_pg_listener_allows_pool_to_close() -> anyhow::Result<()> {
-    let pool = pool::<Postgres>().await?;
+async fn it_can_bind_a_string_as_jsonb_with_an_explicit_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    // acquires and holds a connection which would normally prevent the pool from closing
-    let mut listener = PgListener::connect_with(&pool).await?;
+    conn.execute("CREATE TEMPORARY TABLE explicit_params (id INT4 NOT NULL, value JSONB NOT NULL)")
+        .await?;
 
-    sqlx_core::rt::spawn(async move {
-        listener.recv().await.unwrap();
-    });
+    let sql = "INSERT INTO explicit_params (id, value) VALUES ($1, $2)";
+    let json = r#"{"theme": "dark"}"#.to_string();
 
-    // would previously hang forever since `PgListener` had no way to know the pool wanted to close
-    pool.close().await;
+    // inferred as `text`, which cannot be assigned to `jsonb`
+    let mut arguments = PgArguments::default();
+    arguments.add(1_i32).map_err(sqlx::Error::Encode)?;
+    arguments.add(json.clone()).map_err(sqlx::Error::Encode)?;
+
+    let res = sqlx::query_with(sql, arguments).execute(&mut conn).await;
+    assert!(res.is_err());
+
+    let mut arguments = PgArguments::default();
+    arguments.add(1_i32).map_err(sqlx::Error::Encode)?;
+    arguments
+        .add_with_type(json, PgTypeInfo::with_name("jsonb"))
+        .map_err(sqlx::Error::Encode)?;
+
+    sqlx::query_with(sql, arguments).execute(&mut conn).await?;
+
+    let (id, theme): (i32, String) =
+        sqlx::query_as("SELECT id, value->>'theme' FROM explicit_params")
+            .fetch_one(&mut conn)
+            .await?;
+    assert_eq!(id, 1);
+    assert_eq!(theme, "dark");
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn test_pg_listener_implements_acquire() -> anyhow::Result<()> {
-    use sqlx::Acquire;
+async fn it_reports_the_qualified_type_name_of_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    let pool = pool::<Postgres>().await?;
+    conn.execute(
+        r#"
+CREATE SCHEMA IF NOT EXISTS "column types";
+
+DO $$
+BEGIN
+    CREATE TYPE "column types".status AS ENUM ('ok', 'failed');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END
+$$;
+        "#,
+    )
+    .await?;
 
-    let mut listener = PgListener::connect_with(&pool).await?;
-    listener
-        .listen("test_pg_listener_implements_acquire")
+    let row = sqlx::query(r#"SELECT 'ok'::"column types".status AS status, 1::int4 AS id"#)
+        .fetch_one(&mut conn)
         .await?;
 
-    // Start a transaction on the underlying connection
-    let mut txn = listener.begin().await?;
+    let status = &row.columns()[0];
+    assert_eq!(status.type_name(), r#""column types".status"#);
+    assert_eq!(
+        status.qualified_type_name(),
+        Some(r#""column types".status"#)
+    );
+    assert!(status.type_oid().is_some());
 
-    // This will reuse the same connection, so this connection should be listening to the channel
-    let channels: Vec<String> = sqlx::query_scalar("SELECT pg_listening_channels()")
-        .fetch_all(&mut *txn)
+    let id = &row.columns()[1];
+    assert_eq!(id.type_name(), "INT4");
+    assert_eq!(id.type_oid(), Some(Oid(23)));
+    assert_eq!(id.qualified_type_name(), None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_batch_bind_execute() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE batch_bind (id INT PRIMARY KEY, name TEXT NOT NULL)")
         .await?;
 
-    assert_eq!(channels, vec!["test_pg_listener_implements_acquire"]);
+    let batch = (0..1000)
+        .map(|i| {
+            let mut arguments = PgArguments::default();
+            arguments.add(i)?;
+            arguments.add(format!("row {i}"))?;
+            Ok(arguments)
+        })
+        .collect::<Result<Vec<_>, BoxDynError>>()
+        .map_err(sqlx::Error::Encode)?;
 
-    // Send a notification
-    sqlx::query("NOTIFY test_pg_listener_implements_acquire, 'hello'")
-        .execute(&mut *txn)
+    let results = conn
+        .batch_bind_execute("INSERT INTO batch_bind (id, name) VALUES ($1, $2)", batch)
         .await?;
 
-    txn.commit().await?;
+    assert_eq!(results.len(), 1000);
+    assert!(results.iter().all(|result| result.rows_affected() == 1));
+
+    let (count, sum): (i64, i64) = sqlx::query_as("SELECT count(*), sum(id) FROM batch_bind")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1000);
+    assert_eq!(sum, (0..1000).sum::<i64>());
+
+    // a failing execution rolls back the whole batch and leaves the connection usable
+    let batch = [1000, 1001, 0]
+        .into_iter()
+        .map(|i| {
+            let mut arguments = PgArguments::default();
+            arguments.add(i)?;
+            arguments.add("duplicate")?;
+            Ok(arguments)
+        })
+        .collect::<Result<Vec<_>, BoxDynError>>()
+        .map_err(sqlx::Error::Encode)?;
+
+    let err = conn
+        .batch_bind_execute("INSERT INTO batch_bind (id, name) VALUES ($1, $2)", batch)
+        .await
+        .unwrap_err();
 
-    // And now we can receive the notification we sent in the transaction
-    let notification = listener.recv().await?;
     assert_eq!(
-        notification.channel(),
-        "test_pg_listener_implements_acquire"
+        err.into_database_error().unwrap().code().as_deref(),
+        Some("23505")
     );
-    assert_eq!(notification.payload(), "hello");
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM batch_bind")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1000);
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn it_supports_domain_types_in_composite_domain_types() -> anyhow::Result<()> {
-    // Only supported in Postgres 11+
+async fn it_can_stream_batch_bind_execute_results() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
-    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
-        return Ok(());
+
+    // the last execution sleeps, the earlier ones return straight away
+    let batch = [0.0, 0.0, 0.0, 1.0]
+        .into_iter()
+        .map(|seconds: f64| {
+            let mut arguments = PgArguments::default();
+            arguments.add(seconds)?;
+            Ok(arguments)
+        })
+        .collect::<Result<Vec<_>, BoxDynError>>()
+        .map_err(sqlx::Error::Encode)?;
+
+    let start = Instant::now();
+    let mut results = conn.batch_bind_execute_stream("SELECT pg_sleep($1)", batch);
+
+    // earlier results arrive before the last execution has finished
+    for _ in 0..3 {
+        assert_eq!(results.try_next().await?.unwrap().rows_affected(), 1);
     }
+    assert!(start.elapsed() < Duration::from_millis(500));
 
-    conn.execute(
-        r#"
-DROP TABLE IF EXISTS heating_bills;
-DROP DOMAIN IF EXISTS winter_year_month;
-DROP TYPE IF EXISTS year_month;
-DROP DOMAIN IF EXISTS month_id;
+    assert_eq!(results.try_next().await?.unwrap().rows_affected(), 1);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert!(results.try_next().await?.is_none());
+    drop(results);
 
-CREATE DOMAIN month_id AS INT2 CHECK (1 <= value AND value <= 12);
-CREATE TYPE year_month AS (year INT4, month month_id);
-CREATE DOMAIN winter_year_month AS year_month CHECK ((value).month <= 3);
-CREATE TABLE heating_bills (
-  month winter_year_month NOT NULL PRIMARY KEY,
-  cost INT4 NOT NULL
-);
-    "#,
-    )
-    .await?;
+    // dropping the stream part of the way through skips the executions not yet sent
+    conn.execute("CREATE TEMPORARY TABLE batch_bind_stream (id INT PRIMARY KEY)")
+        .await?;
 
-    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct MonthId(i16);
+    let batch = (0..1000)
+        .map(|i| {
+            let mut arguments = PgArguments::default();
+            arguments.add(i)?;
+            Ok(arguments)
+        })
+        .collect::<Result<Vec<_>, BoxDynError>>()
+        .map_err(sqlx::Error::Encode)?;
 
-    impl sqlx::Type<Postgres> for MonthId {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            sqlx::postgres::PgTypeInfo::with_name("month_id")
-        }
+    let mut results =
+        conn.batch_bind_execute_stream("INSERT INTO batch_bind_stream (id) VALUES ($1)", batch);
+    results.try_next().await?;
+    drop(results);
 
-        fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
-            *ty == Self::type_info()
-        }
-    }
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM batch_bind_stream")
+        .fetch_one(&mut conn)
+        .await?;
 
-    impl<'r> sqlx::Decode<'r, Postgres> for MonthId {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            Ok(Self(<i16 as sqlx::Decode<Postgres>>::decode(value)?))
-        }
-    }
+    assert!(count > 0 && count < 1000, "{count}");
 
-    impl<'q> sqlx::Encode<'q, Postgres> for MonthId {
-        fn encode_by_ref(
-            &self,
-            buf: &mut sqlx::postgres::PgArgumentBuffer,
-        ) -> Result<sqlx::encode::IsNull, BoxDynError> {
-            <i16 as sqlx::Encode<Postgres>>::encode(self.0, buf)
-        }
-    }
+    Ok(())
+}
 
-    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct WinterYearMonth {
-        year: i32,
-        month: MonthId,
-    }
+#[sqlx_macros::test]
+async fn it_can_insert_values_past_the_parameter_limit() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    impl sqlx::Type<Postgres> for WinterYearMonth {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            sqlx::postgres::PgTypeInfo::with_name("winter_year_month")
-        }
+    conn.execute("CREATE TEMPORARY TABLE insert_values (id INT PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
 
-        fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
-            *ty == Self::type_info()
-        }
-    }
+    let insert = "INSERT INTO insert_values (id, name) ";
+    let push_tuple = |mut b: Separated<'_, '_, Postgres, &'static str>, id: i32| {
+        b.push_bind(id).push_bind(format!("row {id}"));
+    };
 
-    impl<'r> sqlx::Decode<'r, Postgres> for WinterYearMonth {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)?;
+    let result = conn.insert_values(insert, 0..30_000, push_tuple).await?;
+    assert_eq!(result.rows_affected(), 30_000);
 
-            let year = decoder.try_decode::<i32>()?;
-            let month = decoder.try_decode::<MonthId>()?;
+    // 80000 parameters do not fit in a single statement
+    let result = conn
+        .insert_values(insert, 30_000..70_000, push_tuple)
+        .await?;
+    assert_eq!(result.rows_affected(), 40_000);
 
-            Ok(Self { year, month })
-        }
-    }
+    let (count, sum): (i64, i64) = sqlx::query_as("SELECT count(*), sum(id) FROM insert_values")
+        .fetch_one(&mut conn)
+        .await?;
 
-    impl<'q> sqlx::Encode<'q, Postgres> for WinterYearMonth {
-        fn encode_by_ref(
-            &self,
-            buf: &mut sqlx::postgres::PgArgumentBuffer,
-        ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
-            let mut encoder = sqlx::postgres::types::PgRecordEncoder::new(buf);
-            encoder.encode(self.year)?;
-            encoder.encode(self.month)?;
-            encoder.finish();
-            Ok(sqlx::encode::IsNull::No)
-        }
-    }
+    assert_eq!(count, 70_000);
+    assert_eq!(sum, (0..70_000).sum::<i64>());
+
+    // the tuple moved to the next statement keeps its own values
+    let mismatched: i64 =
+        sqlx::query_scalar("SELECT count(*) FROM insert_values WHERE name <> 'row ' || id")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(mismatched, 0);
+
+    // a duplicate in a later statement rolls back the earlier ones as well
+    let err = conn
+        .insert_values(insert, (70_000..110_000).chain([0]), push_tuple)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.into_database_error().unwrap().code().as_deref(),
+        Some("23505")
+    );
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM insert_values")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 70_000);
+
+    // a tuple wider than the ones before it must still fit in the statement it goes into
+    conn.execute("CREATE TEMPORARY TABLE insert_wide_values (id INT PRIMARY KEY, a INT, b INT)")
+        .await?;
+
+    let result = conn
+        .insert_values(
+            "INSERT INTO insert_wide_values (id, a, b) ",
+            0..70_000,
+            |mut b, id: i32| {
+                if id < 65_533 {
+                    b.push_bind(id).push("NULL").push("NULL");
+                } else {
+                    b.push_bind(id).push_bind(id).push_bind(id);
+                }
+            },
+        )
+        .await?;
+    assert_eq!(result.rows_affected(), 70_000);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reprepares_cached_statement_after_schema_change() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
 
-    let result = sqlx::query("DELETE FROM heating_bills;")
-        .execute(&mut conn)
-        .await;
+    conn.execute("CREATE TEMPORARY TABLE reprepare (value INT4); INSERT INTO reprepare VALUES (1)")
+        .await?;
 
-    let result = result.unwrap();
-    assert_eq!(result.rows_affected(), 0);
+    let query = "SELECT value FROM reprepare WHERE $1";
 
-    let result =
-        sqlx::query("INSERT INTO heating_bills(month, cost) VALUES($1::winter_year_month, 100);")
-            .bind(WinterYearMonth {
-                year: 2021,
-                month: MonthId(1),
-            })
-            .execute(&mut conn)
-            .await;
+    let value: i32 = sqlx::query_scalar(query)
+        .bind(true)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 1);
 
-    let result = result.unwrap();
-    assert_eq!(result.rows_affected(), 1);
+    // the cached statement now returns a column of a different type
+    conn.execute("ALTER TABLE reprepare ALTER COLUMN value TYPE INT8")
+        .await?;
 
-    let result = sqlx::query("DELETE FROM heating_bills;")
-        .execute(&mut conn)
+    let value: i64 = sqlx::query_scalar(query)
+        .bind(true)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 1);
+
+    // inside a transaction the error has already aborted it, so it is returned as is
+    let mut tx = conn.begin().await?;
+
+    tx.execute("ALTER TABLE reprepare ALTER COLUMN value TYPE TEXT")
+        .await?;
+
+    let res = sqlx::query_scalar::<_, String>(query)
+        .bind(true)
+        .fetch_one(&mut *tx)
         .await;
+    let err = res.unwrap_err().into_database_error().unwrap();
+    assert_eq!(err.code().as_deref(), Some("0A000"));
 
-    let result = result.unwrap();
-    assert_eq!(result.rows_affected(), 1);
+    tx.rollback().await?;
+
+    let value: i64 = sqlx::query_scalar(query)
+        .bind(true)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 1);
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn it_resolves_custom_type_in_array() -> anyhow::Result<()> {
-    // Only supported in Postgres 11+
+async fn it_shrinks_statement_cache_at_runtime() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
-    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
-        return Ok(());
+
+    for i in 0..5 {
+        let val: i32 = sqlx::query_scalar(&*format!("SELECT {i}::int4"))
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(i, val);
     }
 
-    // language=PostgreSQL
-    conn.execute(
-        r#"
-DROP TABLE IF EXISTS pets;
-DROP TYPE IF EXISTS pet_name_and_race;
+    assert_eq!(5, conn.cached_statements_size());
 
-CREATE TYPE pet_name_and_race AS (
-  name TEXT,
-  race TEXT
-);
-CREATE TABLE pets (
-  owner TEXT NOT NULL,
-  name TEXT NOT NULL,
-  race TEXT NOT NULL,
-  PRIMARY KEY (owner, name)
-);
-INSERT INTO pets(owner, name, race)
-VALUES
-  ('Alice', 'Foo', 'cat');
-INSERT INTO pets(owner, name, race)
-VALUES
-  ('Alice', 'Bar', 'dog');
-    "#,
-    )
-    .await?;
+    conn.set_statement_cache_capacity(2).await?;
 
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct PetNameAndRace {
-        name: String,
-        race: String,
-    }
+    assert_eq!(2, conn.cached_statements_size());
 
-    impl sqlx::Type<Postgres> for PetNameAndRace {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            sqlx::postgres::PgTypeInfo::with_name("pet_name_and_race")
-        }
-    }
+    // the evicted statements were deallocated on the server too
+    let prepared: i64 = conn
+        .fetch_one(
+            "SELECT count(*) FROM pg_prepared_statements WHERE statement LIKE 'SELECT %::int4'",
+        )
+        .await?
+        .get(0);
 
-    impl<'r> sqlx::Decode<'r, Postgres> for PetNameAndRace {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)?;
-            let name = decoder.try_decode::<String>()?;
-            let race = decoder.try_decode::<String>()?;
-            Ok(Self { name, race })
-        }
-    }
+    assert_eq!(2, prepared);
 
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct PetNameAndRaceArray(Vec<PetNameAndRace>);
+    // the new capacity is kept when more statements are prepared
+    sqlx::query("SELECT 100::int4").execute(&mut conn).await?;
 
-    impl sqlx::Type<Postgres> for PetNameAndRaceArray {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            // Array type name is the name of the element type prefixed with `_`
-            sqlx::postgres::PgTypeInfo::with_name("_pet_name_and_race")
-        }
-    }
+    assert_eq!(2, conn.cached_statements_size());
 
-    impl<'r> sqlx::Decode<'r, Postgres> for PetNameAndRaceArray {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            Ok(Self(Vec::<PetNameAndRace>::decode(value)?))
-        }
-    }
+    Ok(())
+}
 
+#[sqlx_macros::test]
+async fn it_rejects_unknown_enum_label_before_sending() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
 
-    let row = sqlx::query("select owner, array_agg(row(name, race)::pet_name_and_race) as pets from pets group by owner")
+    // the parameter is inferred as the `status` enum from setup.sql
+    let statement = conn.prepare("SELECT $1::status::text").await?;
+
+    let label: String = statement
+        .query_scalar()
+        .bind("open")
         .fetch_one(&mut conn)
         .await?;
 
-    let pets: PetNameAndRaceArray = row.get("pets");
+    assert_eq!(label, "open");
+
+    let res: Result<String, _> = statement
+        .query_scalar()
+        .bind("opne")
+        .fetch_one(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::Encode(e)) => {
+            assert_eq!(
+                e.to_string(),
+                "invalid input value for enum status: \"opne\""
+            )
+        }
+        other => panic!("expected an encode error, got {other:?}"),
+    }
+
+    // the query never reached the server, so the connection is still usable
+    let label: String = statement
+        .query_scalar()
+        .bind("closed")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(label, "closed");
 
-    assert_eq!(pets.0.len(), 2);
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn it_resolves_custom_types_in_anonymous_records() -> anyhow::Result<()> {
-    use sqlx_core::error::Error;
-    // This request involves nested records and array types.
+async fn it_enforces_min_tls_version() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
 
-    // Only supported in Postgres 11+
     let mut conn = new::<Postgres>().await?;
-    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
+
+    let version: Option<String> =
+        sqlx::query_scalar("SELECT version FROM pg_stat_ssl WHERE pid = pg_backend_pid() AND ssl")
+            .fetch_optional(&mut conn)
+            .await?;
+
+    // nothing to check if the test connection does not use TLS
+    let Some(version) = version else {
         return Ok(());
+    };
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    // the version negotiated without a minimum is always allowed
+    PgConnection::connect_with(&options.clone().min_tls_version(TlsVersion::Tls12))
+        .await?
+        .close()
+        .await?;
+
+    let res = PgConnection::connect_with(&options.min_tls_version(TlsVersion::Tls13)).await;
+
+    match (version.as_str(), res) {
+        ("TLSv1.3", Ok(conn)) => conn.close().await?,
+
+        // native-tls cannot require TLS 1.3
+        (_, Err(sqlx::Error::Configuration(_))) if cfg!(feature = "tls-native-tls") => {}
+
+        // a server capped at TLS 1.2 (`postgres_13_tls12`) must refuse the handshake;
+        // rustls reports the protocol alert it receives as an I/O error
+        ("TLSv1.2", Err(sqlx::Error::Tls(_) | sqlx::Error::Io(_))) => {}
+
+        (version, res) => panic!("unexpected result with a server on {version}: {res:?}"),
     }
 
-    // language=PostgreSQL
-    conn.execute(
-        r#"
-DROP TABLE IF EXISTS repo_users;
-DROP TABLE IF EXISTS repositories;
-DROP TABLE IF EXISTS repo_memberships;
-DROP TYPE IF EXISTS repo_member;
+    Ok(())
+}
 
-CREATE TABLE repo_users (
-  user_id INT4 NOT NULL,
-  username TEXT NOT NULL,
-  PRIMARY KEY (user_id)
-);
-CREATE TABLE repositories (
-  repo_id INT4 NOT NULL,
-  repo_name TEXT NOT NULL,
-  PRIMARY KEY (repo_id)
-);
-CREATE TABLE repo_memberships (
-  repo_id INT4 NOT NULL,
-  user_id INT4 NOT NULL,
-  permission TEXT NOT NULL,
-  PRIMARY KEY (repo_id, user_id)
-);
-CREATE TYPE repo_member AS (
-  user_id INT4,
-  permission TEXT
-);
-INSERT INTO repo_users(user_id, username)
-VALUES
-  (101, 'alice'),
-  (102, 'bob'),
-  (103, 'charlie');
-INSERT INTO repositories(repo_id, repo_name)
-VALUES
-  (201, 'rust'),
-  (202, 'sqlx'),
-  (203, 'hello-world');
-INSERT INTO repo_memberships(repo_id, user_id, permission)
-VALUES
-  (201, 101, 'admin'),
-  (201, 102, 'write'),
-  (201, 103, 'read'),
-  (202, 102, 'admin');
-"#,
+#[sqlx_macros::test]
+async fn it_decrypts_an_encrypted_client_key() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut conn = new::<Postgres>().await?;
+
+    let uses_tls: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM pg_stat_ssl WHERE pid = pg_backend_pid() AND ssl)",
     )
+    .fetch_one(&mut conn)
     .await?;
 
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct RepoMember {
-        user_id: i32,
-        permission: String,
+    // the key is only loaded for a TLS handshake
+    if !uses_tls {
+        return Ok(());
     }
 
-    impl sqlx::Type<Postgres> for RepoMember {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            sqlx::postgres::PgTypeInfo::with_name("repo_member")
-        }
+    // `client.key`, encrypted with the password `sqlx-test`
+    let options: PgConnectOptions = env::var("DATABASE_URL")?
+        .parse::<PgConnectOptions>()?
+        .ssl_mode(PgSslMode::Require)
+        .ssl_client_cert("./tests/certs/client.crt")
+        .ssl_client_key("./tests/keys/client-encrypted.key");
+
+    let res =
+        PgConnection::connect_with(&options.clone().ssl_client_key_password("sqlx-test")).await;
+
+    if cfg!(feature = "tls-native-tls") {
+        res?.close().await?;
+    } else {
+        // rustls cannot decrypt keys
+        assert!(
+            matches!(res, Err(sqlx::Error::Configuration(_))),
+            "unexpected result with rustls: {res:?}"
+        );
     }
 
-    impl<'r> sqlx::Decode<'r, Postgres> for RepoMember {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)?;
-            let user_id = decoder.try_decode::<i32>()?;
-            let permission = decoder.try_decode::<String>()?;
-            Ok(Self {
-                user_id,
-                permission,
-            })
+    let res = PgConnection::connect_with(&options.ssl_client_key_password("wrong")).await;
+
+    assert!(
+        matches!(
+            (cfg!(feature = "tls-native-tls"), &res),
+            (true, Err(sqlx::Error::Tls(_))) | (false, Err(sqlx::Error::Configuration(_)))
+        ),
+        "unexpected result with the wrong password: {res:?}"
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_application_name() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.application_name("some-name");
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let row = sqlx::query("select current_setting('application_name') as app_name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let val: String = row.get("app_name");
+
+    assert_eq!("some-name", &val);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_derives_application_name_from_binary() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    // nothing is derived unless asked for
+    let mut conn = PgConnection::connect_with(&options).await?;
+    let val: String = sqlx::query_scalar("select current_setting('application_name')")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(val, "");
+
+    let mut conn =
+        PgConnection::connect_with(&options.clone().application_name_from_binary(true)).await?;
+    let val: String = sqlx::query_scalar("select current_setting('application_name')")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let exe = env::current_exe()?;
+    let expected = exe.file_stem().unwrap().to_string_lossy();
+    assert_eq!(val, expected);
+
+    // an explicit name still wins
+    let mut conn = PgConnection::connect_with(
+        &options
+            .application_name("some-name")
+            .application_name_from_binary(true),
+    )
+    .await?;
+    let val: String = sqlx::query_scalar("select current_setting('application_name')")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(val, "some-name");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_suffixes_application_name() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    for suffix in [
+        PgApplicationNameSuffix::Counter,
+        PgApplicationNameSuffix::Uuid,
+    ] {
+        let options = options
+            .clone()
+            .application_name("some-name")
+            .application_name_suffix(Some(suffix));
+
+        let mut names = Vec::new();
+
+        for _ in 0..3 {
+            let mut conn = PgConnection::connect_with(&options).await?;
+            let val: String = sqlx::query_scalar("select current_setting('application_name')")
+                .fetch_one(&mut conn)
+                .await?;
+
+            assert!(val.starts_with("some-name-"), "{val:?}");
+            assert!(!names.contains(&val), "{val:?} is not distinct");
+            names.push(val);
         }
     }
 
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct RepoMemberArray(Vec<RepoMember>);
+    Ok(())
+}
 
-    impl sqlx::Type<Postgres> for RepoMemberArray {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            // Array type name is the name of the element type prefixed with `_`
-            sqlx::postgres::PgTypeInfo::with_name("_repo_member")
-        }
+#[sqlx_macros::test]
+async fn it_reports_server_capabilities() -> anyhow::Result<()> {
+    let conn = new::<Postgres>().await?;
+
+    assert_eq!(conn.integer_datetimes(), Some(true));
+    assert_eq!(conn.parameter_status("integer_datetimes"), Some("on"));
+
+    let encoding = conn
+        .server_encoding()
+        .expect("server_encoding is always reported");
+    assert!(!encoding.is_empty());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sends_extra_startup_parameters() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    let mut conn = PgConnection::connect_with(&options.clone().extra_startup_parameters([
+        ("default_transaction_read_only".into(), "on".into()),
+        ("sqlx.test_parameter".into(), "some value".into()),
+        ("TimeZone".into(), "Europe/Berlin".into()),
+    ]))
+    .await?;
+
+    // only reported by the server since Postgres 14
+    let val: String = sqlx::query_scalar("SHOW default_transaction_read_only")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(val, "on");
+
+    assert_eq!(conn.parameter_status("TimeZone"), Some("Europe/Berlin"));
+
+    let val: String = sqlx::query_scalar("select current_setting('sqlx.test_parameter')")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(val, "some value");
+
+    for key in ["", "user", "client_encoding", "_pq_.something", "a\0b"] {
+        let res = PgConnection::connect_with(
+            &options
+                .clone()
+                .extra_startup_parameters([(key.into(), "on".into())]),
+        )
+        .await;
+
+        assert!(
+            matches!(res, Err(sqlx::Error::Configuration(_))),
+            "{key:?} should be rejected"
+        );
     }
 
-    impl<'r> sqlx::Decode<'r, Postgres> for RepoMemberArray {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            Ok(Self(Vec::<RepoMember>::decode(value)?))
-        }
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rejects_oversized_bind_parameters() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.max_bind_size(1024);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    // a single parameter over the limit
+    let res = sqlx::query("SELECT $1::bytea")
+        .bind(vec![0_u8; 2048])
+        .execute(&mut conn)
+        .await;
+
+    let err = res.expect_err("expected the bind size guard to fire");
+    assert!(matches!(err, sqlx::Error::Encode(_)));
+    assert!(err.to_string().contains("bind parameter $1 is too large"));
+
+    // every parameter fits, but the total does not
+    let res = sqlx::query("SELECT $1::bytea, $2::bytea")
+        .bind(vec![0_u8; 600])
+        .bind(vec![0_u8; 600])
+        .execute(&mut conn)
+        .await;
+
+    let err = res.expect_err("expected the bind size guard to fire");
+    assert!(err.to_string().contains("bind parameters are too large"));
+
+    // nothing was sent, so the connection is still usable
+    let len: i32 = sqlx::query_scalar("SELECT length($1::bytea)")
+        .bind(vec![0_u8; 512])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(len, 512);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_inlines_arguments_with_prefer_simple_protocol() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.prefer_simple_protocol(true);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    conn.execute("CREATE TEMPORARY TABLE simple_protocol_injection (value TEXT)")
+        .await?;
+
+    let values = [
+        "'); DROP TABLE simple_protocol_injection; --",
+        "\\'); DROP TABLE simple_protocol_injection; --",
+        "$1 $$ $tag$ \"quoted\" /* comment */ -- comment",
+        "O'Brien \\ backslash \u{1F980}",
+    ];
+
+    for value in values {
+        sqlx::query("INSERT INTO simple_protocol_injection (value) VALUES ($1)")
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
     }
 
+    let stored: Vec<String> = sqlx::query_scalar("SELECT value FROM simple_protocol_injection")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(stored, values);
+
+    let row: (i32, Option<i64>, f64, bool, Vec<String>, Vec<u8>) =
+        sqlx::query_as("SELECT $1, $2, $3, $4, $5, $6")
+            .bind(-5_i32)
+            .bind(None::<i64>)
+            .bind(0.1_f64)
+            .bind(true)
+            .bind(vec!["a\"b", "c\\d", "{e,f}", ""])
+            .bind(vec![0_u8, b'\'', b'\\', 255])
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(
+        row,
+        (
+            -5,
+            None,
+            0.1,
+            true,
+            vec!["a\"b".into(), "c\\d".into(), "{e,f}".into(), "".into()],
+            vec![0, b'\'', b'\\', 255],
+        )
+    );
+
+    // a negative literal after `-` must not become a comment
+    let negated: i32 = sqlx::query_scalar("SELECT -$1")
+        .bind(-5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(negated, 5);
+
+    // placeholders in string literals and comments are left alone
+    let text: String = sqlx::query_scalar("SELECT '$1' || $1 -- $1")
+        .bind("x")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(text, "$1x");
+
+    let err = sqlx::query("SELECT $2")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await
+        .expect_err("expected an error for an unbound placeholder");
+
+    assert!(err.to_string().contains("$2"));
+
+    // nothing was prepared
+    assert_eq!(conn.cached_statements_size(), 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+#[cfg(all(feature = "chrono", feature = "uuid", feature = "bigdecimal"))]
+async fn it_inlines_typed_arguments_with_prefer_simple_protocol() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgInterval, PgRange};
+    use sqlx::types::chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+    use sqlx::types::{BigDecimal, Uuid};
+
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.prefer_simple_protocol(true);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let ancient = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+    let time = NaiveTime::from_hms_micro_opt(23, 59, 58, 123_456).unwrap();
+    let timestamp: DateTime<Utc> = "1999-12-31T23:59:59.999999Z".parse()?;
+    let interval = PgInterval {
+        months: -14,
+        days: 3,
+        microseconds: 3_723_000_004,
+    };
+    let numeric: BigDecimal = "-12345678901234567890.000120".parse()?;
+    let uuid = Uuid::parse_str("a3fd2e54-7c0d-4e3e-8a3b-28f1a5cbe7c1")?;
+    let range = PgRange::from(1_i32..10);
+
+    // these can't be decoded from text, so compare them in Postgres
+    let is_ancient: bool = sqlx::query_scalar("SELECT $1 = '0045-03-15 BC'::date")
+        .bind(ancient)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(is_ancient);
+
+    let interval_text: String = sqlx::query_scalar("SELECT $1::text")
+        .bind(&interval)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(interval_text, "-1 years -2 mons +3 days 01:02:03.000004");
+
+    let row: (
+        NaiveDate,
+        NaiveTime,
+        DateTime<Utc>,
+        BigDecimal,
+        Uuid,
+        PgRange<i32>,
+    ) = sqlx::query_as("SELECT $1, $2, $3, $4, $5, $6")
+        .bind(date)
+        .bind(time)
+        .bind(timestamp)
+        .bind(&numeric)
+        .bind(uuid)
+        .bind(range)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row, (date, time, timestamp, numeric, uuid, range));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()> {
+    new::<Postgres>().await?.execute("SET NAMES 'UTF8'").await?;
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
 
-    #[derive(Debug, sqlx::FromRow)]
-    #[allow(dead_code)] // We don't actually read these fields.
-    struct Row {
-        count: i64,
-        items: Vec<(i32, String, RepoMemberArray)>,
+    let tweet_id: i64 =
+        sqlx::query_scalar("INSERT INTO tweet ( text ) VALUES ( 'Hello, World' ) RETURNING id")
+            .fetch_one(&mut *tx)
+            .await?;
+
+    let statement = tx.prepare("SELECT * FROM tweet WHERE id = $1").await?;
+
+    assert_eq!(statement.column(0).name(), "id");
+    assert_eq!(statement.column(1).name(), "created_at");
+    assert_eq!(statement.column(2).name(), "text");
+    assert_eq!(statement.column(3).name(), "owner_id");
+
+    assert_eq!(statement.column(0).type_info().name(), "INT8");
+    assert_eq!(statement.column(1).type_info().name(), "TIMESTAMPTZ");
+    assert_eq!(statement.column(2).type_info().name(), "TEXT");
+    assert_eq!(statement.column(3).type_info().name(), "INT8");
+
+    let row = statement.query().bind(tweet_id).fetch_one(&mut *tx).await?;
+    let tweet_text: &str = row.try_get("text")?;
+
+    assert_eq!(tweet_text, "Hello, World");
+
+    Ok(())
+}
+
+// repro is more reliable with the basic scheduler used by `#[tokio::test]`
+#[cfg(feature = "_rt-tokio")]
+#[tokio::test]
+async fn test_issue_622() -> anyhow::Result<()> {
+    use std::time::Instant;
+
+    setup_if_needed();
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1) // also fails with higher counts, e.g. 5
+        .connect(&std::env::var("DATABASE_URL").unwrap())
+        .await?;
+
+    println!("pool state: {pool:?}");
+
+    let mut handles = vec![];
+
+    // given repro spawned 100 tasks but I found it reliably reproduced with 3
+    for i in 0..3 {
+        let pool = pool.clone();
+
+        handles.push(sqlx_core::rt::spawn(async move {
+            {
+                let mut conn = pool.acquire().await.unwrap();
+
+                let _ = sqlx::query("SELECT 1").fetch_one(&mut *conn).await.unwrap();
+
+                // conn gets dropped here and should be returned to the pool
+            }
+
+            // (do some other work here without holding on to a connection)
+            // this actually fixes the issue, depending on the timeout used
+            // sqlx_core::rt::sleep(Duration::from_millis(500)).await;
+
+            {
+                let start = Instant::now();
+                match pool.acquire().await {
+                    Ok(conn) => {
+                        println!("{} acquire took {:?}", i, start.elapsed());
+                        drop(conn);
+                    }
+                    Err(e) => panic!("{i} acquire returned error: {e} pool state: {pool:?}"),
+                }
+            }
+
+            Result::<(), anyhow::Error>::Ok(())
+        }));
     }
+
+    futures::future::try_join_all(handles).await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_describe_outer_join_nullable() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // test nullability inference for various joins
+
+    // inner join, nullability should not be overridden
     // language=PostgreSQL
-    let row: Result<Row, Error> = sqlx::query_as::<_, Row>(
-        r"
-        WITH
-          members_by_repo AS (
-            SELECT repo_id,
-              ARRAY_AGG(ROW (user_id, permission)::repo_member) AS members
-            FROM repo_memberships
-            GROUP BY repo_id
-          ),
-          repos AS (
-            SELECT repo_id, repo_name, COALESCE(members, '{}') AS members
-            FROM repositories
-              LEFT OUTER JOIN members_by_repo USING (repo_id)
-            ORDER BY repo_id
-          ),
-          repo_array AS (
-            SELECT COALESCE(ARRAY_AGG(repos.*), '{}') AS items
-            FROM repos
-          ),
-          repo_count AS (
-            SELECT COUNT(*) AS count
-            FROM repos
-          )
-        SELECT count, items
-        FROM repo_count, repo_array
-        ;
-    ",
+    let describe = conn
+        .describe(
+            "select tweet.id
+    from tweet
+    inner join products on products.name = tweet.text",
+        )
+        .await?;
+
+    assert_eq!(describe.nullable(0), Some(false));
+
+    // language=PostgreSQL
+    let describe = conn
+        .describe(
+            "select tweet.id
+from (values (null)) vals(val)
+         left join tweet on false",
+        )
+        .await?;
+
+    // tweet.id is marked NOT NULL but it's brought in from a left-join here
+    // which should make it nullable
+    assert_eq!(describe.nullable(0), Some(true));
+
+    // make sure we don't mis-infer for the outer half of the join
+    // language=PostgreSQL
+    let describe = conn
+        .describe(
+            "select tweet1.id, tweet2.id
+    from tweet tweet1
+    left join tweet tweet2 on false",
+        )
+        .await?;
+
+    assert_eq!(describe.nullable(0), Some(false));
+    assert_eq!(describe.nullable(1), Some(true));
+
+    // right join, nullability should be inverted
+    // language=PostgreSQL
+    let describe = conn
+        .describe(
+            "select tweet1.id, tweet2.id
+    from tweet tweet1
+    right join tweet tweet2 on false",
+        )
+        .await?;
+
+    assert_eq!(describe.nullable(0), Some(true));
+    assert_eq!(describe.nullable(1), Some(false));
+
+    // full outer join, both tables are nullable
+    // language=PostgreSQL
+    let describe = conn
+        .describe(
+            "select tweet1.id, tweet2.id
+    from tweet tweet1
+    full join tweet tweet2 on false",
+        )
+        .await?;
+
+    assert_eq!(describe.nullable(0), Some(true));
+    assert_eq!(describe.nullable(1), Some(true));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_adapts_fetch_size_to_row_width() -> anyhow::Result<()> {
+    use sqlx::postgres::PgFetchSize;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let narrow = "SELECT i FROM generate_series(1, 10000) i";
+
+    let fixed: Vec<Vec<PgRow>> = conn
+        .fetch_batches(sqlx::query(narrow), PgFetchSize::Fixed(100))
+        .try_collect()
+        .await?;
+
+    let adaptive: Vec<Vec<PgRow>> = conn
+        .adaptive_fetch(sqlx::query(narrow), 64 * 1024)
+        .try_collect()
+        .await?;
+
+    // every row arrives exactly once, in order
+    for batches in [&fixed, &adaptive] {
+        let values: Vec<i32> = batches.iter().flatten().map(|row| row.get(0)).collect();
+        assert_eq!(values, (1..=10_000).collect::<Vec<_>>());
+    }
+
+    // one round-trip per batch
+    assert_eq!(fixed.len(), 100);
+    assert!(
+        adaptive.len() <= 3,
+        "expected at most 3 round-trips for narrow rows, got {}",
+        adaptive.len()
+    );
+
+    // wide rows are fetched a few at a time once their size is known
+    let wide: Vec<Vec<PgRow>> = conn
+        .adaptive_fetch(
+            sqlx::query("SELECT repeat('x', $1) FROM generate_series(1, 100)").bind(16 * 1024),
+            64 * 1024,
+        )
+        .try_collect()
+        .await?;
+
+    assert_eq!(wide.iter().map(Vec::len).sum::<usize>(), 100);
+    assert!(wide[1..].iter().all(|batch| batch.len() <= 4));
+
+    // abandoning a fetch part-way leaves the connection usable
+    {
+        let mut batches = conn.fetch_batches(sqlx::query(narrow), PgFetchSize::Fixed(10));
+        assert_eq!(batches.try_next().await?.map(|batch| batch.len()), Some(10));
+    }
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_execute_a_portal_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut portal = conn
+        .bind_portal(sqlx::query("SELECT i FROM generate_series(1, $1) i").bind(7_i32))
+        .await?;
+    assert!(!portal.is_suspended());
+
+    let first = portal.execute(4).await?;
+    assert_eq!(first.len(), 4);
+    assert!(portal.is_suspended());
+
+    // the suspended portal resumes where it left off
+    let rest = portal.execute(4).await?;
+    assert_eq!(rest.len(), 3);
+    assert!(portal.is_complete());
+    assert!(portal.execute(4).await?.is_empty());
+
+    portal.close().await?;
+
+    let values: Vec<i32> = first.iter().chain(&rest).map(|row| row.get(0)).collect();
+    assert_eq!(values, (1..=7).collect::<Vec<_>>());
+
+    // dropping a suspended portal leaves the connection usable
+    {
+        let mut portal = conn
+            .bind_portal(sqlx::query("SELECT i FROM generate_series(1, 100) i"))
+            .await?;
+        assert_eq!(portal.execute(10).await?.len(), 10);
+    }
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_a_portal_after_an_error() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut portal = conn
+        .bind_portal(sqlx::query(
+            "SELECT 1 / (3 - i) FROM generate_series(1, 5) i",
+        ))
+        .await?;
+
+    // the first two rows are fine, the third divides by zero
+    assert_eq!(portal.execute(2).await?.len(), 2);
+    assert!(portal.execute(2).await.is_err());
+    assert!(!portal.is_suspended());
+
+    // a failed portal errors straight away instead of waiting on the server
+    let again = sqlx_core::rt::timeout(Duration::from_secs(5), portal.execute(2)).await;
+    assert!(matches!(again, Ok(Err(_))));
+
+    portal.close().await?;
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_fetch_update_returning_with_result() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE fetch_with_result (id INTEGER PRIMARY KEY, active BOOLEAN NOT NULL);
+
+INSERT INTO fetch_with_result (id, active) VALUES (1, true), (2, true), (3, false), (4, true);
+        "#,
     )
-    .fetch_one(&mut conn)
-    .await;
+    .await?;
+
+    let mut rows = conn.fetch_with_result(sqlx::query(
+        "UPDATE fetch_with_result SET active = false WHERE active RETURNING id",
+    ));
+
+    let mut ids = Vec::new();
+
+    while let Some(row) = rows.try_next().await? {
+        ids.push(row.try_get::<i32, _>("id")?);
+    }
+
+    ids.sort();
+
+    assert_eq!(ids, [1, 2, 4]);
+    assert_eq!(rows.query_result().rows_affected(), 3);
+
+    drop(rows);
+
+    // no rows returned, but the count is still reported
+    let mut rows = conn.fetch_with_result(sqlx::query(
+        "UPDATE fetch_with_result SET active = true WHERE id < 3",
+    ));
+
+    assert!(rows.try_next().await?.is_none());
+    assert_eq!(rows.query_result().rows_affected(), 2);
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[sqlx_macros::test]
+async fn it_computes_timestamp_range_width_like_postgres() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgInterval, PgRange};
+    use sqlx::types::chrono::{DateTime, Utc};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let (range, width): (PgRange<DateTime<Utc>>, PgInterval) = sqlx::query_as(
+        "SELECT r, upper(r) - lower(r) \
+         FROM (SELECT tstzrange('2024-02-27 12:00:00+00', '2024-03-01 13:30:00.25+00') AS r) t",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(range.width(), Some(width));
+
+    let range: PgRange<DateTime<Utc>> =
+        sqlx::query_scalar("SELECT tstzrange('2024-02-27 12:00:00+00', NULL)")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(range.width(), None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_listener_cleanup() -> anyhow::Result<()> {
+    use sqlx_core::rt::timeout;
+
+    use sqlx::pool::PoolOptions;
+    use sqlx::postgres::PgListener;
+
+    // Create a connection on which to send notifications
+    let mut notify_conn = new::<Postgres>().await?;
+
+    // Create a pool with exactly one connection so we can
+    // deterministically test the cleanup.
+    let pool = PoolOptions::<Postgres>::new()
+        .min_connections(1)
+        .max_connections(1)
+        .test_before_acquire(true)
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    let mut listener = PgListener::connect_with(&pool).await?;
+    listener.listen("test_channel").await?;
+
+    // Checks for a notification on the test channel
+    async fn try_recv(listener: &mut PgListener) -> anyhow::Result<bool> {
+        match timeout(Duration::from_millis(100), listener.recv()).await {
+            Ok(res) => {
+                res?;
+                Ok(true)
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    // Check no notification is received before one is sent
+    assert!(!try_recv(&mut listener).await?, "Notification not sent");
+
+    // Check notification is sent and received
+    notify_conn.execute("NOTIFY test_channel").await?;
+    assert!(
+        try_recv(&mut listener).await?,
+        "Notification sent and received"
+    );
+    assert!(
+        !try_recv(&mut listener).await?,
+        "Notification is not duplicated"
+    );
+
+    // Test that cleanup stops listening on the channel
+    drop(listener);
+    let mut listener = PgListener::connect_with(&pool).await?;
+
+    // Check notification is sent but not received
+    notify_conn.execute("NOTIFY test_channel").await?;
+    assert!(
+        !try_recv(&mut listener).await?,
+        "Notification is not received on fresh listener"
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_listener_try_recv_buffered() -> anyhow::Result<()> {
+    use sqlx_core::rt::timeout;
+
+    use sqlx::pool::PoolOptions;
+    use sqlx::postgres::PgListener;
+
+    // Create a connection on which to send notifications
+    let mut notify_conn = new::<Postgres>().await?;
+
+    let pool = PoolOptions::<Postgres>::new()
+        .min_connections(1)
+        .max_connections(1)
+        .test_before_acquire(true)
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    let mut listener = PgListener::connect_with(&pool).await?;
+    listener.listen("test_channel2").await?;
+
+    // Checks for a notification on the test channel
+    async fn try_recv(listener: &mut PgListener) -> anyhow::Result<bool> {
+        match timeout(Duration::from_millis(100), listener.recv()).await {
+            Ok(res) => {
+                res?;
+                Ok(true)
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    // Check no notification is buffered, since we haven't sent one.
+    assert!(listener.next_buffered().is_none());
+
+    // Send five notifications transactionally, so they all arrive at once.
+    {
+        let mut txn = notify_conn.begin().await?;
+        for i in 0..5 {
+            txn.execute(format!("NOTIFY test_channel2, 'payload {i}'").as_str())
+                .await?;
+        }
+        txn.commit().await?;
+    }
+
+    // Still no notifications buffered, since we haven't awaited the listener yet.
+    assert!(listener.next_buffered().is_none());
+
+    // Activate connection.
+    sqlx::query!("SELECT 1 AS one")
+        .fetch_all(&mut listener)
+        .await?;
+
+    // The next five notifications should now be buffered.
+    for i in 0..5 {
+        assert!(
+            listener.next_buffered().is_some(),
+            "Notification {i} was not buffered"
+        );
+    }
+
+    // Should be no more.
+    assert!(listener.next_buffered().is_none());
+
+    // Even if we wait.
+    assert!(!try_recv(&mut listener).await?, "Notification received");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_listener_allows_pool_to_close() -> anyhow::Result<()> {
+    let pool = pool::<Postgres>().await?;
+
+    // acquires and holds a connection which would normally prevent the pool from closing
+    let mut listener = PgListener::connect_with(&pool).await?;
+
+    sqlx_core::rt::spawn(async move {
+        listener.recv().await.unwrap();
+    });
+
+    // would previously hang forever since `PgListener` had no way to know the pool wanted to close
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_listener_hub_dispatches_to_every_subscriber() -> anyhow::Result<()> {
+    use sqlx::postgres::PgListenerHub;
+    use sqlx_core::rt::timeout;
+
+    let pool = pool::<Postgres>().await?;
+    let mut notify_conn = new::<Postgres>().await?;
+
+    let hub = PgListenerHub::connect_with(&pool).await?;
+
+    let mut first = hub.subscribe("test_listener_hub").await?;
+    let mut second = hub.subscribe("test_listener_hub").await?;
+    let mut other = hub.subscribe("test_listener_hub_other").await?;
+
+    // Both subscriptions share the one listening connection
+    assert_eq!(pool.size(), 1);
+
+    notify_conn
+        .execute("NOTIFY test_listener_hub, 'hello'")
+        .await?;
+
+    for subscription in [&mut first, &mut second] {
+        let notification = timeout(Duration::from_secs(5), subscription.recv())
+            .await?
+            .expect("hub stopped dispatching");
+
+        assert_eq!(notification.channel(), "test_listener_hub");
+        assert_eq!(notification.payload(), "hello");
+    }
+
+    // Subscribers of other channels do not see the notification
+    assert!(timeout(Duration::from_millis(100), other.recv())
+        .await
+        .is_err());
+
+    // Notifications past the capacity of a subscription that is not keeping up are discarded
+    let capacity = PgListenerHub::SUBSCRIPTION_CAPACITY as i32;
+
+    sqlx::query(
+        "SELECT pg_notify('test_listener_hub_other', g::text) FROM generate_series(1, $1) g",
+    )
+    .bind(capacity + 10)
+    .execute(&mut notify_conn)
+    .await?;
+
+    // once this arrives, every notification before it has been dispatched
+    notify_conn
+        .execute("NOTIFY test_listener_hub, 'done'")
+        .await?;
+
+    let done = timeout(Duration::from_secs(5), first.recv())
+        .await?
+        .expect("hub stopped dispatching");
+    assert_eq!(done.payload(), "done");
+
+    let mut payloads = Vec::new();
+
+    while let Ok(Some(notification)) = timeout(Duration::from_millis(100), other.recv()).await {
+        payloads.push(notification.payload().parse::<i32>()?);
+    }
+
+    assert_eq!(payloads, (1..=capacity).collect::<Vec<_>>());
+
+    // Dropping the hub ends every subscription
+    drop(hub);
+    assert!(timeout(Duration::from_secs(5), first.recv())
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_connection_wait_for_notify() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut notify_conn = new::<Postgres>().await?;
+
+    let notifier = sqlx_core::rt::spawn(async move {
+        // give the waiting connection time to `LISTEN`
+        sqlx_core::rt::sleep(Duration::from_millis(200)).await;
+
+        notify_conn
+            .execute("NOTIFY test_wait_for_notify_other, 'ignored'; NOTIFY test_wait_for_notify, 'hello'")
+            .await?;
+
+        Ok::<_, sqlx::Error>(notify_conn)
+    });
+
+    let notification = conn
+        .wait_for_notify("test_wait_for_notify", Duration::from_secs(5))
+        .await?
+        .expect("timed out waiting for notification");
+
+    assert_eq!(notification.channel(), "test_wait_for_notify");
+    assert_eq!(notification.payload(), "hello");
+
+    let mut notify_conn = notifier.await?;
+
+    // Nothing raised on the channel: times out
+    let notification = conn
+        .wait_for_notify("test_wait_for_notify", Duration::from_millis(100))
+        .await?;
+    assert!(notification.is_none());
+
+    // The channel was unlistened, so later notifications do not disturb queries
+    notify_conn
+        .execute("NOTIFY test_wait_for_notify, 'late'")
+        .await?;
+    let value: i32 = conn.fetch_one("SELECT 1").await?.try_get(0)?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_listener_implements_acquire() -> anyhow::Result<()> {
+    use sqlx::Acquire;
+
+    let pool = pool::<Postgres>().await?;
+
+    let mut listener = PgListener::connect_with(&pool).await?;
+    listener
+        .listen("test_pg_listener_implements_acquire")
+        .await?;
+
+    // Start a transaction on the underlying connection
+    let mut txn = listener.begin().await?;
+
+    // This will reuse the same connection, so this connection should be listening to the channel
+    let channels: Vec<String> = sqlx::query_scalar("SELECT pg_listening_channels()")
+        .fetch_all(&mut *txn)
+        .await?;
+
+    assert_eq!(channels, vec!["test_pg_listener_implements_acquire"]);
+
+    // Send a notification
+    sqlx::query("NOTIFY test_pg_listener_implements_acquire, 'hello'")
+        .execute(&mut *txn)
+        .await?;
+
+    txn.commit().await?;
+
+    // And now we can receive the notification we sent in the transaction
+    let notification = listener.recv().await?;
+    assert_eq!(
+        notification.channel(),
+        "test_pg_listener_implements_acquire"
+    );
+    assert_eq!(notification.payload(), "hello");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_notification_payload_json() -> anyhow::Result<()> {
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Event<'a> {
+        id: i64,
+        kind: &'a str,
+        tags: Vec<String>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+    let mut listener = PgListener::connect_with(&pool::<Postgres>().await?).await?;
+    listener.listen("test_pg_notification_payload_json").await?;
+
+    conn.execute(
+        r#"NOTIFY test_pg_notification_payload_json, '{"id": 7, "kind": "created", "tags": ["a", "b"]}'"#,
+    )
+    .await?;
+
+    let notification = listener.recv().await?;
+    assert_eq!(
+        notification.payload_json::<Event>()?,
+        Event {
+            id: 7,
+            kind: "created",
+            tags: vec!["a".into(), "b".into()],
+        }
+    );
+
+    // malformed JSON is an error rather than a panic
+    conn.execute("NOTIFY test_pg_notification_payload_json, '{\"id\": 7'")
+        .await?;
+
+    let notification = listener.recv().await?;
+    let err = notification.payload_json::<Event>().unwrap_err();
+    assert!(err.is_eof(), "{err}");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_encodes_tuples_as_composites() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DROP FUNCTION IF EXISTS describe_tuple_arg;
+DROP TYPE IF EXISTS tuple_arg;
+
+CREATE TYPE tuple_arg AS (id INT4, name TEXT);
+CREATE FUNCTION describe_tuple_arg(arg tuple_arg) RETURNS TEXT
+    LANGUAGE SQL AS $$ SELECT arg.id || ': ' || arg.name $$;
+    "#,
+    )
+    .await?;
+
+    let described: String = sqlx::query_scalar("SELECT describe_tuple_arg($1)")
+        .bind((42_i32, "forty-two".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(described, "42: forty-two");
+
+    // the composite type can also come from a cast
+    let name: String = sqlx::query_scalar("SELECT ($1::tuple_arg).name")
+        .bind((7_i32, "seven"))
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(name, "seven");
+
+    // fields of the wrong type are rejected by the server
+    let res = sqlx::query_scalar::<_, String>("SELECT describe_tuple_arg($1)")
+        .bind((42_i64, "forty-two"))
+        .fetch_one(&mut conn)
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_supports_domain_types_in_composite_domain_types() -> anyhow::Result<()> {
+    // Only supported in Postgres 11+
+    let mut conn = new::<Postgres>().await?;
+    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+DROP TABLE IF EXISTS heating_bills;
+DROP DOMAIN IF EXISTS winter_year_month;
+DROP TYPE IF EXISTS year_month;
+DROP DOMAIN IF EXISTS month_id;
+
+CREATE DOMAIN month_id AS INT2 CHECK (1 <= value AND value <= 12);
+CREATE TYPE year_month AS (year INT4, month month_id);
+CREATE DOMAIN winter_year_month AS year_month CHECK ((value).month <= 3);
+CREATE TABLE heating_bills (
+  month winter_year_month NOT NULL PRIMARY KEY,
+  cost INT4 NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct MonthId(i16);
+
+    impl sqlx::Type<Postgres> for MonthId {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::with_name("month_id")
+        }
+
+        fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+            *ty == Self::type_info()
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for MonthId {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            Ok(Self(<i16 as sqlx::Decode<Postgres>>::decode(value)?))
+        }
+    }
+
+    impl<'q> sqlx::Encode<'q, Postgres> for MonthId {
+        fn encode_by_ref(
+            &self,
+            buf: &mut sqlx::postgres::PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, BoxDynError> {
+            <i16 as sqlx::Encode<Postgres>>::encode(self.0, buf)
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct WinterYearMonth {
+        year: i32,
+        month: MonthId,
+    }
+
+    impl sqlx::Type<Postgres> for WinterYearMonth {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::with_name("winter_year_month")
+        }
+
+        fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+            *ty == Self::type_info()
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for WinterYearMonth {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)?;
+
+            let year = decoder.try_decode::<i32>()?;
+            let month = decoder.try_decode::<MonthId>()?;
+
+            Ok(Self { year, month })
+        }
+    }
+
+    impl<'q> sqlx::Encode<'q, Postgres> for WinterYearMonth {
+        fn encode_by_ref(
+            &self,
+            buf: &mut sqlx::postgres::PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+            let mut encoder = sqlx::postgres::types::PgRecordEncoder::new(buf);
+            encoder.encode(self.year)?;
+            encoder.encode(self.month)?;
+            encoder.finish();
+            Ok(sqlx::encode::IsNull::No)
+        }
+    }
+    let mut conn = new::<Postgres>().await?;
+
+    let result = sqlx::query("DELETE FROM heating_bills;")
+        .execute(&mut conn)
+        .await;
+
+    let result = result.unwrap();
+    assert_eq!(result.rows_affected(), 0);
+
+    let result =
+        sqlx::query("INSERT INTO heating_bills(month, cost) VALUES($1::winter_year_month, 100);")
+            .bind(WinterYearMonth {
+                year: 2021,
+                month: MonthId(1),
+            })
+            .execute(&mut conn)
+            .await;
+
+    let result = result.unwrap();
+    assert_eq!(result.rows_affected(), 1);
+
+    let result = sqlx::query("DELETE FROM heating_bills;")
+        .execute(&mut conn)
+        .await;
+
+    let result = result.unwrap();
+    assert_eq!(result.rows_affected(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_resolves_custom_type_in_array() -> anyhow::Result<()> {
+    // Only supported in Postgres 11+
+    let mut conn = new::<Postgres>().await?;
+    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
+        return Ok(());
+    }
+
+    // language=PostgreSQL
+    conn.execute(
+        r#"
+DROP TABLE IF EXISTS pets;
+DROP TYPE IF EXISTS pet_name_and_race;
+
+CREATE TYPE pet_name_and_race AS (
+  name TEXT,
+  race TEXT
+);
+CREATE TABLE pets (
+  owner TEXT NOT NULL,
+  name TEXT NOT NULL,
+  race TEXT NOT NULL,
+  PRIMARY KEY (owner, name)
+);
+INSERT INTO pets(owner, name, race)
+VALUES
+  ('Alice', 'Foo', 'cat');
+INSERT INTO pets(owner, name, race)
+VALUES
+  ('Alice', 'Bar', 'dog');
+    "#,
+    )
+    .await?;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct PetNameAndRace {
+        name: String,
+        race: String,
+    }
+
+    impl sqlx::Type<Postgres> for PetNameAndRace {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::with_name("pet_name_and_race")
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for PetNameAndRace {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)?;
+            let name = decoder.try_decode::<String>()?;
+            let race = decoder.try_decode::<String>()?;
+            Ok(Self { name, race })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct PetNameAndRaceArray(Vec<PetNameAndRace>);
+
+    impl sqlx::Type<Postgres> for PetNameAndRaceArray {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            // Array type name is the name of the element type prefixed with `_`
+            sqlx::postgres::PgTypeInfo::with_name("_pet_name_and_race")
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for PetNameAndRaceArray {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            Ok(Self(Vec::<PetNameAndRace>::decode(value)?))
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let row = sqlx::query("select owner, array_agg(row(name, race)::pet_name_and_race) as pets from pets group by owner")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let pets: PetNameAndRaceArray = row.get("pets");
+
+    assert_eq!(pets.0.len(), 2);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_resolves_custom_types_in_anonymous_records() -> anyhow::Result<()> {
+    use sqlx_core::error::Error;
+    // This request involves nested records and array types.
+
+    // Only supported in Postgres 11+
+    let mut conn = new::<Postgres>().await?;
+    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
+        return Ok(());
+    }
+
+    // language=PostgreSQL
+    conn.execute(
+        r#"
+DROP TABLE IF EXISTS repo_users;
+DROP TABLE IF EXISTS repositories;
+DROP TABLE IF EXISTS repo_memberships;
+DROP TYPE IF EXISTS repo_member;
+
+CREATE TABLE repo_users (
+  user_id INT4 NOT NULL,
+  username TEXT NOT NULL,
+  PRIMARY KEY (user_id)
+);
+CREATE TABLE repositories (
+  repo_id INT4 NOT NULL,
+  repo_name TEXT NOT NULL,
+  PRIMARY KEY (repo_id)
+);
+CREATE TABLE repo_memberships (
+  repo_id INT4 NOT NULL,
+  user_id INT4 NOT NULL,
+  permission TEXT NOT NULL,
+  PRIMARY KEY (repo_id, user_id)
+);
+CREATE TYPE repo_member AS (
+  user_id INT4,
+  permission TEXT
+);
+INSERT INTO repo_users(user_id, username)
+VALUES
+  (101, 'alice'),
+  (102, 'bob'),
+  (103, 'charlie');
+INSERT INTO repositories(repo_id, repo_name)
+VALUES
+  (201, 'rust'),
+  (202, 'sqlx'),
+  (203, 'hello-world');
+INSERT INTO repo_memberships(repo_id, user_id, permission)
+VALUES
+  (201, 101, 'admin'),
+  (201, 102, 'write'),
+  (201, 103, 'read'),
+  (202, 102, 'admin');
+"#,
+    )
+    .await?;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct RepoMember {
+        user_id: i32,
+        permission: String,
+    }
+
+    impl sqlx::Type<Postgres> for RepoMember {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::with_name("repo_member")
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for RepoMember {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)?;
+            let user_id = decoder.try_decode::<i32>()?;
+            let permission = decoder.try_decode::<String>()?;
+            Ok(Self {
+                user_id,
+                permission,
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct RepoMemberArray(Vec<RepoMember>);
+
+    impl sqlx::Type<Postgres> for RepoMemberArray {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            // Array type name is the name of the element type prefixed with `_`
+            sqlx::postgres::PgTypeInfo::with_name("_repo_member")
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for RepoMemberArray {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            Ok(Self(Vec::<RepoMember>::decode(value)?))
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    #[derive(Debug, sqlx::FromRow)]
+    #[allow(dead_code)] // We don't actually read these fields.
+    struct Row {
+        count: i64,
+        items: Vec<(i32, String, RepoMemberArray)>,
+    }
+    // language=PostgreSQL
+    let row: Result<Row, Error> = sqlx::query_as::<_, Row>(
+        r"
+        WITH
+          members_by_repo AS (
+            SELECT repo_id,
+              ARRAY_AGG(ROW (user_id, permission)::repo_member) AS members
+            FROM repo_memberships
+            GROUP BY repo_id
+          ),
+          repos AS (
+            SELECT repo_id, repo_name, COALESCE(members, '{}') AS members
+            FROM repositories
+              LEFT OUTER JOIN members_by_repo USING (repo_id)
+            ORDER BY repo_id
+          ),
+          repo_array AS (
+            SELECT COALESCE(ARRAY_AGG(repos.*), '{}') AS items
+            FROM repos
+          ),
+          repo_count AS (
+            SELECT COUNT(*) AS count
+            FROM repos
+          )
+        SELECT count, items
+        FROM repo_count, repo_array
+        ;
+    ",
+    )
+    .fetch_one(&mut conn)
+    .await;
+
+    // This test currently tests mitigations for `#1672` (use regular errors
+    // instead of panics). Once we fully support custom types, it should be
+    // updated accordingly.
+    match row {
+        Ok(_) => panic!("full support for custom types is not implemented yet"),
+        Err(e) => assert!(e
+            .to_string()
+            .contains("custom types in records are not fully supported yet")),
+    }
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn custom_type_resolution_respects_search_path() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DROP TYPE IF EXISTS some_enum_type;
+DROP SCHEMA IF EXISTS another CASCADE;
+
+CREATE SCHEMA another;
+CREATE TYPE some_enum_type AS ENUM ('a', 'b', 'c');
+CREATE TYPE another.some_enum_type AS ENUM ('d', 'e', 'f');
+    "#,
+    )
+    .await?;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct SomeEnumType(String);
+
+    impl sqlx::Type<Postgres> for SomeEnumType {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::with_name("some_enum_type")
+        }
+
+        fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+            *ty == Self::type_info()
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for SomeEnumType {
+        fn decode(
+            value: sqlx::postgres::PgValueRef<'r>,
+        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
+            Ok(Self(<String as sqlx::Decode<Postgres>>::decode(value)?))
+        }
+    }
+
+    impl<'q> sqlx::Encode<'q, Postgres> for SomeEnumType {
+        fn encode_by_ref(
+            &self,
+            buf: &mut sqlx::postgres::PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, BoxDynError> {
+            <String as sqlx::Encode<Postgres>>::encode_by_ref(&self.0, buf)
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    sqlx::query("set search_path = 'another'")
+        .execute(&mut conn)
+        .await?;
+
+    let result = sqlx::query("SELECT 1 WHERE $1::some_enum_type = 'd'::some_enum_type;")
+        .bind(SomeEnumType("d".into()))
+        .fetch_all(&mut conn)
+        .await;
+
+    let result = result.unwrap();
+    assert_eq!(result.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_server_num() -> anyhow::Result<()> {
+    let conn = new::<Postgres>().await?;
+
+    assert!(conn.server_version_num().is_some());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);
+    "#,
+    )
+    .await?;
+
+    let mut copy = conn
+        .copy_in_raw(
+            r#"
+        COPY users (id) FROM STDIN WITH (FORMAT CSV, HEADER);
+    "#,
+        )
+        .await?;
+
+    copy.send("id\n1\n2\n".as_bytes()).await?;
+    let rows = copy.finish().await?;
+    assert_eq!(rows, 2);
+
+    // conn is safe for reuse
+    let value = sqlx::query("select 1 + 1")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(2i32, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_in_from_stream() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT NOT NULL);")
+        .await?;
+
+    // rows are split across chunks at arbitrary points
+    let chunks = ["1,al", "ice\n2,bob\n", "3,", "carol\n"]
+        .into_iter()
+        .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())));
+
+    let rows = conn
+        .copy_in_from_stream(
+            "COPY users (id, name) FROM STDIN WITH (FORMAT CSV)",
+            futures::stream::iter(chunks),
+        )
+        .await?;
+    assert_eq!(rows, 3);
+
+    let users: Vec<(i32, String)> = sqlx::query_as("SELECT id, name FROM users ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(
+        users,
+        [(1, "alice".into()), (2, "bob".into()), (3, "carol".into())]
+    );
+
+    // an error from the stream aborts the `COPY` and is returned as-is
+    let chunks = vec![
+        Ok(Bytes::from_static(b"4,dave\n")),
+        Err(sqlx::Error::Protocol("upstream went away".into())),
+        Ok(Bytes::from_static(b"5,eve\n")),
+    ];
+
+    let res = conn
+        .copy_in_from_stream(
+            "COPY users (id, name) FROM STDIN WITH (FORMAT CSV)",
+            futures::stream::iter(chunks),
+        )
+        .await;
+    assert!(matches!(res, Err(sqlx::Error::Protocol(msg)) if msg == "upstream went away"));
+
+    // nothing from the aborted `COPY` was kept, and the connection is still usable
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_in_ignoring_malformed_rows() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgCopyFormat, PgCopyInOptions, PgCopyOnError};
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT NOT NULL);")
+        .await?;
+
+    let options = PgCopyInOptions::new("users")
+        .columns(["id", "name"])
+        .format(PgCopyFormat::Csv);
+
+    let mut copy = conn.copy_in_with(&options).await?;
+    copy.send(&b"1,alice\n"[..]).await?;
+
+    let result = copy.finish_with_result().await?;
+    assert_eq!(result.rows_copied(), 1);
+    assert_eq!(result.rows_skipped(), Some(0));
+
+    // `ON_ERROR` was added in Postgres 17
+    if matches!(conn.server_version_num(), Some(version) if version < 170000) {
+        return Ok(());
+    }
+
+    let mut copy = conn
+        .copy_in_with(&options.on_error(PgCopyOnError::Ignore))
+        .await?;
+    copy.send(&b"2,bob\nthree,carol\n4,dave\nfive,eve\n"[..])
+        .await?;
+
+    let result = copy.finish_with_result().await?;
+    assert_eq!(result.rows_copied(), 2);
+    assert_eq!(result.rows_skipped(), Some(2));
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM users ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(ids, [1, 2, 4]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_fetch_a_scalar_or_a_default() -> anyhow::Result<()> {
+    use sqlx::postgres::PgExecutorExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // a `NULL` value
+    let value: i32 = conn.fetch_scalar_or("SELECT NULL::int4", 7).await?;
+    assert_eq!(value, 7);
+
+    // no rows at all
+    let value: String = conn
+        .fetch_scalar_or(
+            sqlx::query("SELECT 'found' WHERE $1").bind(false),
+            "default".to_owned(),
+        )
+        .await?;
+    assert_eq!(value, "default");
+
+    // a value is returned as-is
+    let value: i64 = conn
+        .fetch_scalar_or(sqlx::query("SELECT $1::int8 * 2").bind(21_i64), 0)
+        .await?;
+    assert_eq!(value, 42);
+
+    // other errors are still reported
+    let res = conn.fetch_scalar_or("SELECT 'text'", 0_i32).await;
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    // works with a pool too
+    let pool = pool::<Postgres>().await?;
+    let value: i32 = (&pool).fetch_scalar_or("SELECT NULL::int4", 1).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_fetch_a_scalar_stream() -> anyhow::Result<()> {
+    use sqlx::postgres::PgExecutorExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE scalar_stream (id BIGSERIAL PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let names: Vec<String> = (0..1000).map(|i| format!("name {i}")).collect();
+
+    let mut arguments = PgArguments::default();
+    arguments.add(&names).map_err(sqlx::Error::Encode)?;
+
+    let ids: Vec<i64> = conn
+        .fetch_scalar_stream::<i64>(
+            "INSERT INTO scalar_stream (name) SELECT * FROM UNNEST($1::text[]) RETURNING id",
+            arguments,
+        )
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, (1..=1000).collect::<Vec<i64>>());
+
+    // decoding errors are returned for the row they happen on
+    let mut stream =
+        conn.fetch_scalar_stream::<i64>("SELECT name FROM scalar_stream", PgArguments::default());
+    let res = stream.try_next().await;
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_keeps_the_most_recent_notices() -> anyhow::Result<()> {
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.clone().notice_history(3)).await?;
+
+    conn.clear_notice_history();
+
+    conn.execute(
+        r#"
+DO $$
+BEGIN
+    FOR i IN 1..5 LOOP
+        RAISE NOTICE 'notice %', i;
+    END LOOP;
+
+    RAISE WARNING 'careful' USING HINT = 'slow down';
+END;
+$$;
+        "#,
+    )
+    .await?;
+
+    let notices: Vec<_> = conn.recent_notices().collect();
+
+    assert_eq!(
+        notices.iter().map(|n| n.message()).collect::<Vec<_>>(),
+        ["notice 4", "notice 5", "careful"]
+    );
+
+    assert_eq!(notices[0].severity(), PgSeverity::Notice);
+    assert_eq!(notices[2].severity(), PgSeverity::Warning);
+    assert_eq!(notices[2].code(), "01000");
+    assert_eq!(notices[2].hint(), Some("slow down"));
+
+    conn.clear_notice_history();
+    assert_eq!(conn.recent_notices().len(), 0);
+
+    // by default, nothing is kept
+    let mut conn = PgConnection::connect_with(&options).await?;
+    conn.execute("DO $$ BEGIN RAISE NOTICE 'dropped'; END $$")
+        .await?;
+
+    assert_eq!(conn.recent_notices().len(), 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_checksum_copy_in_data() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgCopyFormat, PgCopyInOptions};
+
+    // bit-by-bit CRC-32 (as in zlib), independent of the implementation under test
+    fn crc32(data: &[u8]) -> u32 {
+        let mut crc = !0_u32;
+
+        for byte in data {
+            crc ^= u32::from(*byte);
+
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+
+        !crc
+    }
+
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT NOT NULL);")
+        .await?;
+
+    let data = b"1,alice\n2,bob\n3,carol\n";
+
+    let options = PgCopyInOptions::new("users")
+        .format(PgCopyFormat::Csv)
+        .checksum(true);
+
+    let mut copy = conn.copy_in_with(&options).await?;
+    assert_eq!(copy.checksum(), Some(crc32(b"")));
+
+    copy.send(&data[..8]).await?;
+    assert_eq!(copy.checksum(), Some(crc32(&data[..8])));
+
+    copy.read_from(&data[8..]).await?;
+
+    let result = copy.finish_with_result().await?;
+    assert_eq!(result.rows_copied(), 3);
+    assert_eq!(result.checksum(), Some(crc32(data)));
+
+    // without the option, no checksum is computed
+    let mut copy = conn.copy_in_with(&options.checksum(false)).await?;
+    copy.send(&b"4,dave\n"[..]).await?;
+
+    let result = copy.finish_with_result().await?;
+    assert_eq!(result.checksum(), None);
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[sqlx_macros::test]
+async fn it_can_bulk_copy_typed_rows() -> anyhow::Result<()> {
+    use sqlx::types::chrono::NaiveDate;
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        "CREATE TEMPORARY TABLE people (id INTEGER NOT NULL, name TEXT NOT NULL, born DATE NOT NULL, note TEXT);",
+    )
+    .await?;
+
+    let born = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+    let born_after = |days: i32| born.iter_days().nth(days as usize).unwrap();
+
+    let mut copy = conn
+        .bulk_copy_builder::<(i32, String, NaiveDate)>("people")
+        .columns(["id", "name", "born"])
+        .begin()
+        .await?;
+
+    // enough rows to be sent in several batches
+    for id in 0..5_000 {
+        copy.write_row((id, format!("person {id}"), born_after(id)))
+            .await?;
+    }
+
+    assert_eq!(copy.finish().await?, 5_000);
+
+    let row: (i32, String, NaiveDate, Option<String>) =
+        sqlx::query_as("SELECT id, name, born, note FROM people WHERE id = 4999")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(
+        row,
+        (4999, "person 4999".to_owned(), born_after(4999), None)
+    );
+
+    // `NULL`s are written for `None`
+    let mut copy = conn
+        .bulk_copy_builder::<(i32, String, NaiveDate, Option<String>)>("people")
+        .begin()
+        .await?;
+
+    copy.write_row((-1, "nobody".to_owned(), born, None))
+        .await?;
+    assert_eq!(copy.finish().await?, 1);
+
+    // the number of columns is checked when the `COPY` starts
+    let res = conn
+        .bulk_copy_builder::<(i32, String)>("people")
+        .begin()
+        .await
+        .map(drop);
+
+    assert!(matches!(res, Err(sqlx::Error::Configuration(_))));
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM people WHERE note IS NULL")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 5_001);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_bulk_copy_in_batches() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE readings (id INTEGER NOT NULL CHECK (id <> 7));")
+        .await?;
+
+    // the batch of rows 6 to 8 fails, after the first two batches were committed
+    let err = conn
+        .bulk_copy_builder::<(i32,)>("readings")
+        .copy_in_batches((0..10).map(|id| (id,)), 3)
+        .await
+        .expect_err("the row with id 7 should be rejected");
+
+    assert_eq!(err.rows_committed(), 6);
+    assert!(matches!(
+        err.into_error(),
+        sqlx::Error::Database(e) if e.code().as_deref() == Some("23514")
+    ));
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM readings ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(ids, [0, 1, 2, 3, 4, 5]);
+
+    // resume from the last committed batch, without the bad row
+    let copied = conn
+        .bulk_copy_builder::<(i32,)>("readings")
+        .copy_in_batches((6..10).filter(|&id| id != 7).map(|id| (id,)), 3)
+        .await?;
+    assert_eq!(copied, 3);
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM readings")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 9);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_validate_bulk_copy_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE pets (id INTEGER NOT NULL, name TEXT NOT NULL);")
+        .await?;
+
+    let validate = |(id, name): (i32, String)| -> Result<_, sqlx::error::BoxDynError> {
+        let name = name.trim().to_owned();
+
+        if name.is_empty() {
+            return Err(format!("pet {id} has no name").into());
+        }
+
+        Ok((id, name))
+    };
+
+    // rows may be transformed
+    let mut copy = conn
+        .bulk_copy_builder::<(i32, String)>("pets")
+        .validate_rows(validate)
+        .begin()
+        .await?;
+
+    copy.write_row((1, "  Rex ".to_owned())).await?;
+    assert_eq!(copy.finish().await?, 1);
+
+    let name: String = sqlx::query_scalar("SELECT name FROM pets WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(name, "Rex");
+
+    // a rejected row aborts the whole `COPY`
+    let mut copy = conn
+        .bulk_copy_builder::<(i32, String)>("pets")
+        .validate_rows(validate)
+        .begin()
+        .await?;
+
+    copy.write_row((2, "Fido".to_owned())).await?;
+
+    let err = copy
+        .write_row((3, " ".to_owned()))
+        .await
+        .err()
+        .expect("the row should be rejected");
+    assert!(matches!(&err, sqlx::Error::Encode(e) if e.to_string() == "pet 3 has no name"));
+
+    assert!(matches!(
+        copy.write_row((4, "Tom".to_owned())).await,
+        Err(sqlx::Error::Configuration(_))
+    ));
+    assert!(matches!(
+        copy.finish().await,
+        Err(sqlx::Error::Configuration(_))
+    ));
+
+    // conn is safe for reuse, and none of the rows were copied
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM pets")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_abort_copy_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);
+    "#,
+    )
+    .await?;
+
+    let copy = conn
+        .copy_in_raw(
+            r#"
+        COPY users (id) FROM STDIN WITH (FORMAT CSV, HEADER);
+    "#,
+        )
+        .await?;
+
+    copy.abort("this is only a test").await?;
+
+    // conn is safe for reuse
+    let value = sqlx::query("select 1 + 1")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(2i32, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_rollback_after_failed_copy_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO users (id) VALUES (1)")
+        .execute(&mut *tx)
+        .await?;
+
+    let mut copy = tx
+        .copy_in_raw("COPY users (id) FROM STDIN WITH (FORMAT CSV)")
+        .await?;
+
+    copy.send("2\nnot a number\n3\n".as_bytes()).await?;
+
+    let err = copy.finish().await.unwrap_err();
+    assert_eq!(
+        err.as_database_error().and_then(|e| e.code()).as_deref(),
+        Some("22P02"),
+        "expected invalid_text_representation, got {err:?}"
+    );
+
+    // the transaction is aborted until it is rolled back
+    let err = sqlx::query("SELECT 1").execute(&mut *tx).await.unwrap_err();
+    assert_eq!(
+        err.as_database_error().and_then(|e| e.code()).as_deref(),
+        Some("25P02"),
+        "expected in_failed_sql_transaction, got {err:?}"
+    );
+
+    tx.rollback().await?;
+
+    // neither the insert nor the copied rows were kept, and conn is safe for reuse
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_both_for_logical_replication() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.logical_replication(true)).await?;
+
+    // a temporary slot is dropped with the connection
+    sqlx::raw_sql("CREATE_REPLICATION_SLOT sqlx_copy_both TEMPORARY LOGICAL test_decoding")
+        .execute(&mut conn)
+        .await?;
+
+    let mut copy = conn
+        .copy_both("START_REPLICATION SLOT sqlx_copy_both LOGICAL 0/0")
+        .await?;
+
+    // other tests may write to the database, so WAL data (`w`) can come before the
+    // primary keepalive message (`k`) the server sends once it is waiting for more
+    let keepalive = loop {
+        let msg = copy.recv().await?.expect("the server ended the stream");
+
+        if msg[0] == b'k' {
+            break msg;
+        }
+
+        assert_eq!(msg[0], b'w', "unexpected replication message {msg:?}");
+    };
+
+    // the tag, the end of WAL, the server clock and whether a reply is requested
+    assert_eq!(keepalive.len(), 18);
+
+    copy.finish().await?;
+
+    // the connection is usable again
+    let rows = sqlx::raw_sql("SELECT 1").fetch_all(&mut conn).await?;
+    assert_eq!(rows.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_rollback_after_dropped_copy_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    let mut copy = tx
+        .copy_in_raw("COPY users (id) FROM STDIN WITH (FORMAT CSV)")
+        .await?;
+
+    copy.send("1\n2\n".as_bytes()).await?;
+
+    // e.g. reading the source failed and `?` dropped the copy before it was finished
+    drop(copy);
+
+    tx.rollback().await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_out() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
 
-    // This test currently tests mitigations for `#1672` (use regular errors
-    // instead of panics). Once we fully support custom types, it should be
-    // updated accordingly.
-    match row {
-        Ok(_) => panic!("full support for custom types is not implemented yet"),
-        Err(e) => assert!(e
-            .to_string()
-            .contains("custom types in records are not fully supported yet")),
+    {
+        let mut copy = conn
+            .copy_out_raw(
+                "
+            COPY (SELECT generate_series(1, 2) AS id) TO STDOUT WITH (FORMAT CSV, HEADER);
+        ",
+            )
+            .await?;
+
+        assert_eq!(copy.next().await.unwrap().unwrap(), "id\n");
+        assert_eq!(copy.next().await.unwrap().unwrap(), "1\n");
+        assert_eq!(copy.next().await.unwrap().unwrap(), "2\n");
+        if copy.next().await.is_some() {
+            anyhow::bail!("Unexpected data from COPY");
+        }
     }
+
+    // conn is safe for reuse
+    let value = sqlx::query("select 1 + 1")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(2i32, value);
+
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn custom_type_resolution_respects_search_path() -> anyhow::Result<()> {
+async fn it_can_fetch_from_refcursor() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgRefCursor;
+
     let mut conn = new::<Postgres>().await?;
 
     conn.execute(
         r#"
-DROP TYPE IF EXISTS some_enum_type;
-DROP SCHEMA IF EXISTS another CASCADE;
-
-CREATE SCHEMA another;
-CREATE TYPE some_enum_type AS ENUM ('a', 'b', 'c');
-CREATE TYPE another.some_enum_type AS ENUM ('d', 'e', 'f');
-    "#,
+CREATE FUNCTION pg_temp.squares_cursor(n INT4, c REFCURSOR DEFAULT NULL) RETURNS REFCURSOR
+LANGUAGE plpgsql AS $$
+BEGIN
+    OPEN c FOR SELECT i, i * i AS square FROM generate_series(1, n) AS i;
+    RETURN c;
+END
+$$;
+        "#,
     )
     .await?;
 
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    struct SomeEnumType(String);
-
-    impl sqlx::Type<Postgres> for SomeEnumType {
-        fn type_info() -> sqlx::postgres::PgTypeInfo {
-            sqlx::postgres::PgTypeInfo::with_name("some_enum_type")
-        }
+    let mut tx = conn.begin().await?;
 
-        fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
-            *ty == Self::type_info()
-        }
-    }
+    // the cursor is given a generated name
+    let cursor: PgRefCursor = sqlx::query_scalar("SELECT pg_temp.squares_cursor($1)")
+        .bind(3_i32)
+        .fetch_one(&mut *tx)
+        .await?;
 
-    impl<'r> sqlx::Decode<'r, Postgres> for SomeEnumType {
-        fn decode(
-            value: sqlx::postgres::PgValueRef<'r>,
-        ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-            Ok(Self(<String as sqlx::Decode<Postgres>>::decode(value)?))
-        }
-    }
+    let rows: Vec<(i32, i32)> = cursor
+        .fetch_all(&mut *tx)
+        .map_ok(|row| (row.get(0), row.get("square")))
+        .try_collect()
+        .await?;
 
-    impl<'q> sqlx::Encode<'q, Postgres> for SomeEnumType {
-        fn encode_by_ref(
-            &self,
-            buf: &mut sqlx::postgres::PgArgumentBuffer,
-        ) -> Result<sqlx::encode::IsNull, BoxDynError> {
-            <String as sqlx::Encode<Postgres>>::encode_by_ref(&self.0, buf)
-        }
-    }
+    assert_eq!(rows, [(1, 1), (2, 4), (3, 9)]);
 
-    let mut conn = new::<Postgres>().await?;
+    // the cursor has been exhausted
+    assert!(cursor.fetch_all(&mut *tx).try_next().await?.is_none());
 
-    sqlx::query("set search_path = 'another'")
-        .execute(&mut conn)
+    // the caller names the cursor, which needs quoting
+    let name = PgRefCursor(r#"my "squares""#.into());
+    let cursor: PgRefCursor = sqlx::query_scalar("SELECT pg_temp.squares_cursor($1, $2)")
+        .bind(2_i32)
+        .bind(&name)
+        .fetch_one(&mut *tx)
         .await?;
 
-    let result = sqlx::query("SELECT 1 WHERE $1::some_enum_type = 'd'::some_enum_type;")
-        .bind(SomeEnumType("d".into()))
-        .fetch_all(&mut conn)
-        .await;
-
-    let result = result.unwrap();
-    assert_eq!(result.len(), 1);
-
-    Ok(())
-}
+    assert_eq!(cursor, name);
 
-#[sqlx_macros::test]
-async fn test_pg_server_num() -> anyhow::Result<()> {
-    let conn = new::<Postgres>().await?;
+    let rows: Vec<PgRow> = cursor.fetch_all(&mut *tx).try_collect().await?;
+    assert_eq!(rows.len(), 2);
 
-    assert!(conn.server_version_num().is_some());
+    tx.commit().await?;
 
     Ok(())
 }
 
 #[sqlx_macros::test]
-async fn it_can_copy_in() -> anyhow::Result<()> {
+async fn it_can_copy_out_binary() -> anyhow::Result<()> {
+    use sqlx::postgres::PgTypeInfo;
+    use sqlx::TypeInfo;
+
     let mut conn = new::<Postgres>().await?;
+
     conn.execute(
-        r#"
-        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);
-    "#,
+        "CREATE TEMPORARY TABLE copy_binary (id INT4, name TEXT, score FLOAT8, data BYTEA)",
     )
     .await?;
 
+    let rows: Vec<(i32, String, Option<f64>, Vec<u8>)> = vec![
+        (1, "first".into(), Some(1.5), vec![0, 1, 2]),
+        (2, "séance 🦀".into(), None, vec![]),
+        (3, "".into(), Some(-0.25), vec![0xff; 300]),
+    ];
+
+    // signature, no flags, no header extension
+    let mut data = b"PGCOPY\n\xff\r\n\0".to_vec();
+    data.extend_from_slice(&0_i32.to_be_bytes());
+    data.extend_from_slice(&0_i32.to_be_bytes());
+
+    for (id, name, score, bytes) in &rows {
+        let fields: [Option<Vec<u8>>; 4] = [
+            Some(id.to_be_bytes().to_vec()),
+            Some(name.as_bytes().to_vec()),
+            score.map(|score| score.to_be_bytes().to_vec()),
+            Some(bytes.clone()),
+        ];
+
+        data.extend_from_slice(&4_i16.to_be_bytes());
+
+        for field in fields {
+            match field {
+                Some(field) => {
+                    data.extend_from_slice(&i32::try_from(field.len())?.to_be_bytes());
+                    data.extend_from_slice(&field);
+                }
+                None => data.extend_from_slice(&(-1_i32).to_be_bytes()),
+            }
+        }
+    }
+
+    data.extend_from_slice(&(-1_i16).to_be_bytes());
+
     let mut copy = conn
-        .copy_in_raw(
-            r#"
-        COPY users (id) FROM STDIN WITH (FORMAT CSV, HEADER);
-    "#,
+        .copy_in_raw("COPY copy_binary FROM STDIN WITH (FORMAT binary)")
+        .await?;
+    copy.send(data).await?;
+    assert_eq!(copy.finish().await?, 3);
+
+    let columns = [
+        PgTypeInfo::with_name("INT4"),
+        PgTypeInfo::with_name("TEXT"),
+        PgTypeInfo::with_name("FLOAT8"),
+        PgTypeInfo::with_name("BYTEA"),
+    ];
+
+    let copied: Vec<(i32, String, Option<f64>, Vec<u8>)> = conn
+        .copy_out_binary(
+            "COPY (SELECT * FROM copy_binary ORDER BY id) TO STDOUT WITH (FORMAT binary)",
+            &columns,
         )
+        .await?
+        .map_ok(|row| {
+            assert_eq!(row.column(1).type_info().name(), "TEXT");
+
+            (row.get(0), row.get(1), row.get(2), row.get(3))
+        })
+        .try_collect()
         .await?;
 
-    copy.send("id\n1\n2\n".as_bytes()).await?;
-    let rows = copy.finish().await?;
-    assert_eq!(rows, 2);
+    assert_eq!(copied, rows);
 
-    // conn is safe for reuse
-    let value = sqlx::query("select 1 + 1")
-        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
-        .fetch_one(&mut conn)
-        .await?;
+    // a different number of columns is an error, but the connection is still usable
+    let res = conn
+        .copy_out_binary(
+            "COPY copy_binary TO STDOUT WITH (FORMAT binary)",
+            &columns[..2],
+        )
+        .await
+        .map(drop);
+    assert!(res.is_err());
 
-    assert_eq!(2i32, value);
+    // as is a text `COPY`
+    let res = conn
+        .copy_out_binary("COPY copy_binary TO STDOUT", &columns)
+        .await
+        .map(drop);
+    assert!(res.is_err());
+
+    let value: i32 = conn.fetch_one("SELECT 1").await?.try_get(0)?;
+    assert_eq!(value, 1);
 
     Ok(())
 }
 
+#[cfg(feature = "_rt-tokio")]
 #[sqlx_macros::test]
-async fn it_can_abort_copy_in() -> anyhow::Result<()> {
+async fn it_can_copy_out_to_writer() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
+
     conn.execute(
         r#"
-        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL);
+        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT NOT NULL);
+        INSERT INTO users (id, name)
+        SELECT i, 'user ' || i FROM generate_series(1, 1000) AS i;
     "#,
     )
     .await?;
 
-    let copy = conn
-        .copy_in_raw(
-            r#"
-        COPY users (id) FROM STDIN WITH (FORMAT CSV, HEADER);
-    "#,
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("users.csv");
+
+    let file = tokio::fs::File::create(&path).await?;
+
+    let written = conn
+        .copy_out_to(
+            "COPY (SELECT id, name FROM users ORDER BY id) TO STDOUT WITH (FORMAT CSV, HEADER)",
+            file,
         )
         .await?;
 
-    copy.abort("this is only a test").await?;
+    let expected: String = std::iter::once("id,name\n".to_string())
+        .chain((1..=1000).map(|i| format!("{i},user {i}\n")))
+        .collect();
+
+    let copied = tokio::fs::read_to_string(&path).await?;
+
+    assert_eq!(copied, expected);
+    assert_eq!(written, expected.len() as u64);
 
     // conn is safe for reuse
-    let value = sqlx::query("select 1 + 1")
-        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+    let value: i32 = sqlx::query_scalar("select 1 + 1")
         .fetch_one(&mut conn)
         .await?;
 
@@ -1688,33 +4278,55 @@ async fn it_can_abort_copy_in() -> anyhow::Result<()> {
 }
 
 #[sqlx_macros::test]
-async fn it_can_copy_out() -> anyhow::Result<()> {
-    let mut conn = new::<Postgres>().await?;
+async fn it_decodes_nullable_columns_with_value_ref_helpers() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgTypeInfo, PgValueRef};
+    use sqlx::{Decode, Type};
 
-    {
-        let mut copy = conn
-            .copy_out_raw(
-                "
-            COPY (SELECT generate_series(1, 2) AS id) TO STDOUT WITH (FORMAT CSV, HEADER);
-        ",
-            )
-            .await?;
+    // `0` when the column is `NULL`
+    #[derive(Debug, PartialEq)]
+    struct Count(i64);
 
-        assert_eq!(copy.next().await.unwrap().unwrap(), "id\n");
-        assert_eq!(copy.next().await.unwrap().unwrap(), "1\n");
-        assert_eq!(copy.next().await.unwrap().unwrap(), "2\n");
-        if copy.next().await.is_some() {
-            anyhow::bail!("Unexpected data from COPY");
+    impl Type<Postgres> for Count {
+        fn type_info() -> PgTypeInfo {
+            <i64 as Type<Postgres>>::type_info()
         }
     }
 
-    // conn is safe for reuse
-    let value = sqlx::query("select 1 + 1")
-        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
-        .fetch_one(&mut conn)
-        .await?;
+    impl<'r> Decode<'r, Postgres> for Count {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(Count(value.decode_or(0)?))
+        }
+    }
 
-    assert_eq!(2i32, value);
+    // whether the column is `NULL`, whatever its type
+    #[derive(Debug, PartialEq)]
+    struct IsNull(bool);
+
+    impl Type<Postgres> for IsNull {
+        fn type_info() -> PgTypeInfo {
+            <i64 as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(_: &PgTypeInfo) -> bool {
+            true
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for IsNull {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(IsNull(value.is_null()))
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let rows: Vec<(Count, IsNull)> = sqlx::query_as(
+        "SELECT n, n FROM (VALUES (3::int8), (NULL)) AS t (n) ORDER BY n NULLS LAST",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(rows, [(Count(3), IsNull(false)), (Count(0), IsNull(true))]);
 
     Ok(())
 }
@@ -1877,6 +4489,170 @@ CREATE TABLE issue_1254 (id INT4 PRIMARY KEY, pairs PAIR[]);
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_decode_record_fields_by_name() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgRecord;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // `inventory_item` is declared in setup.sql
+    let row = sqlx::query("SELECT ROW('fuzzy dice', 42, NULL)::inventory_item AS item")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let item: PgRecord = row.try_get("item")?;
+
+    assert_eq!(
+        item.field_names().collect::<Vec<_>>(),
+        ["name", "supplier_id", "price"]
+    );
+    assert_eq!(item.get::<String>("name"), "fuzzy dice");
+    assert_eq!(item.get::<i32>("supplier_id"), 42);
+    assert_eq!(item.get::<Option<i64>>("price"), None);
+
+    assert!(matches!(
+        item.try_get::<i32>("missing"),
+        Err(sqlx::Error::ColumnNotFound(_))
+    ));
+    assert!(matches!(
+        item.try_get::<String>("supplier_id"),
+        Err(sqlx::Error::ColumnDecode { .. })
+    ));
+
+    // the same, over the text protocol
+    let row = conn
+        .fetch_one("SELECT ROW('fuzzy dice', 42, NULL)::inventory_item AS item")
+        .await?;
+
+    let item: PgRecord = row.try_get("item")?;
+
+    assert_eq!(item.get::<String>("name"), "fuzzy dice");
+    assert_eq!(item.get::<i32>("supplier_id"), 42);
+    assert_eq!(item.get::<Option<i64>>("price"), None);
+
+    // fields of anonymous records are named the way Postgres names them
+    let record: PgRecord = sqlx::query_scalar("SELECT ROW(1::int4, 'two'::text)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.len(), 2);
+    assert_eq!(record.get::<i32>("f1"), 1);
+    assert_eq!(record.get::<String>("f2"), "two");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_caches_type_oids_in_bulk() -> anyhow::Result<()> {
+    #[derive(sqlx::Type)]
+    #[sqlx(type_name = "bulk_type_1")]
+    struct First(String);
+
+    impl sqlx::postgres::PgHasArrayType for First {
+        fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::array_of("bulk_type_1")
+        }
+    }
+
+    #[derive(sqlx::Type)]
+    #[sqlx(type_name = "bulk_type_10")]
+    struct Last(String);
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DROP SCHEMA IF EXISTS bulk_types CASCADE;
+CREATE SCHEMA bulk_types;
+
+DO $$
+BEGIN
+    FOR i IN 1..10 LOOP
+        EXECUTE format('CREATE DOMAIN bulk_types.bulk_type_%s AS TEXT', i);
+    END LOOP;
+END
+$$;
+        "#,
+    )
+    .await?;
+
+    let names: Vec<String> = (1..=10).map(|i| format!("bulk_type_{i}")).collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+    conn.execute("SET search_path TO bulk_types, public")
+        .await?;
+    conn.cache_type_oids(&names).await?;
+
+    // the names no longer resolve, so binding them only works if nothing is looked up
+    conn.execute("SET search_path TO public").await?;
+
+    let (first, last, all): (String, String, Vec<String>) =
+        sqlx::query_as("SELECT $1::text, $2::text, $3::text[]")
+            .bind(First("first".into()))
+            .bind(Last("last".into()))
+            .bind(vec![First("a".into()), First("b".into())])
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!((&*first, &*last), ("first", "last"));
+    assert_eq!(all, ["a", "b"]);
+
+    // whereas a connection that has not cached them has to look them up
+    let mut uncached = new::<Postgres>().await?;
+
+    assert!(sqlx::query("SELECT $1::text")
+        .bind(First("first".into()))
+        .execute(&mut uncached)
+        .await
+        .is_err());
+
+    assert!(matches!(
+        uncached
+            .cache_type_oids(&["bulk_types.bulk_type_1", "no_such_type"])
+            .await,
+        Err(sqlx::Error::TypeNotFound { type_name }) if type_name == "no_such_type"
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pool_calls_on_acquire_slow() -> anyhow::Result<()> {
+    use std::sync::Mutex;
+
+    let threshold = Duration::from_millis(100);
+    let slow_acquires = Arc::new(Mutex::new(Vec::new()));
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .acquire_slow_threshold(threshold)
+        .on_acquire_slow({
+            let slow_acquires = Arc::clone(&slow_acquires);
+            move |waited| slow_acquires.lock().unwrap().push(waited)
+        })
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // saturate the pool, releasing the only connection after a while
+    let conn = pool.acquire().await?;
+
+    // opening the first connection may itself have been slow
+    slow_acquires.lock().unwrap().clear();
+
+    sqlx_core::rt::spawn(async move {
+        sqlx_core::rt::sleep(threshold * 3).await;
+        drop(conn);
+    });
+
+    let _conn = pool.acquire().await?;
+
+    let slow_acquires = slow_acquires.lock().unwrap();
+    assert_eq!(slow_acquires.len(), 1);
+    assert!(slow_acquires[0] > threshold);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_advisory_locks() -> anyhow::Result<()> {
     let pool = PgPoolOptions::new()
@@ -157,3 +157,66 @@ async fn test_max_number_of_binds() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "chrono")]
+#[sqlx::test]
+async fn test_push_values_upsert_returning() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+    use sqlx::types::chrono::{DateTime, TimeZone, Utc};
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE upsert_returning (
+    id INT PRIMARY KEY,
+    name TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+INSERT INTO upsert_returning (id, name, updated_at)
+VALUES (2, 'two', '2000-01-01 00:00:00+00'), (4, 'four', '2000-01-01 00:00:00+00');
+        "#,
+    )
+    .await?;
+
+    let updated_at = |secs: i64| Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap();
+
+    // a mix of conflicting and new rows
+    let rows = [
+        (4, "FOUR", 1),
+        (1, "one", 2),
+        (2, "TWO", 3),
+        (3, "three", 4),
+    ];
+
+    let mut qb: QueryBuilder<'_, Postgres> =
+        QueryBuilder::new("INSERT INTO upsert_returning (id, name, updated_at) ");
+
+    qb.push_values(rows, |mut b, (id, name, secs)| {
+        b.push_bind(id).push_bind(name).push_bind(updated_at(secs));
+    });
+
+    qb.push(
+        " ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, updated_at = EXCLUDED.updated_at \
+         RETURNING id, updated_at",
+    );
+
+    // the returned rows are decoded as they are streamed back
+    let returned: Vec<_> = qb
+        .build_query_as::<(i32, DateTime<Utc>)>()
+        .fetch(&mut conn)
+        .try_collect()
+        .await?;
+
+    // `INSERT ... VALUES` returns the rows in the order they were given
+    assert_eq!(returned, rows.map(|(id, _, secs)| (id, updated_at(secs))));
+
+    let names: Vec<String> = sqlx::query_scalar("SELECT name FROM upsert_returning ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, ["one", "TWO", "three", "FOUR"]);
+
+    Ok(())
+}